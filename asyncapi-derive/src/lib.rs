@@ -0,0 +1,307 @@
+//! The derive macro for `asyncapi::AsyncApiMessage`, enable the `derive`
+//! feature of the `asyncapi` crate to use it.
+use proc_macro::TokenStream;
+use quote::quote;
+use serde_json::{json, Map, Value};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericArgument, Lit,
+    LitStr, PathArguments, Type,
+};
+
+/// Implements `asyncapi::AsyncApiMessage` for a struct with named fields.
+///
+/// The message is named after the struct and its payload is an object schema
+/// with a property per field. Doc comments become descriptions, `Option`
+/// fields are optional, and the `rename`, `rename_all` and `skip` attributes
+/// of serde are followed. Field types that aren't primitives, strings,
+/// collections or maps are referenced as `#/components/schemas/{Type}`.
+///
+/// The `asyncapi` attribute sets the `name`, `title`, `summary`,
+/// `description` and `content_type` of the message.
+#[proc_macro_derive(AsyncApiMessage, attributes(asyncapi))]
+pub fn derive_async_api_message(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "AsyncApiMessage can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            input.ident.span(),
+            "AsyncApiMessage can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut message = MessageAttributes::default();
+    let mut rename_all = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("asyncapi") {
+            message.parse(attr)?;
+        } else if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    rename_all = Some(meta.value()?.parse::<LitStr>()?);
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named fields have a name");
+        let mut name = ident.to_string();
+        if let Some(stripped) = name.strip_prefix("r#") {
+            name = stripped.to_string();
+        }
+        if let Some(rename_all) = &rename_all {
+            name = rename(&name, rename_all)?;
+        }
+        let mut skip = false;
+        for attr in &field.attrs {
+            if attr.path().is_ident("serde") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        name = meta.value()?.parse::<LitStr>()?.value();
+                    } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                        skip = true;
+                    } else {
+                        skip_meta(&meta)?;
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        if skip {
+            continue;
+        }
+
+        let (ty, optional) = match generic_argument(&field.ty, "Option") {
+            Some(inner) => (inner, true),
+            None => (&field.ty, false),
+        };
+        let mut schema = schema(ty);
+        if let (Some(description), Value::Object(schema)) = (doc(&field.attrs), &mut schema) {
+            if !schema.contains_key("$ref") {
+                schema.insert("description".to_string(), description.into());
+            }
+        }
+        if !optional {
+            required.push(Value::String(name.clone()));
+        }
+        properties.insert(name, schema);
+    }
+
+    let mut payload = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        payload["required"] = Value::Array(required);
+    }
+    let payload = payload.to_string();
+
+    let ident = &input.ident;
+    let name = message.name.unwrap_or_else(|| ident.to_string());
+    let description = message.description.or_else(|| doc(&input.attrs));
+    let title = message.title.map(|title| quote!(.title(#title)));
+    let summary = message.summary.map(|summary| quote!(.summary(#summary)));
+    let description = description.map(|description| quote!(.description(#description)));
+    let content_type = message
+        .content_type
+        .map(|content_type| quote!(.content_type(#content_type)));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::asyncapi::AsyncApiMessage for #ident #ty_generics #where_clause {
+            fn message() -> ::asyncapi::Message {
+                ::asyncapi::Message::builder()
+                    .name(#name)
+                    #title
+                    #summary
+                    #description
+                    #content_type
+                    .payload_schema(::asyncapi::__private::schema(#payload))
+                    .build()
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct MessageAttributes {
+    name: Option<String>,
+    title: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    content_type: Option<String>,
+}
+
+impl MessageAttributes {
+    fn parse(&mut self, attr: &Attribute) -> Result<(), Error> {
+        attr.parse_nested_meta(|meta| {
+            let field = if meta.path.is_ident("name") {
+                &mut self.name
+            } else if meta.path.is_ident("title") {
+                &mut self.title
+            } else if meta.path.is_ident("summary") {
+                &mut self.summary
+            } else if meta.path.is_ident("description") {
+                &mut self.description
+            } else if meta.path.is_ident("content_type") {
+                &mut self.content_type
+            } else {
+                return Err(meta.error("unknown asyncapi attribute"));
+            };
+            *field = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        })
+    }
+}
+
+/// Skips a serde attribute this derive doesn't look at.
+fn skip_meta(meta: &syn::meta::ParseNestedMeta) -> Result<(), Error> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<Lit>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_meta(&meta))?;
+    }
+    Ok(())
+}
+
+/// The doc comment of an item, with the lines joined.
+fn doc(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(lit) => Some(lit.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// The type argument of `ty` if it is the generic type `name`.
+fn generic_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    type_arguments(&segment.arguments).into_iter().next()
+}
+
+fn type_arguments(arguments: &PathArguments) -> Vec<&Type> {
+    let PathArguments::AngleBracketed(arguments) = arguments else {
+        return Vec::new();
+    };
+    arguments
+        .args
+        .iter()
+        .filter_map(|argument| match argument {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The JSON schema of the Rust type `ty`.
+fn schema(ty: &Type) -> Value {
+    let ty = match ty {
+        Type::Reference(reference) => return schema(&reference.elem),
+        Type::Slice(slice) => return json!({ "type": "array", "items": schema(&slice.elem) }),
+        Type::Array(array) => return json!({ "type": "array", "items": schema(&array.elem) }),
+        Type::Path(path) => path,
+        _ => return json!({}),
+    };
+    let Some(segment) = ty.path.segments.last() else {
+        return json!({});
+    };
+    let arguments = type_arguments(&segment.arguments);
+    match segment.ident.to_string().as_str() {
+        "String" | "str" | "char" => json!({ "type": "string" }),
+        "bool" => json!({ "type": "boolean" }),
+        "i32" | "u16" | "i16" | "u8" | "i8" => json!({ "type": "integer", "format": "int32" }),
+        "i64" | "u32" | "u64" | "i128" | "u128" | "isize" | "usize" => {
+            json!({ "type": "integer", "format": "int64" })
+        }
+        "f32" => json!({ "type": "number", "format": "float" }),
+        "f64" => json!({ "type": "number", "format": "double" }),
+        "Box" | "Rc" | "Arc" | "Cow" | "Option" => match arguments.first() {
+            Some(inner) => schema(inner),
+            None => json!({}),
+        },
+        "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "IndexSet" => {
+            let mut array = json!({ "type": "array" });
+            if let Some(items) = arguments.first() {
+                array["items"] = schema(items);
+            }
+            if segment.ident.to_string().ends_with("Set") {
+                array["uniqueItems"] = true.into();
+            }
+            array
+        }
+        "HashMap" | "BTreeMap" | "IndexMap" => {
+            let mut object = json!({ "type": "object" });
+            if let Some(values) = arguments.get(1) {
+                object["additionalProperties"] = schema(values);
+            }
+            object
+        }
+        "Value" => json!({}),
+        name => json!({ "$ref": format!("#/components/schemas/{name}") }),
+    }
+}
+
+/// Renames the field `name` like serde does for `rename_all = "{rule}"`.
+fn rename(name: &str, rule: &LitStr) -> Result<String, Error> {
+    let words: Vec<&str> = name.split('_').filter(|word| !word.is_empty()).collect();
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    };
+    Ok(match rule.value().as_str() {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "snake_case" => name.to_string(),
+        "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+        "PascalCase" => words
+            .iter()
+            .map(|word| capitalize(word))
+            .collect::<String>(),
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                if index == 0 {
+                    word.to_string()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        _ => return Err(Error::new(rule.span(), "unknown rename rule")),
+    })
+}
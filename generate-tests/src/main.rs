@@ -32,7 +32,6 @@ fn main() -> io::Result<()> {
         quote! {
             use asyncapi::{AsyncAPI};
         }
-        .to_string()
     )
     .unwrap();
 
@@ -59,7 +58,6 @@ fn main() -> io::Result<()> {
         quote! {
             use asyncapi::{AsyncAPI};
         }
-        .to_string()
     )
     .unwrap();
 
@@ -133,7 +131,7 @@ fn write_deserialize_test(
     let test_name = format_ident!(
         "test_{}",
         ident_regex
-            .replace_all(&path_string, "_")
+            .replace_all(path_string, "_")
             .to_case(Case::Snake)
     );
     let test = quote! {
@@ -142,6 +140,6 @@ fn write_deserialize_test(
             #test
         }
     };
-    writeln!(file, "{}", test.to_string())?;
+    writeln!(file, "{}", test)?;
     Ok(())
 }
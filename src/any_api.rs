@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::{error::FormatError, v3::AsyncApiV3, AsyncAPI, Error};
+
+/// A document of any supported AsyncAPI version.
+///
+/// Deserialization looks at the `asyncapi` field to decide which model the
+/// document is read into: `2.x` versions (2.0 to 2.6) become
+/// [`V2`][AnyAsyncApi::V2] and `3.x` versions become [`V3`][AnyAsyncApi::V3].
+/// Other versions are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::AnyAsyncApi;
+///
+/// let api: AnyAsyncApi = r#"
+/// asyncapi: 3.0.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// "#
+/// .parse()
+/// .unwrap();
+/// assert!(matches!(api, AnyAsyncApi::V3(_)));
+/// assert_eq!(api.version(), "3.0.0");
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyAsyncApi {
+    V2(AsyncAPI),
+    V3(AsyncApiV3),
+}
+
+impl AnyAsyncApi {
+    /// The AsyncAPI version the document declares.
+    pub fn version(&self) -> &str {
+        match self {
+            AnyAsyncApi::V2(api) => api.asyncapi.as_str(),
+            AnyAsyncApi::V3(api) => api.asyncapi.as_str(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AnyAsyncApi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let version = match value.get("asyncapi") {
+            Some(serde_json::Value::String(version)) => version,
+            Some(_) => return Err(de::Error::custom("`asyncapi` must be a string")),
+            None => return Err(de::Error::missing_field("asyncapi")),
+        };
+        match version.split('.').next() {
+            Some("2") => AsyncAPI::deserialize(value)
+                .map(AnyAsyncApi::V2)
+                .map_err(de::Error::custom),
+            Some("3") => AsyncApiV3::deserialize(value)
+                .map(AnyAsyncApi::V3)
+                .map_err(de::Error::custom),
+            _ => Err(de::Error::custom(format!(
+                "unsupported AsyncAPI version `{version}`"
+            ))),
+        }
+    }
+}
+
+impl From<AsyncAPI> for AnyAsyncApi {
+    fn from(api: AsyncAPI) -> Self {
+        AnyAsyncApi::V2(api)
+    }
+}
+
+impl From<AsyncApiV3> for AnyAsyncApi {
+    fn from(api: AsyncApiV3) -> Self {
+        AnyAsyncApi::V3(api)
+    }
+}
+
+impl FromStr for AnyAsyncApi {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        serde_yaml::from_str(input).map_err(|error| Error::Parse(FormatError::Yaml(error)))
+    }
+}
+
+#[test]
+fn test_dispatch_on_version() {
+    let v2: AnyAsyncApi = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            name: UserSignedUp
+    "#
+    .parse()
+    .unwrap();
+    let AnyAsyncApi::V2(api) = &v2 else {
+        panic!("expected a 2.x document");
+    };
+    assert!(api.channels["user/signedup"].subscribe.is_some());
+
+    let v3 = serde_json::from_value::<AnyAsyncApi>(
+        serde_json::to_value(AsyncApiV3::new("Account Service", "1.0.0")).unwrap(),
+    )
+    .unwrap();
+    assert!(matches!(v3, AnyAsyncApi::V3(_)));
+
+    let error = "asyncapi: 1.2.0\ninfo:\n  title: Old\n  version: 1.0.0"
+        .parse::<AnyAsyncApi>()
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("unsupported AsyncAPI version `1.2.0`"));
+    assert!("info:\n  title: Unversioned\n  version: 1.0.0"
+        .parse::<AnyAsyncApi>()
+        .is_err());
+}
@@ -1,7 +1,16 @@
+use std::{collections::HashMap, str::FromStr};
+
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::{Channel, Components, ExternalDocumentation, Info, ReferenceOr, Server, Tag};
+use crate::{
+    error::FormatError,
+    reference::{resolve_local, ObjectKeys},
+    validation::{self, Validate, ValidationError},
+    Channel, Components, Error, ExternalDocumentation, Identifier, Info, MediaType, Message,
+    Operation, OperationKind, OperationMessageType, Payload, ReferenceOr, ResolveError,
+    SecurityScheme, SecuritySchemeRequirement, Server, SpecVersion, Tag,
+};
 
 /// This is the root document object for the API specification.
 /// It combines resource listing and API declaration together into one document.
@@ -33,7 +42,7 @@ pub struct AsyncAPI {
     /// with operations of tooling developed to a lower minor version. Thus a
     /// hypothetical `1.1.0` specification should be usable with tooling
     /// designed for `1.0.0`.
-    pub asyncapi: String,
+    pub asyncapi: SpecVersion,
     /// Identifier of the
     /// [application](https://www.asyncapi.com/docs/specifications/v2.3.0#definitionsApplication)
     /// the AsyncAPI document is defining.
@@ -69,7 +78,7 @@ pub struct AsyncAPI {
     /// id: 'https://github.com/smartylighting/streetlights-server'
     /// ```
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<Identifier>,
     /// **Required.** Provides metadata about the API.
     /// The metadata can be used by the clients if needed.
     pub info: Info,
@@ -120,7 +129,7 @@ pub struct AsyncAPI {
     /// defaultContentType: application/json
     /// ```
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_content_type: Option<String>,
+    pub default_content_type: Option<MediaType>,
     /// **Required** The available channels and messages for the API.
     ///
     /// Holds the relative paths to the individual channel and their operations.
@@ -163,6 +172,1574 @@ pub struct AsyncAPI {
     pub external_docs: Option<ExternalDocumentation>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
+
+impl AsyncAPI {
+    /// Creates the smallest valid document: an API called `title` in `version`
+    /// without any servers or channels.
+    ///
+    /// Unlike [`AsyncAPI::default`], which leaves the required fields empty,
+    /// the returned document declares the latest 2.x version of AsyncAPI,
+    /// 2.6.0, so that every 2.x field such as `messageId` validates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let api = asyncapi::AsyncAPI::new("Account Service", "1.0.0");
+    /// assert_eq!(api.asyncapi, "2.6.0");
+    /// assert_eq!(api.info.title, "Account Service");
+    /// ```
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        AsyncAPI {
+            asyncapi: SpecVersion::V2_6_0,
+            info: Info::new(title, version),
+            ..Default::default()
+        }
+    }
+
+    /// Starts building a document. Like [`AsyncAPI::new`], the document
+    /// declares AsyncAPI 2.6.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, Channel, Info, Server};
+    ///
+    /// let api = AsyncAPI::builder()
+    ///     .info(Info::new("Account Service", "1.0.0"))
+    ///     .server("production", Server::new("broker.example.com", "kafka"))
+    ///     .channel("user/signedup", Channel::default())
+    ///     .build();
+    /// assert_eq!(api.asyncapi, "2.6.0");
+    /// assert!(api.servers.contains_key("production"));
+    /// ```
+    pub fn builder() -> AsyncAPIBuilder {
+        AsyncAPIBuilder {
+            api: AsyncAPI {
+                asyncapi: SpecVersion::V2_6_0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Parses a document from JSON.
+    ///
+    /// JSON is valid YAML, so [`str::parse`] accepts JSON documents as well,
+    /// but the error locations reported by this method are more precise.
+    pub fn from_json(input: &str) -> Result<Self, Error> {
+        serde_json::from_str(input).map_err(|error| Error::Parse(FormatError::Json(error)))
+    }
+
+    /// Parses a document from YAML or JSON, rejecting fields that are neither
+    /// defined by the specification nor specification extensions, see
+    /// [`ParseOptions::deny_unknown_fields`][crate::ParseOptions::deny_unknown_fields],
+    /// as well as values the specification doesn't define, see
+    /// [`ParseOptions::strict`][crate::ParseOptions::strict].
+    pub fn from_str_strict(input: &str) -> Result<Self, Error> {
+        crate::ParseOptions::new()
+            .strict(true)
+            .deny_unknown_fields(true)
+            .parse(input)
+    }
+
+    /// Serializes the document to YAML.
+    pub fn to_yaml(&self) -> Result<String, Error> {
+        serde_yaml::to_string(self).map_err(|error| Error::Serialize(FormatError::Yaml(error)))
+    }
+
+    /// Serializes the document to pretty printed JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self)
+            .map_err(|error| Error::Serialize(FormatError::Json(error)))
+    }
+
+    /// Returns the operations of all channels in document order, together
+    /// with the name of their channel.
+    pub fn operations(&self) -> impl Iterator<Item = (&str, OperationKind, &Operation)> {
+        self.channels.iter().flat_map(|(name, channel)| {
+            channel
+                .operations()
+                .map(move |(kind, operation)| (name.as_str(), kind, operation))
+        })
+    }
+
+    /// Returns a warning for every server, of the document or its components,
+    /// whose protocol isn't one of the [known
+    /// protocols][Server::KNOWN_PROTOCOLS]. Unlike [validation][Validate]
+    /// errors these don't make the document invalid, but mostly point at a
+    /// typo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, Info, Server};
+    ///
+    /// let api = AsyncAPI::builder()
+    ///     .info(Info::new("Account Service", "1.0.0"))
+    ///     .server("production", Server::new("broker.example.com:9092", "kafak"))
+    ///     .build();
+    /// let warnings = api.protocol_warnings();
+    /// assert_eq!(warnings[0].path, "/servers/production/protocol");
+    /// ```
+    pub fn protocol_warnings(&self) -> Vec<ValidationError> {
+        let components = self.components.iter().flat_map(|components| {
+            components
+                .servers
+                .iter()
+                .map(|(name, server)| ("/components/servers", name, server))
+        });
+        self.servers
+            .iter()
+            .map(|(name, server)| ("/servers", name, server))
+            .chain(components)
+            .filter_map(|(path, name, server)| match server {
+                ReferenceOr::Item(server) if !server.has_known_protocol() => {
+                    Some(ValidationError::new(
+                        format!("{}/protocol", validation::join_pointer(path, name)),
+                        format!("`{}` is not a known protocol", server.protocol),
+                    ))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the places in the examples of messages that
+    /// [validation][AsyncAPI::validate] could not check against their schema,
+    /// e.g. because the schema has a `pattern` or references another
+    /// document. Such an example may not match its schema even though the
+    /// document is valid.
+    ///
+    /// This method is only available with the `jsonschema` feature
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{validation::Validate, AsyncAPI};
+    ///
+    /// let api: AsyncAPI = r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         payload:
+    ///           type: string
+    ///           format: email
+    ///         examples:
+    ///           - payload: alice@example.com
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    /// assert!(api.validate().is_ok());
+    /// let warnings = api.example_warnings();
+    /// assert_eq!(
+    ///     warnings[0].path,
+    ///     "/channels/user~1signedup/subscribe/message/examples/0/payload"
+    /// );
+    /// ```
+    #[cfg(feature = "jsonschema")]
+    pub fn example_warnings(&self) -> Vec<ValidationError> {
+        let mut unchecked = Vec::new();
+        check_examples(self, "", &mut Vec::new(), &mut unchecked);
+        unchecked
+    }
+
+    /// Returns a copy of the document seen from the clients of the application,
+    /// with the `publish` and `subscribe` operations of every channel swapped.
+    ///
+    /// Client generators use this to describe what a client sends and receives
+    /// instead of what the application does, see [`OperationKind`]. Channels in
+    /// the [Components Object][Components] are inverted as well.
+    pub fn invert_perspective(&self) -> AsyncAPI {
+        let mut api = self.clone();
+        let components = api
+            .components
+            .iter_mut()
+            .flat_map(|c| c.channels.values_mut());
+        for channel in api.channels.values_mut().chain(components) {
+            std::mem::swap(&mut channel.publish, &mut channel.subscribe);
+        }
+        api
+    }
+
+    /// Resolves the security requirements of the server called `name` to the
+    /// security schemes declared in the [Components Object][Components].
+    ///
+    /// The outer list holds the alternatives, only one of them needs to be
+    /// satisfied. All schemes of an alternative are required at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use asyncapi::{AsyncAPI, SecurityScheme};
+    /// let api: AsyncAPI = r#"
+    /// asyncapi: 2.3.0
+    /// info:
+    ///   title: Streetlights API
+    ///   version: 1.0.0
+    /// servers:
+    ///   production:
+    ///     url: broker.example.com
+    ///     protocol: kafka-secure
+    ///     security:
+    ///       - saslScram: []
+    /// channels: {}
+    /// components:
+    ///   securitySchemes:
+    ///     saslScram:
+    ///       type: scramSha256
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let security = api.server_security("production").unwrap();
+    /// assert_eq!(security[0][0].name, "saslScram");
+    /// assert!(matches!(
+    ///     security[0][0].scheme,
+    ///     SecurityScheme::ScramSha256 { .. }
+    /// ));
+    /// ```
+    pub fn server_security(
+        &self,
+        name: &str,
+    ) -> Result<Vec<Vec<SecuritySchemeRequirement<'_>>>, Error> {
+        let components = self.components.as_ref();
+        let server = self
+            .servers
+            .get(name)
+            .ok_or_else(|| ResolveError::NotFound(format!("#/servers/{name}")))?;
+        let server = resolve_local(
+            components.map(|components| &components.servers),
+            "#/components/servers/",
+            server,
+        )?;
+
+        let security_schemes = components.map(|components| &components.security_schemes);
+        let mut alternatives = Vec::new();
+        for requirement in &server.security {
+            let mut schemes = Vec::new();
+            for (name, scopes) in &requirement.values {
+                let scheme = security_schemes
+                    .and_then(|security_schemes| security_schemes.get(name))
+                    .ok_or_else(|| {
+                        ResolveError::NotFound(format!("#/components/securitySchemes/{name}"))
+                    })?;
+                schemes.push(SecuritySchemeRequirement {
+                    name,
+                    scheme: resolve_local(
+                        security_schemes,
+                        "#/components/securitySchemes/",
+                        scheme,
+                    )?,
+                    scopes,
+                });
+            }
+            alternatives.push(schemes);
+        }
+        Ok(alternatives)
+    }
+
+    /// A copy of the document with every reference to a location in the
+    /// document replaced by a copy of what it points to, so it can be read
+    /// without resolving references. References to other documents are kept,
+    /// as are `$ref`s in examples, defaults, enums, constants and extensions,
+    /// which are data rather than references.
+    ///
+    /// Fails if a reference points to nothing, or if references lead back to
+    /// themselves, like a schema that contains itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, OperationMessageType, ReferenceOr};
+    ///
+    /// let api: AsyncAPI = r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         $ref: '#/components/messages/UserSignedUp'
+    /// components:
+    ///   messages:
+    ///     UserSignedUp:
+    ///       name: UserSignedUp
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let api = api.dereference().unwrap();
+    /// let operation = api.channels["user/signedup"].subscribe.as_ref().unwrap();
+    /// assert!(matches!(
+    ///     &operation.message,
+    ///     Some(OperationMessageType::Single(ReferenceOr::Item(message)))
+    ///         if message.name.as_deref() == Some("UserSignedUp")
+    /// ));
+    /// ```
+    pub fn dereference(&self) -> Result<AsyncAPI, Error> {
+        let root = serde_json::to_value(self)
+            .map_err(|error| Error::Serialize(FormatError::Json(error)))?;
+        let mut document = root.clone();
+        dereference_value(&root, &mut document, ObjectKeys::Fields, &mut Vec::new())?;
+        AsyncAPI::deserialize(document).map_err(|error| Error::Parse(FormatError::Json(error)))
+    }
+}
+
+/// Replaces the references to locations in `root` within `value`, an object
+/// with `keys` if any, by copies of their targets, `stack` holding the
+/// references being replaced. Examples and other literal data are kept as
+/// they are.
+fn dereference_value(
+    root: &serde_json::Value,
+    value: &mut serde_json::Value,
+    keys: ObjectKeys,
+    stack: &mut Vec<String>,
+) -> Result<(), ResolveError> {
+    match value {
+        serde_json::Value::Object(object) => {
+            let reference = match object.get("$ref") {
+                Some(serde_json::Value::String(reference)) if keys != ObjectKeys::Names => {
+                    Some(reference)
+                }
+                _ => None,
+            };
+            if let Some(reference) = reference {
+                let Some(pointer) = reference.strip_prefix('#') else {
+                    return Ok(());
+                };
+                if stack.contains(reference) {
+                    return Err(ResolveError::Cycle(reference.clone()));
+                }
+                let mut target = root
+                    .pointer(pointer)
+                    .ok_or_else(|| ResolveError::NotFound(reference.clone()))?
+                    .clone();
+                stack.push(reference.clone());
+                dereference_value(root, &mut target, ObjectKeys::Fields, stack)?;
+                stack.pop();
+                *value = target;
+            } else {
+                for (key, value) in object.iter_mut() {
+                    if let Some(keys) = keys.child(key) {
+                        dereference_value(root, value, keys, stack)?;
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                dereference_value(root, value, ObjectKeys::Fields, stack)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Builds an [`AsyncAPI`] document, see [`AsyncAPI::builder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncAPIBuilder {
+    api: AsyncAPI,
+}
+
+impl AsyncAPIBuilder {
+    /// Sets the [AsyncAPI version][AsyncAPI::asyncapi].
+    pub fn asyncapi(mut self, asyncapi: SpecVersion) -> Self {
+        self.api.asyncapi = asyncapi;
+        self
+    }
+
+    /// Sets the [identifier][AsyncAPI::id] of the application.
+    pub fn id(mut self, id: impl Into<Identifier>) -> Self {
+        self.api.id = Some(id.into());
+        self
+    }
+
+    /// Sets the [metadata][AsyncAPI::info] of the API.
+    pub fn info(mut self, info: Info) -> Self {
+        self.api.info = info;
+        self
+    }
+
+    /// Adds a [server][AsyncAPI::servers], replacing one of the same name.
+    pub fn server(mut self, name: impl Into<String>, server: Server) -> Self {
+        self.api
+            .servers
+            .insert(name.into(), ReferenceOr::Item(server));
+        self
+    }
+
+    /// Sets the [default content type][AsyncAPI::default_content_type].
+    pub fn default_content_type(mut self, content_type: impl Into<MediaType>) -> Self {
+        self.api.default_content_type = Some(content_type.into());
+        self
+    }
+
+    /// Adds a [channel][AsyncAPI::channels], replacing one of the same name.
+    pub fn channel(mut self, name: impl Into<String>, channel: Channel) -> Self {
+        self.api.channels.insert(name.into(), channel);
+        self
+    }
+
+    /// Sets the [components][AsyncAPI::components].
+    pub fn components(mut self, components: Components) -> Self {
+        self.api.components = Some(components);
+        self
+    }
+
+    /// Adds a [tag][AsyncAPI::tags].
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.api.tags.push(tag);
+        self
+    }
+
+    /// Sets the [external documentation][AsyncAPI::external_docs].
+    pub fn external_docs(mut self, external_docs: ExternalDocumentation) -> Self {
+        self.api.external_docs = Some(external_docs);
+        self
+    }
+
+    /// Adds a [specification extension][AsyncAPI::extensions]. `name` should
+    /// start with `x-`.
+    pub fn extension(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.api.extensions.insert(name.into(), value);
+        self
+    }
+
+    pub fn build(self) -> AsyncAPI {
+        self.api
+    }
+}
+
+impl Validate for AsyncAPI {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(id) = &self.id {
+            id.validate_at(&format!("{path}/id"), errors);
+        }
+        self.info.validate_at(&format!("{path}/info"), errors);
+        if let Some(content_type) = &self.default_content_type {
+            content_type.validate_at(&format!("{path}/defaultContentType"), errors);
+        }
+        for (name, server) in &self.servers {
+            if let ReferenceOr::Item(server) = server {
+                server.validate_at(
+                    &validation::join_pointer(&format!("{path}/servers"), name),
+                    errors,
+                );
+            }
+        }
+        if let Some(components) = &self.components {
+            components.validate_at(&format!("{path}/components"), errors);
+            for (name, server) in &components.servers {
+                if let ReferenceOr::Item(server) = server {
+                    let path =
+                        validation::join_pointer(&format!("{path}/components/servers"), name);
+                    server.validate_at(&path, errors);
+                }
+            }
+            for (name, message_trait) in &components.message_traits {
+                if let ReferenceOr::Item(message_trait) = message_trait {
+                    let path =
+                        validation::join_pointer(&format!("{path}/components/messageTraits"), name);
+                    message_trait.validate_at(&path, errors);
+                }
+            }
+            for (name, scheme) in &components.security_schemes {
+                if let ReferenceOr::Item(scheme) = scheme {
+                    let path = validation::join_pointer(
+                        &format!("{path}/components/securitySchemes"),
+                        name,
+                    );
+                    scheme.validate_at(&path, errors);
+                }
+            }
+        }
+        for (path, message) in defined_messages(self, path) {
+            message.validate_at(&path, errors);
+            if let Some(Payload::MultiFormat(_)) = &message.payload {
+                if !self.asyncapi.is_v3() {
+                    errors.push(ValidationError::new(
+                        format!("{path}/payload/schemaFormat"),
+                        format!(
+                            "a payload with its own schemaFormat requires version 3.0.0, not {}",
+                            self.asyncapi
+                        ),
+                    ));
+                }
+            }
+        }
+        validate_bindings(self, path, errors);
+        validate_channel_servers(self, path, errors);
+        validate_channel_parameters(self, path, errors);
+        validate_security_requirements(self, path, errors);
+        validate_operation_ids(self, path, errors);
+        validate_message_ids(self, path, errors);
+        validate_references(self, path, errors);
+        #[cfg(feature = "jsonschema")]
+        validate_examples(self, path, errors);
+    }
+}
+
+/// The messages of `operation`, with their paths below the operation's
+/// `path`.
+fn operation_messages<'a>(
+    path: &str,
+    operation: &'a Operation,
+) -> Vec<(String, &'a ReferenceOr<Message>)> {
+    match &operation.message {
+        Some(OperationMessageType::Single(message)) => {
+            vec![(format!("{path}/message"), message)]
+        }
+        Some(OperationMessageType::OneOf { one_of }) => one_of
+            .iter()
+            .enumerate()
+            .map(|(index, message)| (format!("{path}/message/oneOf/{index}"), message))
+            .collect(),
+        Some(OperationMessageType::Map(messages)) => messages
+            .iter()
+            .map(|(name, message)| {
+                (
+                    validation::join_pointer(&format!("{path}/message"), name),
+                    message,
+                )
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Checks that the servers listed by channels are defined.
+fn validate_channel_servers(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    for (name, channel) in &api.channels {
+        let path = validation::join_pointer(&format!("{path}/channels"), name);
+        for (index, server) in channel.servers.iter().enumerate() {
+            if !api.servers.contains_key(server) {
+                errors.push(ValidationError::new(
+                    format!("{path}/servers/{index}"),
+                    format!("server `{server}` is not defined in servers"),
+                ));
+            }
+        }
+    }
+}
+
+/// Checks that the `{name}` placeholders of channel names and the
+/// parameters of the channels match.
+fn validate_channel_parameters(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    for (name, channel) in &api.channels {
+        let path = validation::join_pointer(&format!("{path}/channels"), name);
+        let placeholders = validation::placeholders(name);
+        for placeholder in &placeholders {
+            if !channel.parameters.contains_key(*placeholder) {
+                errors.push(ValidationError::new(
+                    path.clone(),
+                    format!("parameter `{placeholder}` is not declared in parameters"),
+                ));
+            }
+        }
+        for parameter in channel.parameters.keys() {
+            if !placeholders.contains(&parameter.as_str()) {
+                errors.push(ValidationError::new(
+                    validation::join_pointer(&format!("{path}/parameters"), parameter),
+                    format!("parameter `{parameter}` does not appear in the channel name"),
+                ));
+            }
+        }
+    }
+}
+
+/// Checks that no two operations have the same `operationId`. Each
+/// duplicate is reported at the later operation and names the first one.
+fn validate_operation_ids(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    let mut first_uses = HashMap::new();
+    for (channel, kind, operation) in api.operations() {
+        let Some(operation_id) = &operation.operation_id else {
+            continue;
+        };
+        let path = format!(
+            "{}/{}",
+            validation::join_pointer(&format!("{path}/channels"), channel),
+            kind.as_str()
+        );
+        match first_uses.get(operation_id.as_str()) {
+            Some(first) => errors.push(ValidationError::new(
+                format!("{path}/operationId"),
+                format!("operationId `{operation_id}` is already used by {first}"),
+            )),
+            None => {
+                first_uses.insert(operation_id.as_str(), path);
+            }
+        }
+    }
+}
+
+/// The messages defined inline in operations or in the components, with
+/// their paths below `path`. References to messages are left out.
+fn defined_messages<'a>(api: &'a AsyncAPI, path: &str) -> Vec<(String, &'a Message)> {
+    let mut messages = Vec::new();
+    for (name, channel) in &api.channels {
+        let path = validation::join_pointer(&format!("{path}/channels"), name);
+        for (kind, operation) in channel.operations() {
+            messages.extend(operation_messages(
+                &format!("{path}/{}", kind.as_str()),
+                operation,
+            ));
+        }
+    }
+    for (name, message) in api
+        .components
+        .iter()
+        .flat_map(|components| &components.messages)
+    {
+        let path = validation::join_pointer(&format!("{path}/components/messages"), name);
+        messages.push((path, message));
+    }
+    messages
+        .into_iter()
+        .filter_map(|(path, message)| match message {
+            ReferenceOr::Item(message) => Some((path, message)),
+            ReferenceOr::Reference { .. } => None,
+        })
+        .collect()
+}
+
+/// Checks that no two messages defined in the document have the same
+/// `messageId` or `name`, and that `messageId` is only used from version
+/// 2.4.0 on. Referenced messages are checked where they are defined.
+fn validate_message_ids(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    let messages = defined_messages(api, path);
+    let mut first_ids = HashMap::new();
+    let mut first_names = HashMap::new();
+    for (path, message) in &messages {
+        if let Some(message_id) = &message.message_id {
+            if !api.asyncapi.supports_message_id() {
+                errors.push(ValidationError::new(
+                    format!("{path}/messageId"),
+                    format!(
+                        "messageId requires a 2.x version from 2.4.0 on, not {}",
+                        api.asyncapi
+                    ),
+                ));
+            }
+            match first_ids.get(message_id.as_str()) {
+                Some(first) => errors.push(ValidationError::new(
+                    format!("{path}/messageId"),
+                    format!("messageId `{message_id}` is already used by {first}"),
+                )),
+                None => {
+                    first_ids.insert(message_id.as_str(), path);
+                }
+            }
+        }
+        if let Some(name) = &message.name {
+            match first_names.get(name.as_str()) {
+                Some(first) => errors.push(ValidationError::new(
+                    format!("{path}/name"),
+                    format!("message name `{name}` is already used by {first}"),
+                )),
+                None => {
+                    first_names.insert(name.as_str(), path);
+                }
+            }
+        }
+    }
+}
+
+/// Checks that every reference to a location in the document points to
+/// something. References to other documents can't be checked here and are
+/// skipped, as are `$ref`s in examples and other literal data.
+fn validate_references(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    if let Ok(root) = serde_json::to_value(api) {
+        check_references(&root, &root, ObjectKeys::Fields, path, errors);
+    }
+}
+
+fn check_references(
+    root: &serde_json::Value,
+    value: &serde_json::Value,
+    keys: ObjectKeys,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    match value {
+        serde_json::Value::Object(object) => {
+            let reference = match object.get("$ref") {
+                Some(serde_json::Value::String(reference)) if keys != ObjectKeys::Names => {
+                    Some(reference)
+                }
+                _ => None,
+            };
+            if let Some(reference) = reference {
+                if let Some(pointer) = reference.strip_prefix('#') {
+                    if root.pointer(pointer).is_none() {
+                        errors.push(ValidationError::new(
+                            format!("{path}/$ref"),
+                            format!("reference `{reference}` points to nothing"),
+                        ));
+                    }
+                }
+            }
+            for (key, value) in object {
+                if let Some(keys) = keys.child(key) {
+                    let path = validation::join_pointer(path, key);
+                    check_references(root, value, keys, &path, errors);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let path = format!("{path}/{index}");
+                check_references(root, value, ObjectKeys::Fields, &path, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Checks the payload and headers of the examples of the messages defined
+/// in the document against the schemas of their message. Payloads in a
+/// schema format other than JSON Schema are skipped.
+#[cfg(feature = "jsonschema")]
+fn validate_examples(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    check_examples(api, path, errors, &mut Vec::new());
+}
+
+/// Checks the examples like [`validate_examples`], appending the parts of
+/// the schemas that were not checked to `unchecked`.
+#[cfg(feature = "jsonschema")]
+fn check_examples(
+    api: &AsyncAPI,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+    unchecked: &mut Vec<ValidationError>,
+) {
+    let Ok(root) = serde_json::to_value(api) else {
+        return;
+    };
+    for (message_path, message) in defined_messages(api, "") {
+        let Some(value) = root.pointer(&message_path) else {
+            continue;
+        };
+        let payload = value.get("payload").and_then(|payload| {
+            match (payload.get("schemaFormat"), payload.get("schema")) {
+                (Some(serde_json::Value::String(format)), Some(schema)) => {
+                    crate::json_schema::is_json_schema_format(Some(format)).then_some(schema)
+                }
+                _ => crate::json_schema::is_json_schema_format(message.schema_format.as_deref())
+                    .then_some(payload),
+            }
+        });
+        let schemas = [("payload", payload), ("headers", value.get("headers"))];
+        for (index, example) in message.examples.iter().enumerate() {
+            let examples = [("payload", &example.payload), ("headers", &example.headers)];
+            for ((part, schema), (_, instance)) in schemas.iter().zip(examples) {
+                if let (Some(schema), Some(instance)) = (schema, instance) {
+                    let path = format!("{path}{message_path}/examples/{index}/{part}");
+                    crate::json_schema::check(&root, schema, instance, &path, errors, unchecked);
+                }
+            }
+        }
+    }
+}
+
+/// Checks that security requirements name security schemes of the components
+/// and only list scopes for OAuth 2 and OpenID Connect schemes.
+fn validate_security_requirements(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    let mut requirements = Vec::new();
+    for (name, server) in &api.servers {
+        if let ReferenceOr::Item(server) = server {
+            let path = validation::join_pointer(&format!("{path}/servers"), name);
+            requirements.push((format!("{path}/security"), &server.security));
+        }
+    }
+    for (name, channel) in &api.channels {
+        let path = validation::join_pointer(&format!("{path}/channels"), name);
+        for (kind, operation) in channel.operations() {
+            let path = format!("{path}/{}", kind.as_str());
+            requirements.push((format!("{path}/security"), &operation.security));
+            for (index, operation_trait) in operation.traits.iter().enumerate() {
+                if let ReferenceOr::Item(operation_trait) = operation_trait {
+                    let path = format!("{path}/traits/{index}/security");
+                    requirements.push((path, &operation_trait.security));
+                }
+            }
+        }
+    }
+    let components = api.components.as_ref();
+    for (name, operation_trait) in components
+        .into_iter()
+        .flat_map(|components| &components.operation_traits)
+    {
+        if let ReferenceOr::Item(operation_trait) = operation_trait {
+            let path =
+                validation::join_pointer(&format!("{path}/components/operationTraits"), name);
+            requirements.push((format!("{path}/security"), &operation_trait.security));
+        }
+    }
+
+    let schemes = components.map(|components| &components.security_schemes);
+    for (path, requirements) in requirements {
+        for (index, requirement) in requirements.iter().enumerate() {
+            for (name, scopes) in &requirement.values {
+                let path = validation::join_pointer(&format!("{path}/{index}"), name);
+                match schemes.and_then(|schemes| schemes.get(name)) {
+                    None => errors.push(ValidationError::new(
+                        path,
+                        format!("security scheme `{name}` is not defined in components"),
+                    )),
+                    Some(ReferenceOr::Item(
+                        SecurityScheme::OAuth2 { .. } | SecurityScheme::OpenIdConnect { .. },
+                    ))
+                    | Some(ReferenceOr::Reference { .. }) => {}
+                    Some(ReferenceOr::Item(_)) if !scopes.is_empty() => {
+                        errors.push(ValidationError::new(
+                            path,
+                            "scopes are only allowed for oauth2 and openIdConnect schemes",
+                        ))
+                    }
+                    Some(ReferenceOr::Item(_)) => {}
+                }
+            }
+        }
+    }
+}
+
+/// Checks the inline binding objects of the document against the rules of
+/// their binding specifications: those of servers, channels, operations,
+/// operation traits, messages and message traits, both in the document and
+/// in its components. Referenced bindings are checked where they are defined.
+fn validate_bindings(api: &AsyncAPI, path: &str, errors: &mut Vec<ValidationError>) {
+    for (name, server) in &api.servers {
+        if let ReferenceOr::Item(server) = server {
+            let path = validation::join_pointer(&format!("{path}/servers"), name);
+            validate_inline(
+                server.bindings.as_ref(),
+                &format!("{path}/bindings"),
+                errors,
+            );
+        }
+    }
+    for (name, channel) in &api.channels {
+        let path = validation::join_pointer(&format!("{path}/channels"), name);
+        validate_channel_bindings(channel, &path, errors);
+    }
+
+    let Some(components) = &api.components else {
+        return;
+    };
+    let path = format!("{path}/components");
+    for (name, server) in &components.servers {
+        if let ReferenceOr::Item(server) = server {
+            let path = validation::join_pointer(&format!("{path}/servers"), name);
+            validate_inline(
+                server.bindings.as_ref(),
+                &format!("{path}/bindings"),
+                errors,
+            );
+        }
+    }
+    for (name, channel) in &components.channels {
+        let path = validation::join_pointer(&format!("{path}/channels"), name);
+        validate_channel_bindings(channel, &path, errors);
+    }
+    for (name, message) in &components.messages {
+        if let ReferenceOr::Item(message) = message {
+            let path = validation::join_pointer(&format!("{path}/messages"), name);
+            validate_message_bindings(message, &path, errors);
+        }
+    }
+    for (name, operation_trait) in &components.operation_traits {
+        if let ReferenceOr::Item(operation_trait) = operation_trait {
+            let path = validation::join_pointer(&format!("{path}/operationTraits"), name);
+            validate_inline(
+                operation_trait.bindings.as_ref(),
+                &format!("{path}/bindings"),
+                errors,
+            );
+        }
+    }
+    for (name, message_trait) in &components.message_traits {
+        if let ReferenceOr::Item(message_trait) = message_trait {
+            let path = validation::join_pointer(&format!("{path}/messageTraits"), name);
+            validate_inline(
+                message_trait.bindings.as_ref(),
+                &format!("{path}/bindings"),
+                errors,
+            );
+        }
+    }
+    for (name, bindings) in &components.server_bindings {
+        let path = validation::join_pointer(&format!("{path}/serverBindings"), name);
+        validate_inline(Some(bindings), &path, errors);
+    }
+    for (name, bindings) in &components.channel_bindings {
+        let path = validation::join_pointer(&format!("{path}/channelBindings"), name);
+        validate_inline(Some(bindings), &path, errors);
+    }
+    for (name, bindings) in &components.operation_bindings {
+        let path = validation::join_pointer(&format!("{path}/operationBindings"), name);
+        validate_inline(Some(bindings), &path, errors);
+    }
+    for (name, bindings) in &components.message_bindings {
+        let path = validation::join_pointer(&format!("{path}/messageBindings"), name);
+        validate_inline(Some(bindings), &path, errors);
+    }
+}
+
+/// Checks the bindings of `channel` at `path`, of its operations and their
+/// traits and of their inline messages.
+fn validate_channel_bindings(channel: &Channel, path: &str, errors: &mut Vec<ValidationError>) {
+    validate_inline(
+        channel.bindings.as_ref(),
+        &format!("{path}/bindings"),
+        errors,
+    );
+    for (kind, operation) in channel.operations() {
+        let path = format!("{path}/{}", kind.as_str());
+        validate_inline(
+            operation.bindings.as_ref(),
+            &format!("{path}/bindings"),
+            errors,
+        );
+        for (index, operation_trait) in operation.traits.iter().enumerate() {
+            if let ReferenceOr::Item(operation_trait) = operation_trait {
+                let path = format!("{path}/traits/{index}/bindings");
+                validate_inline(operation_trait.bindings.as_ref(), &path, errors);
+            }
+        }
+        for (path, message) in operation_messages(&path, operation) {
+            if let ReferenceOr::Item(message) = message {
+                validate_message_bindings(message, &path, errors);
+            }
+        }
+    }
+}
+
+/// Checks the bindings of `message` at `path` and of its inline traits.
+fn validate_message_bindings(message: &Message, path: &str, errors: &mut Vec<ValidationError>) {
+    validate_inline(
+        message.bindings.as_ref(),
+        &format!("{path}/bindings"),
+        errors,
+    );
+    for (index, message_trait) in message.traits.iter().enumerate() {
+        if let ReferenceOr::Item(message_trait) = message_trait {
+            let path = format!("{path}/traits/{index}/bindings");
+            validate_inline(message_trait.bindings.as_ref(), &path, errors);
+        }
+    }
+}
+
+/// Validates `item` at `path` unless it is missing or a reference.
+fn validate_inline<T: Validate>(
+    item: Option<&ReferenceOr<T>>,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(ReferenceOr::Item(item)) = item {
+        item.validate_at(path, errors);
+    }
+}
+
+/// Parses a document from YAML or JSON.
+///
+/// # Examples
+///
+/// ```
+/// let api: asyncapi::AsyncAPI = r#"
+/// asyncapi: 2.3.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels: {}
+/// "#
+/// .parse()
+/// .unwrap();
+/// assert_eq!(api.info.title, "Account Service");
+/// ```
+impl FromStr for AsyncAPI {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        serde_yaml::from_str(input).map_err(|error| Error::Parse(FormatError::Yaml(error)))
+    }
+}
+
+#[test]
+fn test_invert_perspective() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.3.0
+    info:
+      title: Streetlights API
+      version: 1.0.0
+    channels:
+      light/measured:
+        publish:
+          operationId: receiveLightMeasurement
+      light/turn-on:
+        subscribe:
+          operationId: turnOn
+    "#
+    .parse()
+    .unwrap();
+
+    let inverted = api.invert_perspective();
+    let operations: Vec<_> = inverted
+        .operations()
+        .map(|(channel, kind, operation)| (channel, kind, operation.operation_id.as_deref()))
+        .collect();
+    assert_eq!(
+        operations,
+        [
+            (
+                "light/measured",
+                OperationKind::Subscribe,
+                Some("receiveLightMeasurement")
+            ),
+            ("light/turn-on", OperationKind::Publish, Some("turnOn")),
+        ]
+    );
+    assert_eq!(inverted.invert_perspective(), api);
+}
+
+#[test]
+fn test_validate_bindings() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Streetlights API
+      version: 1.0.0
+    channels:
+      light/measured:
+        bindings:
+          ws:
+            method: PUT
+        publish:
+          bindings:
+            mqtt:
+              qos: 3
+    components:
+      channels:
+        dimmed:
+          subscribe:
+            message:
+              name: dimLight
+              bindings:
+                http:
+                  statusCode: 42
+              traits:
+                - bindings:
+                    ibmmq:
+                      type: text
+      messageTraits:
+        websocket:
+          bindings:
+            ws:
+              method: PUT
+      channelBindings:
+        devices:
+          ibmmq:
+            queue:
+              objectName: DEVICES.QUEUE.WITH.A.NAME.LONGER.THAN.FORTY.EIGHT.CHARACTERS
+    "#
+    .parse()
+    .unwrap();
+
+    let mut errors = Vec::new();
+    api.validate_at("", &mut errors);
+    let paths: Vec<_> = errors.iter().map(|error| error.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        [
+            "/channels/light~1measured/bindings/ws/method",
+            "/channels/light~1measured/publish/bindings/mqtt/qos",
+            "/components/channels/dimmed/subscribe/message/bindings/http/statusCode",
+            "/components/channels/dimmed/subscribe/message/traits/0/bindings/ibmmq/type",
+            "/components/messageTraits/websocket/bindings/ws/method",
+            "/components/channelBindings/devices/ibmmq/queue/objectName",
+        ]
+    );
+}
+
+#[test]
+fn test_dereference() {
+    let api: AsyncAPI = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            payload:
+              $ref: '#/components/schemas/User'
+    components:
+      schemas:
+        User:
+          type: object
+          properties:
+            address:
+              $ref: '#/components/schemas/Address'
+            group:
+              $ref: 'common.yaml#/components/schemas/Group'
+        Address:
+          type: string
+    "##
+    .parse()
+    .unwrap();
+
+    let dereferenced = serde_json::to_value(api.dereference().unwrap()).unwrap();
+    let payload = &dereferenced["channels"]["user/signedup"]["subscribe"]["message"]["payload"];
+    assert_eq!(payload["properties"]["address"]["type"], "string");
+    assert_eq!(
+        payload["properties"]["group"]["$ref"],
+        "common.yaml#/components/schemas/Group"
+    );
+
+    let mut cyclic = api.clone();
+    let components = cyclic.components.as_mut().unwrap();
+    components.schemas["Address"] = ReferenceOr::ref_("#/components/schemas/User");
+    assert!(matches!(
+        cyclic.dereference(),
+        Err(Error::Resolve(ResolveError::Cycle(reference))) if reference == "#/components/schemas/User"
+    ));
+}
+
+#[test]
+fn test_validate_cross_references() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    servers:
+      production:
+        url: broker.example.com
+        protocol: kafka
+        security:
+          - sasl: [admin]
+          - missing: []
+    channels:
+      user/signedup:
+        servers: [production, staging]
+        subscribe:
+          security:
+            - oauth: [read]
+    components:
+      securitySchemes:
+        sasl:
+          type: scramSha256
+        oauth:
+          type: oauth2
+          flows:
+            clientCredentials:
+              tokenUrl: https://example.com/token
+              scopes:
+                read: Read access
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    let paths: Vec<_> = errors.iter().map(|error| error.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        [
+            "/channels/user~1signedup/servers/1",
+            "/servers/production/security/0/sasl",
+            "/servers/production/security/1/missing",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_channel_parameters() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/{userId}/{action}:
+        parameters:
+          userId:
+            description: Id of the user.
+          unused:
+            description: Not in the name.
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    assert_eq!(
+        errors,
+        [
+            ValidationError::new(
+                "/channels/user~1{userId}~1{action}",
+                "parameter `action` is not declared in parameters"
+            ),
+            ValidationError::new(
+                "/channels/user~1{userId}~1{action}/parameters/unused",
+                "parameter `unused` does not appear in the channel name"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_operation_ids() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          operationId: onUser
+        publish:
+          operationId: signUp
+      user/deleted:
+        subscribe:
+          operationId: onUser
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    assert_eq!(
+        errors,
+        [ValidationError::new(
+            "/channels/user~1deleted/subscribe/operationId",
+            "operationId `onUser` is already used by /channels/user~1signedup/subscribe"
+        )]
+    );
+}
+
+#[test]
+fn test_validate_multi_format_payload() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels: {}
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            schemaFormat: application/vnd.apache.avro;version=1.9.0
+            schema:
+              type: record
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    assert_eq!(
+        errors,
+        [ValidationError::new(
+            "/components/messages/UserSignedUp/payload/schemaFormat",
+            "a payload with its own schemaFormat requires version 3.0.0, not 2.6.0"
+        )]
+    );
+}
+
+#[test]
+fn test_validate_message_ids() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+      user/deleted:
+        subscribe:
+          message:
+            messageId: userSignedUp
+            name: UserDeleted
+    components:
+      messages:
+        UserSignedUp:
+          messageId: userSignedUp
+          name: UserDeleted
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    assert_eq!(
+        errors,
+        [
+            ValidationError::new(
+                "/components/messages/UserSignedUp/messageId",
+                "messageId `userSignedUp` is already used by /channels/user~1deleted/subscribe/message"
+            ),
+            ValidationError::new(
+                "/components/messages/UserSignedUp/name",
+                "message name `UserDeleted` is already used by /channels/user~1deleted/subscribe/message"
+            ),
+        ]
+    );
+
+    let mut api = api;
+    api.asyncapi = SpecVersion::V2_3_0;
+    api.components = None;
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    assert_eq!(
+        errors[0].message,
+        "messageId requires a 2.x version from 2.4.0 on, not 2.3.0"
+    );
+}
+
+#[test]
+fn test_validate_references() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            oneOf:
+              - $ref: '#/components/messages/UserSignedUp'
+              - $ref: '#/components/messages/UserDeleted'
+              - $ref: 'common.yaml#/components/messages/UserUpdated'
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            $ref: '#/components/schemas/User'
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the document is invalid");
+    };
+    assert_eq!(
+        errors,
+        [
+            ValidationError::new(
+                "/channels/user~1signedup/subscribe/message/oneOf/1/$ref",
+                "reference `#/components/messages/UserDeleted` points to nothing"
+            ),
+            ValidationError::new(
+                "/components/messages/UserSignedUp/payload/$ref",
+                "reference `#/components/schemas/User` points to nothing"
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "jsonschema")]
+#[test]
+fn test_validate_examples() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+      user/avro:
+        subscribe:
+          message:
+            schemaFormat: application/vnd.apache.avro;version=1.9.0
+            payload:
+              type: record
+            examples:
+              - payload: anything
+    components:
+      messages:
+        UserSignedUp:
+          headers:
+            type: object
+            properties:
+              version:
+                type: integer
+          payload:
+            $ref: '#/components/schemas/User'
+          examples:
+            - payload:
+                name: Alice
+            - headers:
+                version: '1'
+              payload:
+                id: 1
+      schemas:
+        User:
+          type: object
+          required: [id]
+          properties:
+            id:
+              type: integer
+    "#
+    .parse()
+    .unwrap();
+
+    let Err(Error::Validate(errors)) = api.validate() else {
+        panic!("the examples are invalid");
+    };
+    assert_eq!(
+        errors,
+        [
+            ValidationError::new(
+                "/components/messages/UserSignedUp/examples/0/payload",
+                "property `id` is required"
+            ),
+            ValidationError::new(
+                "/components/messages/UserSignedUp/examples/1/headers/version",
+                "expected integer, found string"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_references_in_literal_data() {
+    let api: AsyncAPI = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+    components:
+      messages:
+        UserSignedUp:
+          x-meta:
+            $ref: '#/not/a/ref'
+          payload:
+            $ref: '#/components/schemas/User'
+          examples:
+            - payload:
+                $ref: '#/definitions/Foo'
+      schemas:
+        User:
+          type: object
+          default:
+            $ref: '#/not/a/ref'
+          properties:
+            default:
+              $ref: '#/components/schemas/Name'
+        Name:
+          type: string
+    "##
+    .parse()
+    .unwrap();
+
+    let result = api.validate();
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn test_dereference_keeps_literal_data() {
+    let api: AsyncAPI = r##"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+    components:
+      messages:
+        UserSignedUp:
+          x-meta:
+            $ref: '#/not/a/ref'
+          payload:
+            $ref: '#/components/schemas/User'
+          examples:
+            - payload:
+                $ref: '#/definitions/Foo'
+      schemas:
+        User:
+          type: object
+          default:
+            $ref: '#/not/a/ref'
+          properties:
+            default:
+              $ref: '#/components/schemas/Name'
+        Name:
+          type: string
+    "##
+    .parse()
+    .unwrap();
+
+    let dereferenced = serde_json::to_value(api.dereference().unwrap()).unwrap();
+    let message = &dereferenced["components"]["messages"]["UserSignedUp"];
+    assert_eq!(message["x-meta"]["$ref"], "#/not/a/ref");
+    assert_eq!(
+        message["examples"][0]["payload"]["$ref"],
+        "#/definitions/Foo"
+    );
+    assert_eq!(message["payload"]["default"]["$ref"], "#/not/a/ref");
+    assert_eq!(
+        message["payload"]["properties"]["default"]["type"],
+        "string"
+    );
+}
+
+#[test]
+fn test_new_supports_message_ids() {
+    let message = Message {
+        message_id: Some("userSignedUp".to_string()),
+        ..Default::default()
+    };
+    let operation = Operation {
+        message: Some(OperationMessageType::Single(ReferenceOr::Item(message))),
+        ..Default::default()
+    };
+    let channel = Channel {
+        subscribe: Some(operation),
+        ..Default::default()
+    };
+
+    let mut api = AsyncAPI::new("Account Service", "1.0.0");
+    api.channels.insert("user/signedup".to_string(), channel);
+    let result = api.validate();
+    assert!(result.is_ok(), "{result:?}");
+
+    let api = AsyncAPI::builder()
+        .info(Info::new("Account Service", "1.0.0"))
+        .build();
+    assert_eq!(api.asyncapi, SpecVersion::V2_6_0);
+}
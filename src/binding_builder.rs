@@ -0,0 +1,57 @@
+//! Builders for the binding objects, so they can be constructed without
+//! spelling out every field.
+
+/// Implements `builder()` for binding objects and defines their builders.
+///
+/// The builder has a setter for each listed field. Setters of `Option` fields
+/// take the value itself, setters of other fields take anything that converts
+/// into the field type. Fields that aren't listed keep their default.
+macro_rules! binding_builder {
+    ($($ty:ident => $builder:ident { $($fields:tt)* })*) => {
+        $(
+            impl $ty {
+                #[doc = concat!("Starts building a [`", stringify!($ty), "`].")]
+                pub fn builder() -> $builder {
+                    $builder::default()
+                }
+            }
+
+            #[doc = concat!("Builds a [`", stringify!($ty), "`], see [`", stringify!($ty), "::builder`].")]
+            #[derive(Debug, Clone, Default, PartialEq)]
+            pub struct $builder {
+                binding: $ty,
+            }
+
+            impl $builder {
+                pub fn build(self) -> $ty {
+                    self.binding
+                }
+            }
+
+            binding_builder!(@setters $ty $builder, $($fields)*);
+        )*
+    };
+    (@setters $ty:ident $builder:ident, $(,)?) => {};
+    (@setters $ty:ident $builder:ident, $field:ident: Option<$inner:ty> $(, $($rest:tt)*)?) => {
+        impl $builder {
+            #[doc = concat!("Sets [`", stringify!($field), "`][", stringify!($ty), "::", stringify!($field), "].")]
+            pub fn $field(mut self, $field: impl Into<$inner>) -> Self {
+                self.binding.$field = Some($field.into());
+                self
+            }
+        }
+        binding_builder!(@setters $ty $builder, $($($rest)*)?);
+    };
+    (@setters $ty:ident $builder:ident, $field:ident: $field_ty:ty $(, $($rest:tt)*)?) => {
+        impl $builder {
+            #[doc = concat!("Sets [`", stringify!($field), "`][", stringify!($ty), "::", stringify!($field), "].")]
+            pub fn $field(mut self, $field: impl Into<$field_ty>) -> Self {
+                self.binding.$field = $field.into();
+                self
+            }
+        }
+        binding_builder!(@setters $ty $builder, $($($rest)*)?);
+    };
+}
+
+pub(crate) use binding_builder;
@@ -0,0 +1,151 @@
+use std::{cmp::Ordering, convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The `bindingVersion` of a binding object.
+///
+/// A binding without a `bindingVersion`, or with `latest`, uses the latest
+/// version of the binding, which is [`Latest`][BindingVersion::Latest] here.
+/// `Latest` is left out when serializing.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::{channel_binding::KafkaChannelBinding, BindingVersion};
+///
+/// let binding: KafkaChannelBinding = serde_json::from_str("{}").unwrap();
+/// assert!(binding.binding_version.is_latest());
+///
+/// let binding: KafkaChannelBinding =
+///     serde_json::from_str(r#"{"bindingVersion": "0.3.0"}"#).unwrap();
+/// assert_eq!(binding.binding_version, "0.3.0");
+/// assert!(binding.binding_version.is_at_least(0, 3, 0));
+/// assert!(!binding.binding_version.is_at_least(0, 4, 0));
+/// assert!(BindingVersion::Latest.is_at_least(0, 4, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum BindingVersion {
+    #[default]
+    Latest,
+    Version(String),
+}
+
+impl BindingVersion {
+    pub fn new(version: impl Into<String>) -> Self {
+        let version = version.into();
+        if version == "latest" {
+            BindingVersion::Latest
+        } else {
+            BindingVersion::Version(version)
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            BindingVersion::Latest => "latest",
+            BindingVersion::Version(version) => version,
+        }
+    }
+
+    pub fn is_latest(&self) -> bool {
+        matches!(self, BindingVersion::Latest)
+    }
+
+    /// The `major`, `minor` and `patch` part of the version, if it has the
+    /// `major`.`minor`.`patch` form.
+    pub fn parts(&self) -> Option<(u32, u32, u32)> {
+        let BindingVersion::Version(version) = self else {
+            return None;
+        };
+        let mut parts = version.splitn(3, '.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next()?.parse().ok()?;
+        Some((major, minor, patch))
+    }
+
+    /// Whether the version is `major`.`minor`.`patch` or a later one.
+    /// [`Latest`][BindingVersion::Latest] is later than any version, while a
+    /// version that isn't of this form is earlier.
+    pub fn is_at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        self.is_latest()
+            || self
+                .parts()
+                .is_some_and(|version| version >= (major, minor, patch))
+    }
+}
+
+impl PartialOrd for BindingVersion {
+    /// Orders versions of the `major`.`minor`.`patch` form, with
+    /// [`Latest`][BindingVersion::Latest] after all of them. Other versions
+    /// can only be compared for equality.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (BindingVersion::Latest, BindingVersion::Latest) => Some(Ordering::Equal),
+            (BindingVersion::Latest, _) => Some(Ordering::Greater),
+            (_, BindingVersion::Latest) => Some(Ordering::Less),
+            _ if self == other => Some(Ordering::Equal),
+            _ => Some(self.parts()?.cmp(&other.parts()?)),
+        }
+    }
+}
+
+impl FromStr for BindingVersion {
+    type Err = Infallible;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        Ok(BindingVersion::new(version))
+    }
+}
+
+impl From<String> for BindingVersion {
+    fn from(version: String) -> Self {
+        BindingVersion::new(version)
+    }
+}
+
+impl From<&str> for BindingVersion {
+    fn from(version: &str) -> Self {
+        BindingVersion::new(version)
+    }
+}
+
+impl fmt::Display for BindingVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for BindingVersion {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for BindingVersion {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Serialize for BindingVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BindingVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(BindingVersion::new)
+    }
+}
+
+#[test]
+fn test_binding_version_order() {
+    let version = |version: &str| version.parse::<BindingVersion>().unwrap();
+    assert_eq!(version("latest"), BindingVersion::Latest);
+    assert!(version("0.2.0") < version("0.10.0"));
+    assert!(version("0.10.0") < BindingVersion::Latest);
+    assert_eq!(version("next").partial_cmp(&version("0.1.0")), None);
+    assert!(!version("next").is_at_least(0, 0, 0));
+}
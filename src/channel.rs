@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     ChannelBinding, ExternalDocumentation, Message, OperationBinding, OperationTrait, Parameter,
-    ReferenceOr, Tag,
+    ReferenceOr, SecurityRequirement, Tag,
 };
 
 /// Describes the operations available on a single channel.
@@ -161,7 +161,7 @@ pub struct Channel {
     pub bindings: Option<ReferenceOr<ChannelBinding>>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -264,6 +264,15 @@ pub struct Operation {
     /// can be used for rich text representation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// A declaration of which security mechanisms are associated with this
+    /// operation. Only one of the security requirement objects MUST be
+    /// satisfied to authorize an operation. In cases where
+    /// [Server Security][crate::Server::security] also applies, it MUST also
+    /// be satisfied.
+    ///
+    /// Added in AsyncAPI 2.4.0.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<SecurityRequirement>,
     /// A list of tags for API documentation control.
     /// Tags can be used for logical grouping of operations.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -288,13 +297,463 @@ pub struct Operation {
     pub message: Option<OperationMessageType>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+/// The message, or messages, of an [Operation][Operation].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum OperationMessageType {
-    Map(IndexMap<String, ReferenceOr<Message>>),
+    /// Several messages, of which every message MUST be valid against exactly
+    /// one.
+    OneOf {
+        #[serde(rename = "oneOf")]
+        one_of: Vec<ReferenceOr<Message>>,
+    },
+    /// Messages by name. An object with a key that is a field of a message,
+    /// or a typo of one when unknown fields are denied, is a single message
+    /// instead.
+    Map(
+        #[serde(deserialize_with = "deserialize_message_map")]
+        IndexMap<String, ReferenceOr<Message>>,
+    ),
     Single(ReferenceOr<Message>),
 }
+
+/// The fields of a [Message].
+const MESSAGE_FIELDS: &[&str] = &[
+    "messageId",
+    "headers",
+    "payload",
+    "correlationId",
+    "schemaFormat",
+    "contentType",
+    "name",
+    "title",
+    "summary",
+    "description",
+    "tags",
+    "externalDocs",
+    "bindings",
+    "examples",
+    "traits",
+];
+
+/// Keeps a single message whose fields are all objects, e.g. only a
+/// `payload`, from being read as a [map of messages][OperationMessageType::Map].
+fn deserialize_message_map<'de, D>(
+    deserializer: D,
+) -> Result<IndexMap<String, ReferenceOr<Message>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let messages = IndexMap::<String, ReferenceOr<Message>>::deserialize(deserializer)?;
+    let field = messages
+        .keys()
+        .find(|key| key.starts_with("x-") || crate::parse::is_field_or_typo(key, MESSAGE_FIELDS));
+    match field {
+        Some(field) => Err(serde::de::Error::custom(format!(
+            "`{field}` is a field of a message rather than the name of one"
+        ))),
+        None => Ok(messages),
+    }
+}
+
+/// The kind of an [Operation][Operation] of a [Channel][Channel].
+///
+/// The kinds are named from the point of view of the clients of the
+/// application the document describes, not the application itself: a
+/// [`Subscribe`][OperationKind::Subscribe] operation describes messages that
+/// the application sends and its clients subscribe to, a
+/// [`Publish`][OperationKind::Publish] operation describes messages that
+/// clients publish and the application receives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationKind {
+    /// Clients publish messages to the channel, the application receives them.
+    Publish,
+    /// Clients subscribe to the channel, the application sends the messages.
+    Subscribe,
+}
+
+impl OperationKind {
+    /// The name of the field the operation is stored in.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OperationKind::Publish => "publish",
+            OperationKind::Subscribe => "subscribe",
+        }
+    }
+
+    /// Whether the application described by the document sends the messages.
+    pub fn application_sends(self) -> bool {
+        self == OperationKind::Subscribe
+    }
+
+    /// Whether the application described by the document receives the messages.
+    pub fn application_receives(self) -> bool {
+        self == OperationKind::Publish
+    }
+
+    /// The kind of the same operation seen from the other side of the channel,
+    /// i.e. the kind a client of the application would document it as.
+    pub fn inverse(self) -> Self {
+        match self {
+            OperationKind::Publish => OperationKind::Subscribe,
+            OperationKind::Subscribe => OperationKind::Publish,
+        }
+    }
+}
+
+impl std::fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Channel {
+    /// Starts building a channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{Channel, Operation, OperationMessageType, ReferenceOr};
+    ///
+    /// let channel = Channel::builder()
+    ///     .description("Users signing up")
+    ///     .parameter_ref("userId", "#/components/parameters/userId")
+    ///     .subscribe(
+    ///         Operation::builder()
+    ///             .operation_id("onUserSignedUp")
+    ///             .message_ref("#/components/messages/UserSignedUp")
+    ///             .message_ref("#/components/messages/UserSignedUpV2")
+    ///             .build(),
+    ///     )
+    ///     .build();
+    ///
+    /// let operation = channel.subscribe.unwrap();
+    /// let Some(OperationMessageType::OneOf { one_of }) = operation.message else {
+    ///     panic!("two messages are a oneOf");
+    /// };
+    /// assert_eq!(
+    ///     one_of[1],
+    ///     ReferenceOr::ref_("#/components/messages/UserSignedUpV2")
+    /// );
+    /// ```
+    pub fn builder() -> ChannelBuilder {
+        ChannelBuilder::default()
+    }
+
+    /// Returns the operations of this channel, `publish` first like in the
+    /// specification. Documentation and diagrams list operations in this
+    /// order.
+    pub fn operations(&self) -> impl Iterator<Item = (OperationKind, &Operation)> {
+        [
+            (OperationKind::Publish, &self.publish),
+            (OperationKind::Subscribe, &self.subscribe),
+        ]
+        .into_iter()
+        .filter_map(|(kind, operation)| Some((kind, operation.as_ref()?)))
+    }
+
+    /// Returns the operation of the given kind.
+    pub fn operation(&self, kind: OperationKind) -> Option<&Operation> {
+        match kind {
+            OperationKind::Publish => self.publish.as_ref(),
+            OperationKind::Subscribe => self.subscribe.as_ref(),
+        }
+    }
+}
+
+impl Operation {
+    /// The messages of this operation.
+    pub(crate) fn messages(&self) -> Vec<&ReferenceOr<Message>> {
+        match &self.message {
+            Some(OperationMessageType::Single(message)) => vec![message],
+            Some(OperationMessageType::OneOf { one_of }) => one_of.iter().collect(),
+            Some(OperationMessageType::Map(messages)) => messages.values().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Starts building an operation, see [`Channel::builder`].
+    pub fn builder() -> OperationBuilder {
+        OperationBuilder::default()
+    }
+}
+
+/// Builds a [`Channel`], see [`Channel::builder`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChannelBuilder {
+    channel: Channel,
+}
+
+impl ChannelBuilder {
+    /// Sets the [description][Channel::description].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.channel.description = Some(description.into());
+        self
+    }
+
+    /// Adds a [server][Channel::servers] the channel is available on.
+    pub fn server(mut self, name: impl Into<String>) -> Self {
+        self.channel.servers.push(name.into());
+        self
+    }
+
+    /// Sets the [subscribe][Channel::subscribe] operation.
+    pub fn subscribe(mut self, operation: Operation) -> Self {
+        self.channel.subscribe = Some(operation);
+        self
+    }
+
+    /// Sets the [publish][Channel::publish] operation.
+    pub fn publish(mut self, operation: Operation) -> Self {
+        self.channel.publish = Some(operation);
+        self
+    }
+
+    /// Sets the operation of the given kind.
+    pub fn operation(self, kind: OperationKind, operation: Operation) -> Self {
+        match kind {
+            OperationKind::Publish => self.publish(operation),
+            OperationKind::Subscribe => self.subscribe(operation),
+        }
+    }
+
+    /// Adds a [parameter][Channel::parameters], replacing one of the same name.
+    pub fn parameter(mut self, name: impl Into<String>, parameter: Parameter) -> Self {
+        self.channel
+            .parameters
+            .insert(name.into(), ReferenceOr::Item(parameter));
+        self
+    }
+
+    /// Adds a [parameter][Channel::parameters] defined at `reference`,
+    /// replacing one of the same name.
+    pub fn parameter_ref(mut self, name: impl Into<String>, reference: &str) -> Self {
+        self.channel
+            .parameters
+            .insert(name.into(), ReferenceOr::ref_(reference));
+        self
+    }
+
+    /// Sets the [bindings][Channel::bindings].
+    pub fn bindings(mut self, bindings: ChannelBinding) -> Self {
+        self.channel.bindings = Some(ReferenceOr::Item(bindings));
+        self
+    }
+
+    /// Sets the [bindings][Channel::bindings] to those defined at `reference`.
+    pub fn bindings_ref(mut self, reference: &str) -> Self {
+        self.channel.bindings = Some(ReferenceOr::ref_(reference));
+        self
+    }
+
+    /// Adds a [specification extension][Channel::extensions].
+    pub fn extension(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.channel.extensions.insert(name.into(), value);
+        self
+    }
+
+    pub fn build(self) -> Channel {
+        self.channel
+    }
+}
+
+/// Builds an [`Operation`], see [`Operation::builder`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OperationBuilder {
+    operation: Operation,
+}
+
+impl OperationBuilder {
+    /// Sets the [operation id][Operation::operation_id].
+    pub fn operation_id(mut self, operation_id: impl Into<String>) -> Self {
+        self.operation.operation_id = Some(operation_id.into());
+        self
+    }
+
+    /// Sets the [summary][Operation::summary].
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.operation.summary = Some(summary.into());
+        self
+    }
+
+    /// Sets the [description][Operation::description].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.operation.description = Some(description.into());
+        self
+    }
+
+    /// Adds an alternative [security requirement][Operation::security].
+    pub fn security(mut self, requirement: SecurityRequirement) -> Self {
+        self.operation.security.push(requirement);
+        self
+    }
+
+    /// Adds a [tag][Operation::tags].
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.operation.tags.push(tag);
+        self
+    }
+
+    /// Sets the [external documentation][Operation::external_docs].
+    pub fn external_docs(mut self, external_docs: ExternalDocumentation) -> Self {
+        self.operation.external_docs = Some(external_docs);
+        self
+    }
+
+    /// Sets the [bindings][Operation::bindings].
+    pub fn bindings(mut self, bindings: OperationBinding) -> Self {
+        self.operation.bindings = Some(ReferenceOr::Item(bindings));
+        self
+    }
+
+    /// Sets the [bindings][Operation::bindings] to those defined at `reference`.
+    pub fn bindings_ref(mut self, reference: &str) -> Self {
+        self.operation.bindings = Some(ReferenceOr::ref_(reference));
+        self
+    }
+
+    /// Applies a [trait][Operation::traits] after the ones added before.
+    pub fn operation_trait(mut self, operation_trait: OperationTrait) -> Self {
+        self.operation
+            .traits
+            .push(ReferenceOr::Item(operation_trait));
+        self
+    }
+
+    /// Applies the [trait][Operation::traits] defined at `reference` after the
+    /// ones added before.
+    pub fn trait_ref(mut self, reference: &str) -> Self {
+        self.operation.traits.push(ReferenceOr::ref_(reference));
+        self
+    }
+
+    /// Adds a [message][Operation::message]. A single message is set as it
+    /// is, adding more turns the messages into a `oneOf`.
+    pub fn message(self, message: Message) -> Self {
+        self.add_message(ReferenceOr::Item(message))
+    }
+
+    /// Adds the [message][Operation::message] defined at `reference`, see
+    /// [message][OperationBuilder::message].
+    pub fn message_ref(self, reference: &str) -> Self {
+        self.add_message(ReferenceOr::ref_(reference))
+    }
+
+    fn add_message(mut self, message: ReferenceOr<Message>) -> Self {
+        self.operation.message = Some(match self.operation.message.take() {
+            None => OperationMessageType::Single(message),
+            Some(OperationMessageType::Single(first)) => OperationMessageType::OneOf {
+                one_of: vec![first, message],
+            },
+            Some(OperationMessageType::OneOf { mut one_of }) => {
+                one_of.push(message);
+                OperationMessageType::OneOf { one_of }
+            }
+            Some(OperationMessageType::Map(messages)) => OperationMessageType::OneOf {
+                one_of: messages.into_values().chain([message]).collect(),
+            },
+        });
+        self
+    }
+
+    /// Adds a [specification extension][Operation::extensions].
+    pub fn extension(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.operation.extensions.insert(name.into(), value);
+        self
+    }
+
+    pub fn build(self) -> Operation {
+        self.operation
+    }
+}
+
+#[test]
+fn test_operation_security() {
+    let operation: Operation = serde_yaml::from_str(
+        r#"
+        operationId: sendOrder
+        security:
+          - petstore_auth:
+              - write:orders
+        "#,
+    )
+    .expect("Could not deserialize operation");
+    assert_eq!(
+        operation.security[0].values["petstore_auth"],
+        ["write:orders"]
+    );
+    assert!(operation.extensions.is_empty());
+}
+
+#[test]
+fn test_operation_one_of_messages() {
+    let operation: Operation = serde_yaml::from_str(
+        r#"
+        message:
+          oneOf:
+            - $ref: '#/components/messages/UserSignedUp'
+            - name: UserDeleted
+        "#,
+    )
+    .expect("Could not deserialize operation");
+    let Some(OperationMessageType::OneOf { one_of }) = &operation.message else {
+        panic!("expected a oneOf message, got {:?}", operation.message);
+    };
+    assert_eq!(
+        one_of[0],
+        ReferenceOr::ref_("#/components/messages/UserSignedUp")
+    );
+    assert_eq!(operation.messages().len(), 2);
+}
+
+#[test]
+fn test_operations_order() {
+    let api: crate::AsyncAPI = serde_yaml::from_str(
+        r#"
+        asyncapi: 2.3.0
+        info:
+          title: Streetlights
+          version: 1.0.0
+        channels:
+          light/measured:
+            subscribe:
+              operationId: dimLight
+            publish:
+              operationId: receiveLight
+        "#,
+    )
+    .unwrap();
+    let kinds: Vec<_> = api.channels["light/measured"]
+        .operations()
+        .map(|(kind, _)| kind)
+        .collect();
+    assert_eq!(kinds, [OperationKind::Publish, OperationKind::Subscribe]);
+
+    let position = |text: &str, pattern: &str| text.find(pattern).unwrap();
+    let markdown = crate::docgen::to_markdown(&api);
+    assert!(position(&markdown, "receiveLight") < position(&markdown, "dimLight"));
+    let dot = crate::dot::to_dot(&api, &Default::default());
+    assert!(position(&dot, "receiveLight") < position(&dot, "dimLight"));
+}
+
+#[test]
+fn test_operation_message_with_object_fields_only() {
+    let operation: Operation = serde_yaml::from_str(
+        r#"
+        message:
+          payload:
+            type: string
+        "#,
+    )
+    .expect("Could not deserialize operation");
+    assert!(matches!(
+        &operation.message,
+        Some(OperationMessageType::Single(ReferenceOr::Item(message))) if message.payload.is_some()
+    ));
+}
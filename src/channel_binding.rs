@@ -1,7 +1,12 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::Schema;
+use crate::{
+    binding_builder::binding_builder,
+    operation_binding::HttpMethod,
+    validation::{check_length, Validate, ValidationError},
+    BindingVersion, Schema,
+};
 
 /// Map describing protocol-specific definitions for a message.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -57,12 +62,59 @@ pub struct ChannelBinding {
     /// Protocol-specific information for an IBM MQ channel.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ibmmq: Option<IBMMQChannelBinding>,
+    /// Protocol-specific information for a Google Cloud Pub/Sub channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub googlepubsub: Option<GooglePubSubChannelBinding>,
+    /// Protocol-specific information for a Pulsar channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pulsar: Option<PulsarChannelBinding>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
+    /// Bindings of other protocols are kept here as well, see
+    /// [custom][ChannelBinding::custom].
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl ChannelBinding {
+    /// The bindings of protocols this crate doesn't define a binding object
+    /// for, i.e. the [extensions][ChannelBinding::extensions] that aren't
+    /// specification extensions.
+    pub fn custom(&self) -> impl Iterator<Item = (&str, &serde_json::Value)> {
+        self.extensions
+            .iter()
+            .filter(|(protocol, _)| !protocol.starts_with("x-"))
+            .map(|(protocol, binding)| (protocol.as_str(), binding))
+    }
+
+    /// The binding for `protocol` among the [custom][ChannelBinding::custom] ones.
+    pub fn custom_binding(&self, protocol: &str) -> Option<&serde_json::Value> {
+        self.extensions
+            .get(protocol)
+            .filter(|_| !protocol.starts_with("x-"))
+    }
+}
+
+impl Validate for ChannelBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(ws) = &self.ws {
+            ws.validate_at(&format!("{path}/ws"), errors);
+        }
+        if let Some(kafka) = &self.kafka {
+            kafka.validate_at(&format!("{path}/kafka"), errors);
+        }
+        if let Some(amqp) = &self.amqp {
+            amqp.validate_at(&format!("{path}/amqp"), errors);
+        }
+        if let Some(amqp1) = &self.amqp1 {
+            amqp1.validate_at(&format!("{path}/amqp1"), errors);
+        }
+        if let Some(ibmmq) = &self.ibmmq {
+            ibmmq.validate_at(&format!("{path}/ibmmq"), errors);
+        }
+    }
+}
+
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct HTTPChannelBinding {}
@@ -72,13 +124,29 @@ pub struct HTTPChannelBinding {}
 /// WebSockets doesn't support virtual channels or, put it another way, there's only one
 /// channel and its characteristics are strongly related to the protocol used for the
 /// handshake, i.e., HTTP.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   /:
+///     bindings:
+///       ws:
+///         method: GET
+///         query:
+///           type: object
+///           properties:
+///             token:
+///               type: string
+///         bindingVersion: 0.1.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WebsocketsChannelBinding {
     /// The HTTP method to use when establishing the connection.
     /// Its value MUST be either `GET` or `POST`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub method: Option<String>,
+    pub method: Option<HttpMethod>,
     /// A Schema object containing the definitions for each query parameter.
     /// This schema MUST be of type `object` and have a `properties` key.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -88,14 +156,111 @@ pub struct WebsocketsChannelBinding {
     /// a `properties` key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<Schema>,
-    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    /// The version of this binding, currently `0.1.0`. If omitted, "latest"
+    /// MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+impl Validate for WebsocketsChannelBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(method) = &self.method {
+            if !matches!(method, HttpMethod::Get | HttpMethod::Post) {
+                errors.push(ValidationError::new(
+                    format!("{path}/method"),
+                    format!("`{method}` is neither GET nor POST"),
+                ));
+            }
+        }
+    }
+}
+
+/// This object contains information about the channel representation in Kafka.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user-signedup:
+///     bindings:
+///       kafka:
+///         topic: 'my-specific-topic-name'
+///         partitions: 20
+///         replicas: 3
+///         topicConfiguration:
+///           cleanup.policy: ["delete", "compact"]
+///           retention.ms: 604800000
+///           retention.bytes: 1000000000
+///           delete.retention.ms: 86400000
+///           max.message.bytes: 1048588
+///         bindingVersion: '0.4.0'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaChannelBinding {
+    /// Kafka topic name if different from channel name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// Number of partitions configured on this topic (useful to know how many
+    /// parallel consumers you may run). MUST be positive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partitions: Option<u32>,
+    /// Number of replicas configured on this topic. MUST be positive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<u32>,
+    /// Topic configuration properties that are relevant for the API.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    pub topic_configuration: Option<KafkaChannelBindingTopicConfiguration>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+impl Validate for KafkaChannelBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.partitions == Some(0) {
+            errors.push(ValidationError::new(
+                format!("{path}/partitions"),
+                "must be positive",
+            ));
+        }
+        if self.replicas == Some(0) {
+            errors.push(ValidationError::new(
+                format!("{path}/replicas"),
+                "must be positive",
+            ));
+        }
+    }
+}
+
+/// The Kafka topic configuration properties, see the
+/// [Kafka documentation](https://kafka.apache.org/documentation/#topicconfigs)
+/// for their meaning.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct KafkaChannelBinding {}
+pub struct KafkaChannelBindingTopicConfiguration {
+    /// The `cleanup.policy` configuration option, `delete` and/or `compact`.
+    #[serde(
+        rename = "cleanup.policy",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub cleanup_policy: Vec<String>,
+    /// The `retention.ms` configuration option, `-1` for no limit.
+    #[serde(rename = "retention.ms", skip_serializing_if = "Option::is_none")]
+    pub retention_ms: Option<i64>,
+    /// The `retention.bytes` configuration option, `-1` for no limit.
+    #[serde(rename = "retention.bytes", skip_serializing_if = "Option::is_none")]
+    pub retention_bytes: Option<i64>,
+    /// The `delete.retention.ms` configuration option.
+    #[serde(
+        rename = "delete.retention.ms",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub delete_retention_ms: Option<i64>,
+    /// The `max.message.bytes` configuration option.
+    #[serde(rename = "max.message.bytes", skip_serializing_if = "Option::is_none")]
+    pub max_message_bytes: Option<i32>,
+}
 
 /// The Anypoint MQ [Channel Binding Object][ChannelBinding] is defined by a
 /// [JSON Schema](https://github.com/asyncapi/bindings/blob/master/anypointmq/json_schemas/channel.json),
@@ -150,8 +315,8 @@ pub struct AnyPointMQChannelBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub destination_type: Option<String>,
     /// **Optional**, defaults to `latest`. The version of this binding.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// This object contains information about the channel representation in AMQP.
@@ -176,8 +341,7 @@ pub struct AnyPointMQChannelBinding {
 ///           durable: true
 ///           autoDelete: false
 ///           vhost: /
-///         bindingVersion: 0.2.0
-///
+///         bindingVersion: 0.3.0
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -192,8 +356,23 @@ pub struct AMQPChannelBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub queue: Option<AMQPChannelBindingQueue>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+impl Validate for AMQPChannelBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(name) = self
+            .exchange
+            .as_ref()
+            .and_then(|exchange| exchange.name.as_ref())
+        {
+            check_length(format!("{path}/exchange/name"), name, 255, errors);
+        }
+        if let Some(name) = self.queue.as_ref().and_then(|queue| queue.name.as_ref()) {
+            check_length(format!("{path}/queue/name"), name, 255, errors);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -202,10 +381,9 @@ pub struct AMQPChannelBindingExchange {
     /// The name of the exchange. It MUST NOT exceed 255 characters long.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    /// The type of the exchange. Can be either
-    /// `topic`, `direct`, `fanout`, `default` or `headers`.
+    /// The type of the exchange.
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
-    pub typ: Option<String>,
+    pub typ: Option<AMQPChannelBindingExchangeType>,
     /// Whether the exchange should survive broker restarts or not.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub durable: Option<bool>,
@@ -217,6 +395,20 @@ pub struct AMQPChannelBindingExchange {
     pub vhost: Option<String>,
 }
 
+/// The type of an AMQP exchange.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AMQPChannelBindingExchangeType {
+    Topic,
+    Direct,
+    Fanout,
+    Default,
+    Headers,
+    /// An exchange type not defined by the binding.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AMQPChannelBindingQueue {
@@ -257,25 +449,249 @@ pub struct MQTT5ChannelBinding {}
 #[serde(rename_all = "camelCase")]
 pub struct NATSChannelBinding {}
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains configuration for describing a JMS queue, or FIFO
+/// queue as an AsyncAPI channel.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user.signup:
+///     bindings:
+///       jms:
+///         destination: user-sign-up
+///         destinationType: fifo-queue
+///         bindingVersion: 0.0.1
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct JMSChannelBinding {}
+pub struct JMSChannelBinding {
+    /// **Optional**, defaults to the channel name. The destination (queue)
+    /// name for this channel. SHOULD only be specified if the channel name
+    /// differs from the actual destination name, such as when the channel
+    /// name is not a valid destination name according to the JMS Provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    /// **Optional**, defaults to `queue`. The type of destination, which MUST
+    /// be either `queue`, or `fifo-queue`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_type: Option<String>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains information about the channel representation in SNS,
+/// which is an SNS topic.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user-signedup:
+///     bindings:
+///       sns:
+///         name: my-sns-topic
+///         policy:
+///           statements:
+///             - effect : Allow
+///               principal: '*'
+///               action: SNS:Publish
+///         bindingVersion: 0.1.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct SNSChannelBinding {}
+pub struct SNSChannelBinding {
+    /// The name of the topic. Can be different from the channel name to allow
+    /// flexibility around AWS resource naming limitations.
+    pub name: String,
+    /// By default, an unordered SNS topic is assumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering: Option<SNSChannelBindingOrdering>,
+    /// The security policy for the SNS topic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<AWSPolicy>,
+    /// Key-value pairs that represent AWS tags on the topic.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub tags: IndexMap<String, String>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SNSChannelBindingOrdering {
+    /// Defines the type of SNS topic, either `standard` or `FIFO`.
+    #[serde(rename = "type")]
+    pub typ: String,
+    /// Whether the de-duplication of messages should be turned on. Defaults
+    /// to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_based_deduplication: Option<bool>,
+}
+
+/// The security policy of an AWS resource, such as an SNS topic or an SQS
+/// queue.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AWSPolicy {
+    /// An array of statement objects, each of which controls a permission for
+    /// this resource.
+    pub statements: Vec<AWSPolicyStatement>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AWSPolicyStatement {
+    /// Either `Allow` or `Deny`.
+    pub effect: String,
+    /// The AWS account or resource ARN that this statement applies to.
+    pub principal: AWSPolicyValue,
+    /// The permission or permissions this statement allows or denies.
+    pub action: AWSPolicyValue,
+}
+
+/// A value of an [AWSPolicyStatement], written as a single string or as a list
+/// of strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum AWSPolicyValue {
+    Single(String),
+    Multiple(Vec<String>),
+}
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SolaceChannelBinding {}
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains information about the channel representation in SQS,
+/// which is an SQS queue. Use it when the channel is a point-to-point queue;
+/// when an SNS topic fans out to SQS queues, describe the queues in the
+/// [SQS operation binding][crate::operation_binding::SQSOperationBinding]
+/// instead.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user-signedup:
+///     bindings:
+///       sqs:
+///         queue:
+///           name: user-signedup-queue
+///           fifoQueue: true
+///           deduplicationScope: messageGroup
+///           fifoThroughputLimit: perMessageGroupId
+///           deliveryDelay: 15
+///           visibilityTimeout: 60
+///           receiveMessageWaitTime: 0
+///           messageRetentionPeriod: 86400
+///           redrivePolicy:
+///             deadLetterQueue:
+///               arn: arn:aws:SQS:eu-west-1:0000000:123456789
+///             maxReceiveCount: 15
+///           policy:
+///             statements:
+///               - effect : Deny
+///                 principal: arn:aws:iam::123456789012:user/dec.kolakowski
+///                 action: [sqs:SendMessage, sqs:ReceiveMessage]
+///           tags:
+///             owner: AsyncAPI.NET
+///             platform: AsyncAPIOrg
+///         deadLetterQueue:
+///           name: user-signedup-dlq
+///           messageRetentionPeriod: 1209600
+///           fifoQueue: true
+///         bindingVersion: 0.2.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct SQSChannelBinding {}
+pub struct SQSChannelBinding {
+    /// The queue this channel stands for.
+    pub queue: SQSQueue,
+    /// The queue messages are moved to after failing to be processed, see
+    /// [SQSQueue::redrive_policy].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_letter_queue: Option<SQSQueue>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+/// The definition of an SQS queue.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SQSQueue {
+    /// The name of the queue. When an SNS operation binding uses the queue as
+    /// the endpoint of a consumer, this is the name it refers to.
+    pub name: String,
+    /// Whether this is a FIFO queue.
+    pub fifo_queue: bool,
+    /// Whether message deduplication occurs at the `messageGroup` or `queue`
+    /// (default) level. Only applies to FIFO queues.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduplication_scope: Option<String>,
+    /// Whether the FIFO queue throughput quota applies to the entire queue
+    /// (`perQueue`) or per message group (`perMessageGroupId`). Only applies
+    /// to FIFO queues.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fifo_throughput_limit: Option<String>,
+    /// The number of seconds to delay before a message sent to the queue can
+    /// be received, `0` to `900` (15 minutes). Defaults to `0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_delay: Option<u32>,
+    /// The length of time, in seconds, that a consumer locks a message,
+    /// hiding it from reads by other consumers, `0` to `43200` (12 hours).
+    /// Defaults to `30`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility_timeout: Option<u32>,
+    /// Determines if the queue uses short polling or long polling. Set to `0`
+    /// (default) for short polling, or a positive number of seconds up to `20`
+    /// for long polling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receive_message_wait_time: Option<u32>,
+    /// How long to retain a message on the queue in seconds, from `60` up to
+    /// `1209600` (14 days). Defaults to `345600` (4 days).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_retention_period: Option<u32>,
+    /// Prevent poison pill messages by moving un-processable messages to a
+    /// dead letter queue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redrive_policy: Option<SQSRedrivePolicy>,
+    /// The security policy for the SQS queue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<AWSPolicy>,
+    /// Key-value pairs that represent AWS tags on the queue.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub tags: IndexMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SQSRedrivePolicy {
+    /// The SQS queue to use as a dead letter queue.
+    pub dead_letter_queue: SQSIdentifier,
+    /// The number of times a message is delivered to the source queue before
+    /// being moved to the dead-letter queue. Defaults to `10`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_receive_count: Option<u32>,
+}
+
+/// Identifies an SQS queue either by its ARN or by its name.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SQSIdentifier {
+    /// The target is an ARN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// The endpoint is identified by a name, which corresponds to an
+    /// identifying field called `name` of a binding for that protocol on this
+    /// publish operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -331,11 +747,10 @@ pub struct MercureChannelBinding {}
 pub struct IBMMQChannelBinding {
     /// Defines the type of AsyncAPI channel.
     ///
-    /// MUST be either `topic` or `queue`. For type `topic`,
-    /// the AsyncAPI channel name MUST be assumed for the
+    /// For type `topic`, the AsyncAPI channel name MUST be assumed for the
     /// IBM MQ topic string unless overridden.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_type: Option<String>,
+    pub destination_type: Option<IBMMQChannelBindingDestinationType>,
     /// Defines the properties of a queue.
     ///
     /// `queue` and `topic` fields MUST NOT coexist within a channel binding
@@ -357,8 +772,54 @@ pub struct IBMMQChannelBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_msg_length: Option<i32>,
     /// The version of this binding.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+/// The type of the IBM MQ object an AsyncAPI channel stands for.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IBMMQChannelBindingDestinationType {
+    Topic,
+    Queue,
+    /// A destination type not defined by the binding.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+impl Validate for IBMMQChannelBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.queue.is_some() && self.topic.is_some() {
+            errors.push(ValidationError::new(
+                path,
+                "`queue` and `topic` must not coexist",
+            ));
+        }
+        if let Some(queue) = &self.queue {
+            check_length(
+                format!("{path}/queue/objectName"),
+                &queue.object_name,
+                48,
+                errors,
+            );
+        }
+        if let Some(topic) = &self.topic {
+            if let Some(string) = &topic.string {
+                check_length(format!("{path}/topic/string"), string, 10240, errors);
+            }
+            if let Some(object_name) = &topic.object_name {
+                check_length(format!("{path}/topic/objectName"), object_name, 48, errors);
+            }
+        }
+        if let Some(length) = self.max_msg_length {
+            if !(0..=104_857_600).contains(&length) {
+                errors.push(ValidationError::new(
+                    format!("{path}/maxMsgLength"),
+                    format!("`{length}` is not between 0 and 104857600"),
+                ));
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -410,3 +871,434 @@ pub struct IBMMQChannelBindingTopic {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_msg_retained: Option<bool>,
 }
+
+/// This object contains information about the topic representation in
+/// Google Cloud Pub/Sub, the channel address being the topic name.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   projects/your-project/topics/topic-proto-schema:
+///     bindings:
+///       googlepubsub:
+///         messageRetentionDuration: 86400s
+///         messageStoragePolicy:
+///           allowedPersistenceRegions:
+///             - us-central1
+///             - us-east1
+///         schemaSettings:
+///           encoding: binary
+///           name: projects/your-project/schemas/message-proto
+///         bindingVersion: 0.2.0
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePubSubChannelBinding {
+    /// An object of key-value pairs used to organize the topic.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub labels: IndexMap<String, String>,
+    /// Indicates the minimum duration to retain a message after it is published
+    /// to the topic, e.g. `86400s`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_retention_duration: Option<String>,
+    /// Policy constraining the set of Google Cloud Platform regions where
+    /// messages published to the topic may be stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_storage_policy: Option<GooglePubSubChannelBindingMessageStoragePolicy>,
+    /// Settings for validating messages published against a schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_settings: Option<GooglePubSubChannelBindingSchemaSettings>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePubSubChannelBindingMessageStoragePolicy {
+    /// A list of IDs of Google Cloud Platform regions where messages published
+    /// to the topic may be persisted in storage.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_persistence_regions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePubSubChannelBindingSchemaSettings {
+    /// The encoding of the message, either `json` or `binary`.
+    pub encoding: String,
+    /// The minimum (inclusive) revision allowed for validating messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_revision_id: Option<String>,
+    /// The maximum (inclusive) revision allowed for validating messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_revision_id: Option<String>,
+    /// The name of the schema that messages published should be validated
+    /// against, e.g. `projects/your-project/schemas/message-proto`.
+    pub name: String,
+}
+
+/// This object contains information about the channel representation in Pulsar,
+/// which is a topic of a namespace.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user-signedup:
+///     bindings:
+///       pulsar:
+///         namespace: 'staging'
+///         persistence: 'persistent'
+///         compaction: 1000
+///         geo-replication:
+///           - 'us-east1'
+///           - 'us-west1'
+///         retention:
+///           time: 7
+///           size: 1000
+///         ttl: 360
+///         deduplication: false
+///         bindingVersion: '0.1.0'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PulsarChannelBinding {
+    /// The namespace the channel is associated with.
+    pub namespace: String,
+    /// Persistence of the topic in Pulsar. It MUST be either `persistent` or
+    /// `non-persistent`.
+    pub persistence: String,
+    /// Topic compaction threshold given in Megabytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compaction: Option<u64>,
+    /// A list of clusters the topic is replicated to.
+    #[serde(
+        rename = "geo-replication",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub geo_replication: Vec<String>,
+    /// Topic retention policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<PulsarChannelBindingRetention>,
+    /// Message time-to-live in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// Message deduplication. When true, it ensures that each message produced
+    /// on Pulsar topics is persisted to disk only once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduplication: Option<bool>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PulsarChannelBindingRetention {
+    /// Time given in minutes. Defaults to `0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<u64>,
+    /// Size given in Megabytes. Defaults to `0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+binding_builder! {
+    WebsocketsChannelBinding => WebsocketsChannelBindingBuilder {
+        method: Option<HttpMethod>,
+        query: Option<Schema>,
+        headers: Option<Schema>,
+        binding_version: BindingVersion,
+    }
+    KafkaChannelBinding => KafkaChannelBindingBuilder {
+        topic: Option<String>,
+        partitions: Option<u32>,
+        replicas: Option<u32>,
+        topic_configuration: Option<KafkaChannelBindingTopicConfiguration>,
+        binding_version: BindingVersion,
+    }
+    KafkaChannelBindingTopicConfiguration => KafkaChannelBindingTopicConfigurationBuilder {
+        cleanup_policy: Vec<String>,
+        retention_ms: Option<i64>,
+        retention_bytes: Option<i64>,
+        delete_retention_ms: Option<i64>,
+        max_message_bytes: Option<i32>,
+    }
+    AMQPChannelBinding => AMQPChannelBindingBuilder {
+        is: Option<String>,
+        exchange: Option<AMQPChannelBindingExchange>,
+        queue: Option<AMQPChannelBindingQueue>,
+        binding_version: BindingVersion,
+    }
+    AMQPChannelBindingExchange => AMQPChannelBindingExchangeBuilder {
+        name: Option<String>,
+        typ: Option<AMQPChannelBindingExchangeType>,
+        durable: Option<bool>,
+        auto_delete: Option<bool>,
+        vhost: Option<String>,
+    }
+    AMQPChannelBindingQueue => AMQPChannelBindingQueueBuilder {
+        name: Option<String>,
+        durable: Option<bool>,
+        exclusive: Option<bool>,
+        auto_delete: Option<bool>,
+        vhost: Option<String>,
+    }
+    SNSChannelBinding => SNSChannelBindingBuilder {
+        name: String,
+        ordering: Option<SNSChannelBindingOrdering>,
+        policy: Option<AWSPolicy>,
+        tags: IndexMap<String, String>,
+        binding_version: BindingVersion,
+    }
+    SQSQueue => SQSQueueBuilder {
+        name: String,
+        fifo_queue: bool,
+        deduplication_scope: Option<String>,
+        fifo_throughput_limit: Option<String>,
+        delivery_delay: Option<u32>,
+        visibility_timeout: Option<u32>,
+        receive_message_wait_time: Option<u32>,
+        message_retention_period: Option<u32>,
+        redrive_policy: Option<SQSRedrivePolicy>,
+        policy: Option<AWSPolicy>,
+        tags: IndexMap<String, String>,
+    }
+    IBMMQChannelBinding => IBMMQChannelBindingBuilder {
+        destination_type: Option<IBMMQChannelBindingDestinationType>,
+        queue: Option<IBMMQChannelBindingQueue>,
+        topic: Option<IBMMQChannelBindingTopic>,
+        max_msg_length: Option<i32>,
+        binding_version: BindingVersion,
+    }
+    GooglePubSubChannelBinding => GooglePubSubChannelBindingBuilder {
+        labels: IndexMap<String, String>,
+        message_retention_duration: Option<String>,
+        message_storage_policy: Option<GooglePubSubChannelBindingMessageStoragePolicy>,
+        schema_settings: Option<GooglePubSubChannelBindingSchemaSettings>,
+        binding_version: BindingVersion,
+    }
+    PulsarChannelBinding => PulsarChannelBindingBuilder {
+        namespace: String,
+        persistence: String,
+        compaction: Option<u64>,
+        geo_replication: Vec<String>,
+        retention: Option<PulsarChannelBindingRetention>,
+        ttl: Option<u64>,
+        deduplication: Option<bool>,
+        binding_version: BindingVersion,
+    }
+}
+
+#[test]
+fn test_custom_channel_binding() {
+    let binding: ChannelBinding = serde_yaml::from_str(
+        r#"
+        kafka:
+          topic: events
+        zeromq:
+          socket: pub
+        x-internal: true
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        binding.custom().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["zeromq"]
+    );
+    assert_eq!(binding.custom_binding("zeromq").unwrap()["socket"], "pub");
+    assert!(binding.custom_binding("x-internal").is_none());
+    assert!(binding.custom_binding("kafka").is_none());
+}
+
+#[test]
+fn test_amqp_channel_binding() {
+    let binding: ChannelBinding = serde_yaml::from_str(
+        r#"
+        amqp:
+          is: routingKey
+          exchange:
+            name: myExchange
+            type: topic
+            durable: true
+          bindingVersion: 0.3.0
+        "#,
+    )
+    .unwrap();
+    let exchange = binding.amqp.unwrap().exchange.unwrap();
+    assert_eq!(exchange.typ, Some(AMQPChannelBindingExchangeType::Topic));
+
+    let exchange: AMQPChannelBindingExchange =
+        serde_yaml::from_str("type: x-consistent-hash").unwrap();
+    assert_eq!(
+        exchange.typ,
+        Some(AMQPChannelBindingExchangeType::Other(
+            "x-consistent-hash".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_amqp_channel_binding_builder() {
+    let binding = AMQPChannelBinding::builder()
+        .is("queue")
+        .queue(
+            AMQPChannelBindingQueue::builder()
+                .name("my-queue-name")
+                .durable(true)
+                .build(),
+        )
+        .binding_version("0.3.0")
+        .build();
+    assert_eq!(
+        binding,
+        AMQPChannelBinding {
+            is: Some("queue".to_string()),
+            queue: Some(AMQPChannelBindingQueue {
+                name: Some("my-queue-name".to_string()),
+                durable: Some(true),
+                ..Default::default()
+            }),
+            binding_version: BindingVersion::new("0.3.0"),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_ibmmq_queue_and_topic_are_exclusive() {
+    let binding: IBMMQChannelBinding = serde_yaml::from_str(
+        r#"
+        destinationType: queue
+        queue:
+          objectName: myQueueName
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        binding.destination_type,
+        Some(IBMMQChannelBindingDestinationType::Queue)
+    );
+    assert!(binding.validate().is_ok());
+
+    let binding = IBMMQChannelBinding::builder()
+        .queue(IBMMQChannelBindingQueue::default())
+        .topic(IBMMQChannelBindingTopic::default())
+        .build();
+    assert!(binding.validate().is_err());
+}
+
+#[test]
+fn test_kafka_channel_binding() {
+    let binding: ChannelBinding = serde_yaml::from_str(
+        r#"
+        kafka:
+          topic: 'my-specific-topic-name'
+          partitions: 20
+          replicas: 3
+          topicConfiguration:
+            cleanup.policy: ["delete", "compact"]
+            retention.ms: 604800000
+            retention.bytes: -1
+            delete.retention.ms: 86400000
+            max.message.bytes: 1048588
+          bindingVersion: '0.4.0'
+        "#,
+    )
+    .unwrap();
+    let kafka = binding.kafka.unwrap();
+    assert_eq!(kafka.partitions, Some(20));
+    let topic_configuration = kafka.topic_configuration.unwrap();
+    assert_eq!(topic_configuration.cleanup_policy, ["delete", "compact"]);
+    assert_eq!(topic_configuration.retention_bytes, Some(-1));
+    assert_eq!(topic_configuration.max_message_bytes, Some(1048588));
+}
+
+#[test]
+fn test_sqs_channel_binding() {
+    let binding: ChannelBinding = serde_yaml::from_str(
+        r#"
+        sqs:
+          queue:
+            name: user-signedup-queue
+            fifoQueue: true
+            redrivePolicy:
+              deadLetterQueue:
+                arn: arn:aws:SQS:eu-west-1:0000000:123456789
+              maxReceiveCount: 15
+            policy:
+              statements:
+                - effect : Deny
+                  principal: arn:aws:iam::123456789012:user/dec.kolakowski
+                  action: [sqs:SendMessage, sqs:ReceiveMessage]
+          deadLetterQueue:
+            name: user-signedup-dlq
+            messageRetentionPeriod: 1209600
+            fifoQueue: true
+          bindingVersion: 0.2.0
+        "#,
+    )
+    .unwrap();
+    let sqs = binding.sqs.unwrap();
+    assert!(sqs.queue.fifo_queue);
+    let statement = &sqs.queue.policy.unwrap().statements[0];
+    assert_eq!(
+        statement.principal,
+        AWSPolicyValue::Single("arn:aws:iam::123456789012:user/dec.kolakowski".to_string())
+    );
+    assert!(matches!(&statement.action, AWSPolicyValue::Multiple(actions) if actions.len() == 2));
+    assert_eq!(
+        sqs.dead_letter_queue.unwrap().message_retention_period,
+        Some(1209600)
+    );
+}
+
+#[test]
+fn test_pulsar_channel_binding() {
+    let yaml = r#"
+        pulsar:
+          namespace: 'staging'
+          persistence: 'persistent'
+          compaction: 1000
+          geo-replication:
+            - 'us-east1'
+            - 'us-west1'
+          retention:
+            time: 7
+            size: 1000
+          ttl: 360
+          deduplication: false
+          bindingVersion: '0.1.0'
+        "#;
+    let binding: ChannelBinding = serde_yaml::from_str(yaml).unwrap();
+    let pulsar = binding.pulsar.as_ref().unwrap();
+    assert_eq!(pulsar.namespace, "staging");
+    assert_eq!(pulsar.geo_replication, ["us-east1", "us-west1"]);
+    assert_eq!(pulsar.retention.as_ref().unwrap().time, Some(7));
+    assert_eq!(pulsar.deduplication, Some(false));
+    assert!(binding.extensions.is_empty());
+    assert_eq!(
+        serde_yaml::to_value(&binding).unwrap(),
+        serde_yaml::from_str::<serde_yaml::Value>(yaml).unwrap()
+    );
+}
+
+#[test]
+fn test_anypointmq_channel_binding() {
+    let binding: ChannelBinding = serde_yaml::from_str(
+        r#"
+        anypointmq:
+          destination:     user-signup-exchg
+          destinationType: exchange
+          bindingVersion:  '0.0.1'
+        "#,
+    )
+    .unwrap();
+    let anypointmq = binding.anypointmq.unwrap();
+    assert_eq!(anypointmq.destination.as_deref(), Some("user-signup-exchg"));
+    assert_eq!(anypointmq.destination_type.as_deref(), Some("exchange"));
+    assert!(binding.extensions.is_empty());
+}
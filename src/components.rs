@@ -1,10 +1,16 @@
+use std::collections::HashSet;
+
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    message_binding::MessageBinding, Channel, ChannelBinding, CorrelationId, Message, MessageTrait,
-    OperationBinding, OperationTrait, Parameter, ReferenceOr, Schema, SecurityScheme, Server,
-    ServerBinding,
+    channel::OperationMessageType,
+    error::FormatError,
+    message_binding::MessageBinding,
+    validation::{join_pointer, Validate, ValidationError},
+    AsyncAPI, Channel, ChannelBinding, CorrelationId, Error, Message, MessageTrait,
+    OperationBinding, OperationTrait, Parameter, Payload, ReferenceOr, ResolveError, Schema,
+    SecurityRequirement, SecurityScheme, Server, ServerBinding, ServerVariable,
 };
 
 /// Holds a set of reusable objects for different aspects of the AsyncAPI specification.
@@ -216,6 +222,12 @@ pub struct Components {
     #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub servers: IndexMap<String, ReferenceOr<Server>>,
     /// An object to hold reusable
+    /// [Server Variable Objects][crate::ServerVariable].
+    ///
+    /// Added in AsyncAPI 2.4.0.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub server_variables: IndexMap<String, ReferenceOr<ServerVariable>>,
+    /// An object to hold reusable
     /// [Server Bindings Objects][crate::ServerBinding].
     #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub server_bindings: IndexMap<String, ReferenceOr<ServerBinding>>,
@@ -236,6 +248,1010 @@ pub struct Components {
     pub message_bindings: IndexMap<String, ReferenceOr<MessageBinding>>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
+
+/// The kinds of objects the [Components Object][Components] holds, one per
+/// map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentKind {
+    Schema,
+    Message,
+    SecurityScheme,
+    Parameter,
+    CorrelationId,
+    OperationTrait,
+    MessageTrait,
+    Server,
+    ServerVariable,
+    ServerBinding,
+    Channel,
+    ChannelBinding,
+    OperationBinding,
+    MessageBinding,
+}
+
+impl ComponentKind {
+    const ALL: [ComponentKind; 14] = [
+        ComponentKind::Schema,
+        ComponentKind::Message,
+        ComponentKind::SecurityScheme,
+        ComponentKind::Parameter,
+        ComponentKind::CorrelationId,
+        ComponentKind::OperationTrait,
+        ComponentKind::MessageTrait,
+        ComponentKind::Server,
+        ComponentKind::ServerVariable,
+        ComponentKind::ServerBinding,
+        ComponentKind::Channel,
+        ComponentKind::ChannelBinding,
+        ComponentKind::OperationBinding,
+        ComponentKind::MessageBinding,
+    ];
+
+    /// The name of the field of the Components Object holding this kind,
+    /// e.g. `schemas`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ComponentKind::Schema => "schemas",
+            ComponentKind::Message => "messages",
+            ComponentKind::SecurityScheme => "securitySchemes",
+            ComponentKind::Parameter => "parameters",
+            ComponentKind::CorrelationId => "correlationIds",
+            ComponentKind::OperationTrait => "operationTraits",
+            ComponentKind::MessageTrait => "messageTraits",
+            ComponentKind::Server => "servers",
+            ComponentKind::ServerVariable => "serverVariables",
+            ComponentKind::ServerBinding => "serverBindings",
+            ComponentKind::Channel => "channels",
+            ComponentKind::ChannelBinding => "channelBindings",
+            ComponentKind::OperationBinding => "operationBindings",
+            ComponentKind::MessageBinding => "messageBindings",
+        }
+    }
+
+    /// The kind held in the field called `field`, the inverse of
+    /// [as_str][ComponentKind::as_str].
+    pub fn from_field(field: &str) -> Option<Self> {
+        ComponentKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == field)
+    }
+
+    /// The reference to the component of this kind called `name`, e.g.
+    /// `#/components/schemas/User`.
+    pub fn pointer(self, name: &str) -> String {
+        join_pointer(&format!("#/components/{}", self.as_str()), name)
+    }
+
+    /// The kind and name of the component `reference` points to, if it
+    /// points to one in the same document.
+    pub(crate) fn parse(reference: &str) -> Option<(Self, String)> {
+        let (field, name) = reference.strip_prefix("#/components/")?.split_once('/')?;
+        if name.is_empty() || name.contains('/') {
+            return None;
+        }
+        let name = name.replace("~1", "/").replace("~0", "~");
+        Some((ComponentKind::from_field(field)?, name))
+    }
+}
+
+impl std::fmt::Display for ComponentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The types the [Components Object][Components] holds in a map, the ones
+/// [`ReferenceOr::resolve`] can resolve references to.
+pub trait Component: Sized {
+    /// The kind of component this is.
+    const KIND: ComponentKind;
+
+    /// The map of `components` holding this kind.
+    fn components(components: &Components) -> &IndexMap<String, ReferenceOr<Self>>;
+}
+
+/// Implements the `add_*` methods of [`Components`], the constructors of the
+/// references to components and [`Component`], one per map.
+macro_rules! components {
+    ($($add:ident, $reference:ident($field:ident: $ty:ty) => $kind:ident,)*) => {
+        impl Components {
+            $(
+                #[doc = concat!(
+                    "Adds `item` to [", stringify!($field), "][Components::", stringify!($field),
+                    "] as `name`, replacing one of the same name, and returns the reference to it."
+                )]
+                pub fn $add(&mut self, name: impl Into<String>, item: $ty) -> String {
+                    let name = name.into();
+                    let reference = ComponentKind::$kind.pointer(&name);
+                    self.$field.insert(name, ReferenceOr::Item(item));
+                    reference
+                }
+            )*
+        }
+
+        $(
+            impl ReferenceOr<$ty> {
+                #[doc = concat!(
+                    "A reference to the component in [", stringify!($field), "][Components::",
+                    stringify!($field), "] called `name`."
+                )]
+                pub fn $reference(name: &str) -> Self {
+                    ReferenceOr::ref_(&ComponentKind::$kind.pointer(name))
+                }
+            }
+
+            impl Component for $ty {
+                const KIND: ComponentKind = ComponentKind::$kind;
+
+                fn components(components: &Components) -> &IndexMap<String, ReferenceOr<Self>> {
+                    &components.$field
+                }
+            }
+        )*
+    };
+}
+
+impl Components {
+    /// Adds `channel` to [channels][Components::channels] as `name`,
+    /// replacing one of the same name, and returns the reference to it.
+    pub fn add_channel(&mut self, name: impl Into<String>, channel: Channel) -> String {
+        let name = name.into();
+        let reference = ComponentKind::Channel.pointer(&name);
+        self.channels.insert(name, channel);
+        reference
+    }
+}
+
+impl Components {
+    /// The names of the components of `kind`, in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{ComponentKind, Components, Message};
+    ///
+    /// let mut components = Components::default();
+    /// components.add_message("UserSignedUp", Message::default());
+    /// assert_eq!(components.names(ComponentKind::Message), ["UserSignedUp"]);
+    /// assert!(components.names(ComponentKind::Schema).is_empty());
+    /// ```
+    pub fn names(&self, kind: ComponentKind) -> Vec<&str> {
+        fn keys<V>(map: &IndexMap<String, V>) -> Vec<&str> {
+            map.keys().map(String::as_str).collect()
+        }
+        match kind {
+            ComponentKind::Schema => keys(&self.schemas),
+            ComponentKind::Message => keys(&self.messages),
+            ComponentKind::SecurityScheme => keys(&self.security_schemes),
+            ComponentKind::Parameter => keys(&self.parameters),
+            ComponentKind::CorrelationId => keys(&self.correlation_ids),
+            ComponentKind::OperationTrait => keys(&self.operation_traits),
+            ComponentKind::MessageTrait => keys(&self.message_traits),
+            ComponentKind::Server => keys(&self.servers),
+            ComponentKind::ServerVariable => keys(&self.server_variables),
+            ComponentKind::ServerBinding => keys(&self.server_bindings),
+            ComponentKind::Channel => keys(&self.channels),
+            ComponentKind::ChannelBinding => keys(&self.channel_bindings),
+            ComponentKind::OperationBinding => keys(&self.operation_bindings),
+            ComponentKind::MessageBinding => keys(&self.message_bindings),
+        }
+    }
+}
+
+impl Validate for Components {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for kind in ComponentKind::ALL {
+            for name in self.names(kind) {
+                if !is_component_name(name) {
+                    errors.push(ValidationError::new(
+                        join_pointer(&format!("{path}/{kind}"), name),
+                        format!("`{name}` may only contain letters, digits, `.`, `-` and `_`"),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether `name` matches `^[a-zA-Z0-9\.\-_]+$`, the pattern the
+/// keys of the Components Object must match.
+fn is_component_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+}
+
+impl AsyncAPI {
+    /// Renames the component of `kind` called `old` to `new`, keeping its
+    /// position, and rewrites every reference to it. Renaming a security
+    /// scheme also renames it in the security requirements. Returns how many
+    /// references and requirements were rewritten.
+    ///
+    /// Fails if there is no component called `old` or one called `new`
+    /// already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, ComponentKind};
+    ///
+    /// let mut api: AsyncAPI = r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         payload:
+    ///           $ref: '#/components/schemas/User'
+    /// components:
+    ///   schemas:
+    ///     User:
+    ///       type: object
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let renamed = api
+    ///     .rename_component(ComponentKind::Schema, "User", "Account")
+    ///     .unwrap();
+    /// assert_eq!(renamed, 1);
+    /// assert!(api.components.unwrap().schemas.contains_key("Account"));
+    /// ```
+    pub fn rename_component(
+        &mut self,
+        kind: ComponentKind,
+        old: &str,
+        new: &str,
+    ) -> Result<usize, Error> {
+        let old_pointer = kind.pointer(old);
+        let new_pointer = kind.pointer(new);
+        let components = self
+            .components
+            .as_mut()
+            .ok_or_else(|| ResolveError::NotFound(old_pointer.clone()))?;
+        let renamed = match kind {
+            ComponentKind::Schema => rename_key(&mut components.schemas, old, new),
+            ComponentKind::Message => rename_key(&mut components.messages, old, new),
+            ComponentKind::SecurityScheme => rename_key(&mut components.security_schemes, old, new),
+            ComponentKind::Parameter => rename_key(&mut components.parameters, old, new),
+            ComponentKind::CorrelationId => rename_key(&mut components.correlation_ids, old, new),
+            ComponentKind::OperationTrait => rename_key(&mut components.operation_traits, old, new),
+            ComponentKind::MessageTrait => rename_key(&mut components.message_traits, old, new),
+            ComponentKind::Server => rename_key(&mut components.servers, old, new),
+            ComponentKind::ServerVariable => rename_key(&mut components.server_variables, old, new),
+            ComponentKind::ServerBinding => rename_key(&mut components.server_bindings, old, new),
+            ComponentKind::Channel => rename_key(&mut components.channels, old, new),
+            ComponentKind::ChannelBinding => rename_key(&mut components.channel_bindings, old, new),
+            ComponentKind::OperationBinding => {
+                rename_key(&mut components.operation_bindings, old, new)
+            }
+            ComponentKind::MessageBinding => rename_key(&mut components.message_bindings, old, new),
+        };
+        match renamed {
+            Rename::Renamed => {}
+            Rename::Unchanged => return Ok(0),
+            Rename::NotFound => return Err(ResolveError::NotFound(old_pointer).into()),
+            Rename::Taken => {
+                return Err(Error::Validate(vec![ValidationError::new(
+                    new_pointer,
+                    "a component of this name already exists",
+                )]))
+            }
+        }
+
+        let mut document = serde_json::to_value(&*self)
+            .map_err(|error| Error::Serialize(FormatError::Json(error)))?;
+        let mut count = rewrite_references(&mut document, &old_pointer, &new_pointer);
+        *self = AsyncAPI::deserialize(document)
+            .map_err(|error| Error::Parse(FormatError::Json(error)))?;
+
+        if kind == ComponentKind::SecurityScheme {
+            let (servers, channels, operation_traits) = match &mut self.components {
+                Some(Components {
+                    servers,
+                    channels,
+                    operation_traits,
+                    ..
+                }) => (Some(servers), Some(channels), Some(operation_traits)),
+                None => (None, None, None),
+            };
+            let servers = self
+                .servers
+                .values_mut()
+                .chain(servers.into_iter().flat_map(IndexMap::values_mut));
+            let channels = self
+                .channels
+                .values_mut()
+                .chain(channels.into_iter().flat_map(IndexMap::values_mut));
+            let mut operation_traits: Vec<_> = operation_traits
+                .into_iter()
+                .flat_map(IndexMap::values_mut)
+                .collect();
+
+            let mut requirements: Vec<&mut Vec<SecurityRequirement>> = Vec::new();
+            for server in servers {
+                if let ReferenceOr::Item(server) = server {
+                    requirements.push(&mut server.security);
+                }
+            }
+            for channel in channels {
+                for operation in [&mut channel.publish, &mut channel.subscribe]
+                    .into_iter()
+                    .flatten()
+                {
+                    requirements.push(&mut operation.security);
+                    operation_traits.extend(&mut operation.traits);
+                }
+            }
+            for operation_trait in operation_traits {
+                if let ReferenceOr::Item(operation_trait) = operation_trait {
+                    requirements.push(&mut operation_trait.security);
+                }
+            }
+            for requirement in requirements.into_iter().flatten() {
+                if rename_key(&mut requirement.values, old, new) == Rename::Renamed {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Removes the components that nothing outside the components uses,
+    /// directly or through other components, and returns the references to
+    /// the removed components in document order.
+    ///
+    /// Components are used by references to them or into them, and security
+    /// schemes also by the security requirements naming them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::AsyncAPI;
+    ///
+    /// let mut api: AsyncAPI = r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         $ref: '#/components/messages/UserSignedUp'
+    /// components:
+    ///   messages:
+    ///     UserSignedUp:
+    ///       payload:
+    ///         $ref: '#/components/schemas/User'
+    ///     UserDeleted: {}
+    ///   schemas:
+    ///     User:
+    ///       type: object
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let removed = api.prune_unused_components().unwrap();
+    /// assert_eq!(removed, ["#/components/messages/UserDeleted"]);
+    /// ```
+    pub fn prune_unused_components(&mut self) -> Result<Vec<String>, Error> {
+        let mut document = serde_json::to_value(&*self)
+            .map_err(|error| Error::Serialize(FormatError::Json(error)))?;
+        let Some(serde_json::Value::Object(mut components)) = document
+            .as_object_mut()
+            .and_then(|document| document.remove("components"))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut used = HashSet::new();
+        let mut pending = Vec::new();
+        collect_uses(&document, &mut pending);
+        while let Some((kind, name)) = pending.pop() {
+            if !used.insert((kind, name.clone())) {
+                continue;
+            }
+            if let Some(component) = components
+                .get(kind.as_str())
+                .and_then(|components| components.get(&name))
+            {
+                collect_uses(component, &mut pending);
+            }
+        }
+
+        let mut removed = Vec::new();
+        for (field, map) in components.iter_mut() {
+            let (Some(kind), serde_json::Value::Object(map)) =
+                (ComponentKind::from_field(field), map)
+            else {
+                continue;
+            };
+            map.retain(|name, _| {
+                let keep = used.contains(&(kind, name.clone()));
+                if !keep {
+                    removed.push(kind.pointer(name));
+                }
+                keep
+            });
+        }
+        if removed.is_empty() {
+            return Ok(removed);
+        }
+        document["components"] = serde_json::Value::Object(components);
+        *self = AsyncAPI::deserialize(document)
+            .map_err(|error| Error::Parse(FormatError::Json(error)))?;
+        Ok(removed)
+    }
+
+    /// Moves the schemas defined inline as the payload or headers of a
+    /// message into [schemas][Components::schemas] and replaces them by
+    /// references. Returns the names of the schemas added.
+    ///
+    /// The schemas are named after their message, its name, message id or
+    /// component name, followed by `Payload` or `Headers`. A schema equal to
+    /// one already in the components, or lifted before, is replaced by a
+    /// reference to that one instead. Messages of operations, message
+    /// components and the headers of message traits are covered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, Payload};
+    ///
+    /// let mut api: AsyncAPI = r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         name: UserSignedUp
+    ///         payload:
+    ///           type: object
+    ///   user/updated:
+    ///     subscribe:
+    ///       message:
+    ///         name: UserUpdated
+    ///         payload:
+    ///           type: object
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// assert_eq!(api.lift_inline_schemas(), ["UserSignedUpPayload"]);
+    /// let components = api.components.as_ref().unwrap();
+    /// assert!(components.schemas.contains_key("UserSignedUpPayload"));
+    /// ```
+    pub fn lift_inline_schemas(&mut self) -> Vec<String> {
+        let mut components = self.components.take().unwrap_or_default();
+        let Components {
+            schemas,
+            messages,
+            channels,
+            message_traits,
+            ..
+        } = &mut components;
+        let mut lifter = Lifter {
+            schemas,
+            lifted: Vec::new(),
+        };
+
+        for channel in self.channels.values_mut().chain(channels.values_mut()) {
+            for operation in [&mut channel.publish, &mut channel.subscribe]
+                .into_iter()
+                .flatten()
+            {
+                let operation_id = operation.operation_id.clone();
+                let messages: Vec<&mut ReferenceOr<Message>> = match &mut operation.message {
+                    Some(OperationMessageType::Single(message)) => vec![message],
+                    Some(OperationMessageType::OneOf { one_of }) => one_of.iter_mut().collect(),
+                    Some(OperationMessageType::Map(messages)) => messages.values_mut().collect(),
+                    None => Vec::new(),
+                };
+                for message in messages {
+                    if let ReferenceOr::Item(message) = message {
+                        lifter.lift_message(message, operation_id.as_deref());
+                    }
+                }
+            }
+        }
+        for (name, message) in messages.iter_mut() {
+            if let ReferenceOr::Item(message) = message {
+                lifter.lift_message(message, Some(name));
+            }
+        }
+        for (name, message_trait) in message_traits.iter_mut() {
+            if let ReferenceOr::Item(message_trait) = message_trait {
+                if let Some(headers) = &mut message_trait.headers {
+                    lifter.lift(headers, &format!("{name}Headers"));
+                }
+            }
+        }
+
+        let lifted = lifter.lifted;
+        if !lifted.is_empty() || components != Components::default() {
+            self.components = Some(components);
+        }
+        lifted
+    }
+}
+
+/// The state of [`AsyncAPI::lift_inline_schemas`].
+struct Lifter<'a> {
+    schemas: &'a mut IndexMap<String, ReferenceOr<Schema>>,
+    lifted: Vec<String>,
+}
+
+impl Lifter<'_> {
+    /// Lifts the payload and headers of `message`, named after the message
+    /// or else `fallback`.
+    fn lift_message(&mut self, message: &mut Message, fallback: Option<&str>) {
+        let name = message
+            .name
+            .as_deref()
+            .or(message.message_id.as_deref())
+            .or(fallback)
+            .unwrap_or("Message")
+            .to_string();
+        if let Some(Payload::Schema(payload)) = &mut message.payload {
+            self.lift(payload, &format!("{name}Payload"));
+        }
+        if let Some(headers) = &mut message.headers {
+            self.lift(headers, &format!("{name}Headers"));
+        }
+    }
+
+    /// Replaces an inline `schema` by a reference to an equal schema of the
+    /// components, adding it as `name` if there is none.
+    fn lift(&mut self, schema: &mut ReferenceOr<Schema>, name: &str) {
+        let ReferenceOr::Item(item) = schema else {
+            return;
+        };
+        let existing = self
+            .schemas
+            .iter()
+            .find(
+                |(_, existing)| matches!(existing, ReferenceOr::Item(existing) if existing == item),
+            )
+            .map(|(name, _)| name.clone());
+        let name = match existing {
+            Some(name) => name,
+            None => {
+                let name: String = name
+                    .chars()
+                    .map(|c| match c {
+                        'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => c,
+                        _ => '_',
+                    })
+                    .collect();
+                let name = (1..)
+                    .map(|number| match number {
+                        1 => name.clone(),
+                        number => format!("{name}{number}"),
+                    })
+                    .find(|name| !self.schemas.contains_key(name))
+                    .expect("one of infinitely many names is free");
+                self.schemas
+                    .insert(name.clone(), ReferenceOr::Item(item.clone()));
+                self.lifted.push(name.clone());
+                name
+            }
+        };
+        *schema = ReferenceOr::schema(&name);
+    }
+}
+
+/// Collects the components `value` uses, by references to them or into them
+/// and by security requirements.
+fn collect_uses(value: &serde_json::Value, uses: &mut Vec<(ComponentKind, String)>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object {
+                match value {
+                    serde_json::Value::String(reference) if key == "$ref" => {
+                        let component = reference
+                            .strip_prefix("#/components/")
+                            .and_then(|pointer| pointer.split_once('/'))
+                            .and_then(|(field, rest)| {
+                                let name = rest.split('/').next()?;
+                                let name = name.replace("~1", "/").replace("~0", "~");
+                                Some((ComponentKind::from_field(field)?, name))
+                            });
+                        uses.extend(component);
+                    }
+                    serde_json::Value::Array(requirements) if key == "security" => {
+                        for requirement in requirements.iter().filter_map(|r| r.as_object()) {
+                            uses.extend(
+                                requirement
+                                    .keys()
+                                    .map(|name| (ComponentKind::SecurityScheme, name.clone())),
+                            );
+                        }
+                    }
+                    value => collect_uses(value, uses),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                collect_uses(value, uses);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The outcomes of [`rename_key`].
+#[derive(Debug, PartialEq)]
+enum Rename {
+    Renamed,
+    Unchanged,
+    NotFound,
+    Taken,
+}
+
+/// Renames the entry `old` of `map` to `new` in place.
+fn rename_key<V>(map: &mut IndexMap<String, V>, old: &str, new: &str) -> Rename {
+    if !map.contains_key(old) {
+        return Rename::NotFound;
+    }
+    if old == new {
+        return Rename::Unchanged;
+    }
+    if map.contains_key(new) {
+        return Rename::Taken;
+    }
+    *map = std::mem::take(map)
+        .into_iter()
+        .map(|(key, value)| {
+            if key == old {
+                (new.to_string(), value)
+            } else {
+                (key, value)
+            }
+        })
+        .collect();
+    Rename::Renamed
+}
+
+/// Points the references to `old`, or into it, to `new` instead and returns
+/// how many there were.
+fn rewrite_references(value: &mut serde_json::Value, old: &str, new: &str) -> usize {
+    match value {
+        serde_json::Value::Object(object) => {
+            if let Some(serde_json::Value::String(reference)) = object.get_mut("$ref") {
+                let rest = reference.strip_prefix(old);
+                if let Some(rest) = rest.filter(|rest| rest.is_empty() || rest.starts_with('/')) {
+                    *reference = format!("{new}{rest}");
+                    return 1;
+                }
+                return 0;
+            }
+            object
+                .values_mut()
+                .map(|value| rewrite_references(value, old, new))
+                .sum()
+        }
+        serde_json::Value::Array(values) => values
+            .iter_mut()
+            .map(|value| rewrite_references(value, old, new))
+            .sum(),
+        _ => 0,
+    }
+}
+
+components! {
+    add_schema, schema(schemas: Schema) => Schema,
+    add_message, message(messages: Message) => Message,
+    add_security_scheme, security_scheme(security_schemes: SecurityScheme) => SecurityScheme,
+    add_parameter, parameter(parameters: Parameter) => Parameter,
+    add_correlation_id, correlation_id(correlation_ids: CorrelationId) => CorrelationId,
+    add_operation_trait, operation_trait(operation_traits: OperationTrait) => OperationTrait,
+    add_message_trait, message_trait(message_traits: MessageTrait) => MessageTrait,
+    add_server, server(servers: Server) => Server,
+    add_server_variable, server_variable(server_variables: ServerVariable) => ServerVariable,
+    add_server_binding, server_binding(server_bindings: ServerBinding) => ServerBinding,
+    add_channel_binding, channel_binding(channel_bindings: ChannelBinding) => ChannelBinding,
+    add_operation_binding, operation_binding(operation_bindings: OperationBinding) => OperationBinding,
+    add_message_binding, message_binding(message_bindings: MessageBinding) => MessageBinding,
+}
+
+#[test]
+fn test_add_components() {
+    let mut components = Components::default();
+    let message = components.add_message("UserSignedUp", Message::default());
+    assert_eq!(message, "#/components/messages/UserSignedUp");
+    let binding = components.add_channel_binding("user/topic", ChannelBinding::default());
+    assert_eq!(binding, "#/components/channelBindings/user~1topic");
+    assert!(components.messages.contains_key("UserSignedUp"));
+    assert!(components.channel_bindings.contains_key("user/topic"));
+}
+
+#[test]
+fn test_component_references() {
+    let reference = ReferenceOr::<Message>::message("UserSignedUp");
+    assert_eq!(
+        reference,
+        ReferenceOr::ref_("#/components/messages/UserSignedUp")
+    );
+    assert_eq!(reference.component_kind(), Some(ComponentKind::Message));
+    assert_eq!(reference.component_name().as_deref(), Some("UserSignedUp"));
+
+    let reference = ReferenceOr::<ChannelBinding>::channel_binding("user/topic");
+    assert_eq!(reference.component_name().as_deref(), Some("user/topic"));
+    assert_eq!(
+        ReferenceOr::<Schema>::ref_("#/components/schemas/User/properties/id").component_kind(),
+        None
+    );
+    assert_eq!(
+        ReferenceOr::<Schema>::ref_("other.yaml#/components/schemas/User").component_kind(),
+        None
+    );
+    assert_eq!(ReferenceOr::Item(Message::default()).component_name(), None);
+}
+
+#[test]
+fn test_resolve_components() {
+    use crate::ResolveError;
+
+    let mut components = Components::default();
+    let message = components.add_message("UserSignedUp", Message::default());
+    components
+        .messages
+        .insert("SignedUp".to_string(), ReferenceOr::ref_(&message));
+    components
+        .messages
+        .insert("Loop".to_string(), ReferenceOr::message("Loop"));
+
+    assert!(ReferenceOr::<Message>::message("SignedUp")
+        .resolve(&components)
+        .is_ok());
+    assert_eq!(
+        ReferenceOr::<Message>::message("Loop").resolve(&components),
+        Err(ResolveError::Cycle(
+            "#/components/messages/Loop".to_string()
+        ))
+    );
+    assert_eq!(
+        ReferenceOr::<Message>::ref_("#/components/schemas/UserSignedUp").resolve(&components),
+        Err(ResolveError::NotFound(
+            "#/components/schemas/UserSignedUp".to_string()
+        ))
+    );
+    assert_eq!(
+        ReferenceOr::<Message>::ref_("common.yaml#/components/messages/UserSignedUp")
+            .resolve(&components),
+        Err(ResolveError::External(
+            "common.yaml#/components/messages/UserSignedUp".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_rename_component() {
+    let mut api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    servers:
+      production:
+        url: broker.example.com
+        protocol: kafka
+        security:
+          - sasl: []
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            payload:
+              $ref: '#/components/schemas/User/properties/name'
+    components:
+      schemas:
+        Account:
+          type: object
+        User:
+          type: object
+          properties:
+            name:
+              type: string
+        UserName:
+          $ref: '#/components/schemas/UserName'
+      securitySchemes:
+        sasl:
+          type: scramSha256
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        api.rename_component(ComponentKind::Schema, "User", "Person")
+            .unwrap(),
+        1
+    );
+    let components = api.components.as_ref().unwrap();
+    assert_eq!(
+        components.schemas.keys().collect::<Vec<_>>(),
+        ["Account", "Person", "UserName"]
+    );
+    let payload = &api.channels["user/signedup"]
+        .subscribe
+        .as_ref()
+        .unwrap()
+        .message;
+    assert!(serde_json::to_string(payload)
+        .unwrap()
+        .contains("#/components/schemas/Person/properties/name"));
+
+    assert_eq!(
+        api.rename_component(ComponentKind::SecurityScheme, "sasl", "scram")
+            .unwrap(),
+        1
+    );
+    let server = &api.servers["production"];
+    assert!(
+        matches!(server, ReferenceOr::Item(server) if server.security[0].values.contains_key("scram"))
+    );
+
+    assert!(matches!(
+        api.rename_component(ComponentKind::Schema, "Person", "Account"),
+        Err(Error::Validate(_))
+    ));
+    assert!(matches!(
+        api.rename_component(ComponentKind::Message, "User", "Person"),
+        Err(Error::Resolve(ResolveError::NotFound(_)))
+    ));
+}
+
+#[test]
+fn test_prune_unused_components() {
+    let mut api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    servers:
+      production:
+        url: broker.example.com
+        protocol: kafka
+        security:
+          - sasl: []
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            payload:
+              $ref: '#/components/schemas/User/properties/address'
+    components:
+      schemas:
+        User:
+          type: object
+          properties:
+            address:
+              $ref: '#/components/schemas/Address'
+        Address:
+          type: string
+        Unused:
+          $ref: '#/components/schemas/AlsoUnused'
+        AlsoUnused:
+          type: string
+      securitySchemes:
+        sasl:
+          type: scramSha256
+        apiKey:
+          type: httpApiKey
+          name: key
+          in: header
+      x-owner: accounts
+    "#
+    .parse()
+    .unwrap();
+
+    let removed = api.prune_unused_components().unwrap();
+    assert_eq!(
+        removed,
+        [
+            "#/components/schemas/Unused",
+            "#/components/schemas/AlsoUnused",
+            "#/components/securitySchemes/apiKey"
+        ]
+    );
+    let components = api.components.as_ref().unwrap();
+    assert_eq!(
+        components.schemas.keys().collect::<Vec<_>>(),
+        ["User", "Address"]
+    );
+    assert!(components.security_schemes.contains_key("sasl"));
+    assert!(components.extensions.contains_key("x-owner"));
+    assert!(api.prune_unused_components().unwrap().is_empty());
+}
+
+#[test]
+fn test_lift_inline_schemas() {
+    let mut api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          operationId: onUserSignedUp
+          message:
+            oneOf:
+              - payload:
+                  type: string
+                headers:
+                  type: object
+              - name: user signed up
+                payload:
+                  $ref: '#/components/schemas/Existing'
+    components:
+      schemas:
+        Existing:
+          type: object
+          properties:
+            id:
+              type: integer
+      messages:
+        UserDeleted:
+          payload:
+            type: string
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        api.lift_inline_schemas(),
+        ["onUserSignedUpPayload", "onUserSignedUpHeaders"]
+    );
+    let components = api.components.as_ref().unwrap();
+    assert_eq!(
+        components.schemas.keys().collect::<Vec<_>>(),
+        ["Existing", "onUserSignedUpPayload", "onUserSignedUpHeaders"]
+    );
+    let ReferenceOr::Item(deleted) = &components.messages["UserDeleted"] else {
+        panic!("the message is an item");
+    };
+    assert_eq!(
+        deleted.payload,
+        Some(Payload::reference(
+            "#/components/schemas/onUserSignedUpPayload"
+        ))
+    );
+    assert!(api.lift_inline_schemas().is_empty());
+}
+
+#[test]
+fn test_validate_component_names() {
+    let mut components = Components::default();
+    components.add_message("my.org.User_Name-1", Message::default());
+    components.add_message("user/signedup", Message::default());
+    components.add_channel("", Channel::default());
+    let Err(Error::Validate(errors)) = components.validate() else {
+        panic!("the components are invalid");
+    };
+    assert_eq!(
+        errors,
+        [
+            ValidationError::new(
+                "/messages/user~1signedup",
+                "`user/signedup` may only contain letters, digits, `.`, `-` and `_`"
+            ),
+            ValidationError::new(
+                "/channels/",
+                "`` may only contain letters, digits, `.`, `-` and `_`"
+            ),
+        ]
+    );
+}
@@ -0,0 +1,1814 @@
+//! Conversion of documents between the AsyncAPI 2.x and
+//! [3.0][crate::v3] models.
+//!
+//! The conversions follow the
+//! [official migration guide](https://www.asyncapi.com/docs/migration/migrating-to-v3).
+use indexmap::IndexMap;
+
+use crate::{
+    reference::{reference_name, resolve_local},
+    schema::{SchemaKind, Type},
+    v3::{self, Action, AsyncApiV3, Reference},
+    validation::join_pointer,
+    AsyncAPI, Channel, Components, ExternalDocumentation, Info, Message, MessageTrait,
+    MultiFormatSchema, Operation, OperationKind, OperationMessageType, OperationTrait, Parameter,
+    Payload, ReferenceOr, ResolveError, SecurityRequirement, SecurityScheme, Server, SpecVersion,
+    Tag,
+};
+
+/// Upgrades a 2.x document to AsyncAPI 3.0.
+///
+/// - The root `tags` and `externalDocs` move to the `info` object.
+/// - Server URLs are split into `host` and `pathname`.
+/// - Channels are keyed by an identifier derived from their address, the
+///   address itself moves to the `address` field.
+/// - `publish` and `subscribe` operations move to the top-level `operations`
+///   object. As 2.x operations are described from the point of view of the
+///   clients, `subscribe` becomes [`send`][Action::Send] and `publish`
+///   becomes [`receive`][Action::Receive]. Operations are keyed by their
+///   `operationId`, or by `{channelId}.{publish|subscribe}` without one.
+/// - The messages of the operations are hoisted into the `messages` of their
+///   channel, from where the operations reference them. A `messageId` only
+///   names the message there and is dropped, as 3.0 removed it.
+/// - The `schemaFormat` of a message, or else that of the last of its traits
+///   that has one, moves into a [multi format payload][Payload::MultiFormat].
+///   The `schemaFormat` of message traits is dropped.
+/// - Security requirements become references to the security schemes;
+///   scopes can't be carried over.
+///
+/// Channels that reference a channel in the components are inlined, so
+/// their address isn't lost.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::{convert::upgrade_v2_to_v3, v3::Action, AsyncAPI, ReferenceOr};
+///
+/// let api: AsyncAPI = r#"
+/// asyncapi: 2.6.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels:
+///   user/signedup:
+///     subscribe:
+///       operationId: sendUserSignedUp
+///       message:
+///         $ref: '#/components/messages/UserSignedUp'
+/// "#
+/// .parse()
+/// .unwrap();
+///
+/// let upgraded = upgrade_v2_to_v3(&api);
+/// let ReferenceOr::Item(operation) = &upgraded.operations["sendUserSignedUp"] else {
+///     unreachable!()
+/// };
+/// assert_eq!(operation.action, Action::Send);
+/// assert_eq!(operation.channel.reference, "#/channels/user_signedup");
+/// ```
+pub fn upgrade_v2_to_v3(api: &AsyncAPI) -> AsyncApiV3 {
+    let mut upgraded = AsyncApiV3 {
+        asyncapi: SpecVersion::V3_0_0,
+        id: api.id.clone(),
+        info: v3::Info {
+            title: api.info.title.clone(),
+            version: api.info.version.clone(),
+            description: api.info.description.clone(),
+            terms_of_service: api.info.terms_of_service.clone(),
+            contact: api.info.contact.clone(),
+            license: api.info.license.clone(),
+            tags: upgrade_tags(&api.tags),
+            external_docs: api.external_docs.clone().map(ReferenceOr::Item),
+            extensions: api.info.extensions.clone(),
+        },
+        servers: api
+            .servers
+            .iter()
+            .map(|(name, server)| (name.clone(), upgrade_ref(server, upgrade_server)))
+            .collect(),
+        default_content_type: api.default_content_type.clone(),
+        channels: IndexMap::new(),
+        operations: IndexMap::new(),
+        components: api.components.as_ref().map(upgrade_components),
+        extensions: api.extensions.clone(),
+    };
+
+    let mut inlined = Vec::new();
+    for (address, channel) in &api.channels {
+        let id = unique_key(&upgraded.channels, channel_id(address));
+        #[allow(deprecated)]
+        if let Some(reference) = &channel.reference {
+            inlined.push(reference.as_str());
+        }
+        let channel = match local_channel(api.components.as_ref(), channel) {
+            Ok(channel) => channel,
+            Err(reference) => {
+                upgraded.channels.insert(id, ReferenceOr::ref_(&reference));
+                continue;
+            }
+        };
+        let pointer = join_pointer("#/channels", &id);
+        let message_traits = api.components.as_ref().map(|c| &c.message_traits);
+        let channel = upgrade_channel(
+            Some(address),
+            channel,
+            &pointer,
+            message_traits,
+            &mut upgraded.operations,
+        );
+        upgraded.channels.insert(id, ReferenceOr::Item(channel));
+    }
+    // The operations of component channels that were inlined above are
+    // already top-level operations of the inlined copy.
+    if let Some(components) = &mut upgraded.components {
+        components
+            .operations
+            .retain(|_, operation| match operation {
+                ReferenceOr::Item(operation) => {
+                    !inlined.contains(&operation.channel.reference.as_str())
+                }
+                ReferenceOr::Reference { .. } => true,
+            });
+    }
+    upgraded
+}
+
+/// Follows the deprecated `$ref` of a 2.x channel into the components.
+/// Returns the reference if it can't be followed.
+#[allow(deprecated)]
+fn local_channel<'a>(
+    components: Option<&'a Components>,
+    channel: &'a Channel,
+) -> Result<&'a Channel, String> {
+    let Some(reference) = &channel.reference else {
+        return Ok(channel);
+    };
+    reference
+        .strip_prefix("#/components/channels/")
+        .and_then(|name| components?.channels.get(name))
+        .ok_or_else(|| reference.clone())
+}
+
+fn upgrade_components(components: &Components) -> v3::Components {
+    let mut upgraded = v3::Components {
+        schemas: components
+            .schemas
+            .iter()
+            .map(|(name, schema)| (name.clone(), Payload::Schema(schema.clone())))
+            .collect(),
+        servers: components
+            .servers
+            .iter()
+            .map(|(name, server)| (name.clone(), upgrade_ref(server, upgrade_server)))
+            .collect(),
+        messages: components
+            .messages
+            .iter()
+            .map(|(name, message)| {
+                let upgrade =
+                    |message: &Message| upgrade_message(message, Some(&components.message_traits));
+                (name.clone(), upgrade_ref(message, upgrade))
+            })
+            .collect(),
+        security_schemes: components
+            .security_schemes
+            .iter()
+            .map(|(name, scheme)| (name.clone(), upgrade_ref(scheme, upgrade_security_scheme)))
+            .collect(),
+        server_variables: components.server_variables.clone(),
+        parameters: components
+            .parameters
+            .iter()
+            .map(|(name, parameter)| (name.clone(), upgrade_ref(parameter, upgrade_parameter)))
+            .collect(),
+        correlation_ids: components.correlation_ids.clone(),
+        operation_traits: components
+            .operation_traits
+            .iter()
+            .map(|(name, operation_trait)| {
+                (
+                    name.clone(),
+                    upgrade_ref(operation_trait, upgrade_operation_trait),
+                )
+            })
+            .collect(),
+        message_traits: components
+            .message_traits
+            .iter()
+            .map(|(name, message_trait)| {
+                (
+                    name.clone(),
+                    upgrade_ref(message_trait, upgrade_message_trait),
+                )
+            })
+            .collect(),
+        server_bindings: components.server_bindings.clone(),
+        channel_bindings: components.channel_bindings.clone(),
+        operation_bindings: components.operation_bindings.clone(),
+        message_bindings: components.message_bindings.clone(),
+        extensions: components.extensions.clone(),
+        ..Default::default()
+    };
+    for (name, channel) in &components.channels {
+        let pointer = join_pointer("#/components/channels", name);
+        let channel = upgrade_channel(
+            None,
+            channel,
+            &pointer,
+            Some(&components.message_traits),
+            &mut upgraded.operations,
+        );
+        upgraded
+            .channels
+            .insert(name.clone(), ReferenceOr::Item(channel));
+    }
+    upgraded
+}
+
+/// Converts `channel`, found at `pointer`, and adds its operations to
+/// `operations`. `message_traits` are the traits in the components.
+fn upgrade_channel(
+    address: Option<&str>,
+    channel: &Channel,
+    pointer: &str,
+    message_traits: Option<&IndexMap<String, ReferenceOr<MessageTrait>>>,
+    operations: &mut IndexMap<String, ReferenceOr<v3::Operation>>,
+) -> v3::Channel {
+    let id = reference_name(pointer);
+    let mut upgraded = v3::Channel {
+        address: address.map(str::to_string),
+        description: channel.description.clone(),
+        servers: channel
+            .servers
+            .iter()
+            .map(|server| Reference::new(join_pointer("#/servers", server)))
+            .collect(),
+        parameters: channel
+            .parameters
+            .iter()
+            .map(|(name, parameter)| (name.clone(), upgrade_ref(parameter, upgrade_parameter)))
+            .collect(),
+        bindings: channel.bindings.clone(),
+        extensions: channel.extensions.clone(),
+        ..Default::default()
+    };
+
+    for (kind, operation) in channel.operations() {
+        let mut messages = Vec::new();
+        for message in operation.messages() {
+            let candidate = match message {
+                ReferenceOr::Reference { reference } => reference_name(reference),
+                ReferenceOr::Item(Message {
+                    message_id: Some(name),
+                    ..
+                })
+                | ReferenceOr::Item(Message {
+                    name: Some(name), ..
+                }) => name.clone(),
+                ReferenceOr::Item(_) => format!("{id}.{kind}.message"),
+            };
+            let message = upgrade_ref(message, |message| upgrade_message(message, message_traits));
+            let name = insert_message(&mut upgraded.messages, candidate, &message);
+            let messages_pointer = join_pointer(pointer, "messages");
+            messages.push(Reference::new(join_pointer(&messages_pointer, &name)));
+        }
+
+        let mut upgraded_operation = v3::Operation::new(kind.into(), Reference::new(pointer));
+        upgraded_operation.summary = operation.summary.clone();
+        upgraded_operation.description = operation.description.clone();
+        upgraded_operation.security = upgrade_security(&operation.security);
+        upgraded_operation.tags = upgrade_tags(&operation.tags);
+        upgraded_operation.external_docs = operation.external_docs.clone().map(ReferenceOr::Item);
+        upgraded_operation.bindings = operation.bindings.clone();
+        upgraded_operation.traits = operation
+            .traits
+            .iter()
+            .map(|operation_trait| upgrade_ref(operation_trait, upgrade_operation_trait))
+            .collect();
+        upgraded_operation.messages = messages;
+        upgraded_operation.extensions = operation.extensions.clone();
+
+        let candidate = operation
+            .operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{id}.{kind}"));
+        let operation_id = unique_key(operations, candidate);
+        operations.insert(operation_id, ReferenceOr::Item(upgraded_operation));
+    }
+    upgraded
+}
+
+fn upgrade_server(server: &Server) -> v3::Server {
+    v3::Server {
+        protocol_version: server.protocol_version.clone(),
+        description: server.description.clone(),
+        variables: server.variables.clone(),
+        security: upgrade_security(&server.security),
+        tags: upgrade_tags(&server.tags),
+        bindings: server.bindings.clone(),
+        extensions: server.extensions.clone(),
+        ..v3::Server::from_url(&server.url, server.protocol.clone())
+    }
+}
+
+/// Converts a 2.x parameter. Of its schema, only the values of string
+/// schemas can be carried over.
+fn upgrade_parameter(parameter: &Parameter) -> v3::Parameter {
+    let mut upgraded = v3::Parameter {
+        description: parameter.description.clone(),
+        location: parameter.location.clone(),
+        extensions: parameter.extensions.clone(),
+        ..Default::default()
+    };
+    if let Some(ReferenceOr::Item(schema)) = &parameter.schema {
+        if let Some(serde_json::Value::String(default)) = &schema.schema_data.default {
+            upgraded.default = Some(default.clone());
+        }
+        if let Some(serde_json::Value::String(example)) = &schema.schema_data.example {
+            upgraded.examples.push(example.clone());
+        }
+        if let SchemaKind::Type(Type::String(string)) = &schema.schema_kind {
+            upgraded.en = string.enumeration.iter().flatten().cloned().collect();
+        }
+    }
+    upgraded
+}
+
+/// Drops the `messageId` and moves the `schemaFormat` into the payload, see
+/// [`upgrade_v2_to_v3`]. `message_traits` are the traits in the components.
+fn upgrade_message(
+    message: &Message,
+    message_traits: Option<&IndexMap<String, ReferenceOr<MessageTrait>>>,
+) -> v3::Message {
+    let schema_format = message.schema_format.as_ref().or_else(|| {
+        message.traits.iter().rev().find_map(|message_trait| {
+            resolve_local(message_traits, "#/components/messageTraits/", message_trait)
+                .ok()?
+                .schema_format
+                .as_ref()
+        })
+    });
+    let payload = match (&message.payload, schema_format) {
+        (Some(payload @ Payload::MultiFormat(_)), _) | (Some(payload), None) => {
+            Some(payload.clone())
+        }
+        (Some(payload), Some(schema_format)) => Some(Payload::MultiFormat(MultiFormatSchema {
+            schema_format: schema_format.clone(),
+            schema: serde_json::to_value(payload)
+                .expect("payloads can always be serialized to JSON"),
+            extensions: IndexMap::new(),
+        })),
+        (None, _) => None,
+    };
+    v3::Message {
+        headers: message.headers.clone(),
+        payload,
+        correlation_id: message.correlation_id.clone(),
+        content_type: message.content_type.clone(),
+        name: message.name.clone(),
+        title: message.title.clone(),
+        summary: message.summary.clone(),
+        description: message.description.clone(),
+        tags: upgrade_tags(&message.tags),
+        external_docs: message.external_docs.clone().map(ReferenceOr::Item),
+        bindings: message.bindings.clone(),
+        examples: message.examples.clone(),
+        traits: message
+            .traits
+            .iter()
+            .map(|message_trait| upgrade_ref(message_trait, upgrade_message_trait))
+            .collect(),
+        extensions: message.extensions.clone(),
+    }
+}
+
+fn upgrade_message_trait(message_trait: &MessageTrait) -> v3::MessageTrait {
+    v3::MessageTrait {
+        headers: message_trait.headers.clone(),
+        correlation_id: message_trait.correlation_id.clone(),
+        content_type: message_trait.content_type.clone(),
+        name: message_trait.name.clone(),
+        title: message_trait.title.clone(),
+        summary: message_trait.summary.clone(),
+        description: message_trait.description.clone(),
+        tags: upgrade_tags(&message_trait.tags),
+        external_docs: message_trait.external_docs.clone().map(ReferenceOr::Item),
+        bindings: message_trait.bindings.clone(),
+        examples: message_trait.examples.clone(),
+        extensions: message_trait.extensions.clone(),
+    }
+}
+
+fn upgrade_operation_trait(operation_trait: &OperationTrait) -> v3::OperationTrait {
+    v3::OperationTrait {
+        summary: operation_trait.summary.clone(),
+        description: operation_trait.description.clone(),
+        security: upgrade_security(&operation_trait.security),
+        tags: upgrade_tags(&operation_trait.tags),
+        external_docs: operation_trait.external_docs.clone().map(ReferenceOr::Item),
+        bindings: operation_trait.bindings.clone(),
+        extensions: operation_trait.extensions.clone(),
+        ..Default::default()
+    }
+}
+
+fn upgrade_security(requirements: &[SecurityRequirement]) -> Vec<ReferenceOr<v3::SecurityScheme>> {
+    let mut security = Vec::new();
+    for name in requirements
+        .iter()
+        .flat_map(|requirement| requirement.values.keys())
+    {
+        let scheme = ReferenceOr::ref_(&join_pointer("#/components/securitySchemes", name));
+        if !security.contains(&scheme) {
+            security.push(scheme);
+        }
+    }
+    security
+}
+
+fn upgrade_tags(tags: &[Tag]) -> Vec<ReferenceOr<Tag>> {
+    tags.iter().cloned().map(ReferenceOr::Item).collect()
+}
+
+fn upgrade_ref<T, U>(item: &ReferenceOr<T>, upgrade: impl FnOnce(&T) -> U) -> ReferenceOr<U> {
+    match item {
+        ReferenceOr::Reference { reference } => ReferenceOr::ref_(reference),
+        ReferenceOr::Item(item) => ReferenceOr::Item(upgrade(item)),
+    }
+}
+
+/// Converts a security scheme, whose OAuth 2.0 flows list their
+/// `availableScopes` in 3.0. The schemes of 2.x don't list the scopes they
+/// require, those are part of the security requirements.
+fn upgrade_security_scheme(scheme: &SecurityScheme) -> v3::SecurityScheme {
+    match scheme {
+        SecurityScheme::OAuth2 {
+            description,
+            flows,
+            extensions,
+        } => v3::SecurityScheme::OAuth2 {
+            description: description.clone(),
+            flows: upgrade_oauth_flows(flows),
+            scopes: Vec::new(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::OpenIdConnect {
+            description,
+            open_id_connect_url,
+            extensions,
+        } => v3::SecurityScheme::OpenIdConnect {
+            description: description.clone(),
+            open_id_connect_url: open_id_connect_url.clone(),
+            scopes: Vec::new(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::UserPassword {
+            description,
+            extensions,
+        } => v3::SecurityScheme::UserPassword {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::X509 {
+            description,
+            extensions,
+        } => v3::SecurityScheme::X509 {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::SymmetricEncryption {
+            description,
+            extensions,
+        } => v3::SecurityScheme::SymmetricEncryption {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::AsymmetricEncryption {
+            description,
+            extensions,
+        } => v3::SecurityScheme::AsymmetricEncryption {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::Plain {
+            description,
+            extensions,
+        } => v3::SecurityScheme::Plain {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::ScramSha256 {
+            description,
+            extensions,
+        } => v3::SecurityScheme::ScramSha256 {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::ScramSha512 {
+            description,
+            extensions,
+        } => v3::SecurityScheme::ScramSha512 {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::Gssapi {
+            description,
+            extensions,
+        } => v3::SecurityScheme::Gssapi {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::ApiKey {
+            description,
+            location,
+            extensions,
+        } => v3::SecurityScheme::ApiKey {
+            description: description.clone(),
+            location: location.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::HttpApiKey {
+            description,
+            name,
+            location,
+            extensions,
+        } => v3::SecurityScheme::HttpApiKey {
+            description: description.clone(),
+            name: name.clone(),
+            location: location.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::Http {
+            description,
+            scheme,
+            bearer_format,
+            extensions,
+        } => v3::SecurityScheme::Http {
+            description: description.clone(),
+            scheme: scheme.clone(),
+            bearer_format: bearer_format.clone(),
+            extensions: extensions.clone(),
+        },
+        SecurityScheme::Other { typ, fields } => v3::SecurityScheme::Other {
+            typ: typ.clone(),
+            fields: fields.clone(),
+        },
+    }
+}
+
+fn upgrade_oauth_flows(flows: &crate::OAuthFlows) -> v3::OAuthFlows {
+    v3::OAuthFlows {
+        implicit: flows.implicit.as_ref().map(|flow| v3::OAuthFlowImplicit {
+            authorization_url: flow.authorization_url.clone(),
+            refresh_url: flow.refresh_url.clone(),
+            available_scopes: flow.scopes.clone(),
+            extensions: flow.extensions.clone(),
+        }),
+        password: flows.password.as_ref().map(|flow| v3::OAuthFlowPassword {
+            token_url: flow.token_url.clone(),
+            refresh_url: flow.refresh_url.clone(),
+            available_scopes: flow.scopes.clone(),
+            extensions: flow.extensions.clone(),
+        }),
+        client_credentials: flows.client_credentials.as_ref().map(|flow| {
+            v3::OAuthFlowClientCredentials {
+                token_url: flow.token_url.clone(),
+                refresh_url: flow.refresh_url.clone(),
+                available_scopes: flow.scopes.clone(),
+                extensions: flow.extensions.clone(),
+            }
+        }),
+        authorization_code: flows.authorization_code.as_ref().map(|flow| {
+            v3::OAuthFlowAuthorizationCode {
+                authorization_url: flow.authorization_url.clone(),
+                token_url: flow.token_url.clone(),
+                refresh_url: flow.refresh_url.clone(),
+                available_scopes: flow.scopes.clone(),
+                extensions: flow.extensions.clone(),
+            }
+        }),
+        extensions: flows.extensions.clone(),
+    }
+}
+
+/// Adds `message` to `messages` under `candidate` and returns the name it
+/// was added under. The same message is only added once.
+fn insert_message(
+    messages: &mut IndexMap<String, ReferenceOr<v3::Message>>,
+    candidate: String,
+    message: &ReferenceOr<v3::Message>,
+) -> String {
+    if messages.get(&candidate) == Some(message) {
+        return candidate;
+    }
+    let name = unique_key(messages, candidate);
+    messages.insert(name.clone(), message.clone());
+    name
+}
+
+/// Derives a channel id from a 2.x channel address, e.g. `user_signedup` for
+/// `user/signedup` or `user_userId` for `user/{userId}`.
+fn channel_id(address: &str) -> String {
+    let id = address
+        .split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_')))
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    if id.is_empty() {
+        "channel".to_string()
+    } else {
+        id
+    }
+}
+
+/// Returns `candidate`, or `candidate` with a number appended if `map`
+/// already contains it.
+fn unique_key<T>(map: &IndexMap<String, T>, candidate: String) -> String {
+    if !map.contains_key(&candidate) {
+        return candidate;
+    }
+    (2..)
+        .map(|i| format!("{candidate}{i}"))
+        .find(|key| !map.contains_key(key))
+        .unwrap()
+}
+
+/// A part of a 3.0 document that [`downgrade_v3_to_v2`] couldn't carry over
+/// to the 2.6 document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DowngradeWarning {
+    /// JSON Pointer to the part of the 3.0 document.
+    pub path: String,
+    pub kind: DowngradeWarningKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DowngradeWarningKind {
+    /// The field has no equivalent in 2.6 and was dropped.
+    UnsupportedField(&'static str),
+    /// The reference couldn't be followed within the document, so the
+    /// referencing object was dropped.
+    UnresolvedReference(String),
+    /// The channel has no address, which 2.6 channels are keyed by, so it
+    /// was dropped together with its operations.
+    MissingAddress,
+    /// An earlier channel has the same address, which 2.6 channels are keyed
+    /// by, so this channel was dropped together with its operations.
+    DuplicateAddress,
+    /// A 2.6 channel has at most one operation of each kind, so this
+    /// operation was dropped.
+    DuplicateOperation,
+    /// The security scheme is defined inline, while 2.6 security requirements
+    /// can only name schemes of the components. The scheme was dropped.
+    InlineSecurityScheme,
+    /// The schema isn't in the default schema format, which is the only one
+    /// allowed in the 2.6 components. The schema was dropped.
+    SchemaFormat,
+}
+
+impl std::fmt::Display for DowngradeWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            DowngradeWarningKind::UnsupportedField(field) => {
+                write!(f, "`{field}` is not supported by AsyncAPI 2.6")
+            }
+            DowngradeWarningKind::UnresolvedReference(reference) => {
+                write!(f, "could not resolve `{reference}`")
+            }
+            DowngradeWarningKind::MissingAddress => f.write_str("the channel has no address"),
+            DowngradeWarningKind::DuplicateAddress => {
+                f.write_str("another channel already has this address")
+            }
+            DowngradeWarningKind::DuplicateOperation => {
+                f.write_str("the channel already has an operation with this action")
+            }
+            DowngradeWarningKind::InlineSecurityScheme => {
+                f.write_str("inline security schemes are not supported by AsyncAPI 2.6")
+            }
+            DowngradeWarningKind::SchemaFormat => {
+                f.write_str("the schema is not in the default schema format")
+            }
+        }
+    }
+}
+
+/// Converts a 3.0 document to AsyncAPI 2.6, as far as 2.6 can express it.
+///
+/// This reverses [`upgrade_v2_to_v3`]: channels are keyed by their address
+/// again, operations move back into their channel and their messages are
+/// inlined from the channel. The server URLs are put together from the host
+/// and pathname, with the protocol as their scheme. The `scopes` that OAuth
+/// 2.0 and OpenID Connect schemes require become the scopes of the security
+/// requirements referencing them. Whatever can't be
+/// represented is dropped and reported as a [DowngradeWarning], e.g. replies,
+/// channels without an address or with the address of an earlier channel, or
+/// several operations with the same action on one channel.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::{
+///     convert::{downgrade_v3_to_v2, DowngradeWarningKind},
+///     AnyAsyncApi,
+/// };
+///
+/// let AnyAsyncApi::V3(api) = r#"
+/// asyncapi: 3.0.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels:
+///   userSignedUp:
+///     address: user/signedup
+///     messages:
+///       UserSignedUp:
+///         $ref: '#/components/messages/UserSignedUp'
+/// operations:
+///   sendUserSignedUp:
+///     action: send
+///     channel:
+///       $ref: '#/channels/userSignedUp'
+///     reply:
+///       channel:
+///         $ref: '#/channels/userSignedUp'
+/// "#
+/// .parse()
+/// .unwrap() else {
+///     unreachable!()
+/// };
+///
+/// let (downgraded, warnings) = downgrade_v3_to_v2(&api);
+/// let operation = downgraded.channels["user/signedup"].subscribe.as_ref().unwrap();
+/// assert_eq!(operation.operation_id.as_deref(), Some("sendUserSignedUp"));
+/// assert_eq!(warnings[0].path, "/operations/sendUserSignedUp/reply");
+/// assert_eq!(warnings[0].kind, DowngradeWarningKind::UnsupportedField("reply"));
+/// ```
+pub fn downgrade_v3_to_v2(api: &AsyncApiV3) -> (AsyncAPI, Vec<DowngradeWarning>) {
+    let mut downgrade = Downgrade {
+        api,
+        warnings: Vec::new(),
+    };
+    let downgraded = downgrade.document();
+    (downgraded, downgrade.warnings)
+}
+
+struct Downgrade<'a> {
+    api: &'a AsyncApiV3,
+    warnings: Vec<DowngradeWarning>,
+}
+
+impl<'a> Downgrade<'a> {
+    fn warn(&mut self, path: impl Into<String>, kind: DowngradeWarningKind) {
+        let warning = DowngradeWarning {
+            path: path.into(),
+            kind,
+        };
+        // The messages of a channel are converted for each of its operations.
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    fn components(&self) -> Option<&'a v3::Components> {
+        self.api.components.as_ref()
+    }
+
+    fn document(&mut self) -> AsyncAPI {
+        let api = self.api;
+        let mut downgraded = AsyncAPI {
+            asyncapi: SpecVersion::V2_6_0,
+            id: api.id.clone(),
+            info: Info {
+                title: api.info.title.clone(),
+                version: api.info.version.clone(),
+                description: api.info.description.clone(),
+                terms_of_service: api.info.terms_of_service.clone(),
+                contact: api.info.contact.clone(),
+                license: api.info.license.clone(),
+                extensions: api.info.extensions.clone(),
+            },
+            default_content_type: api.default_content_type.clone(),
+            tags: self.tags(&api.info.tags, "/info/tags"),
+            external_docs: self.external_docs(&api.info.external_docs, "/info/externalDocs"),
+            extensions: api.extensions.clone(),
+            ..Default::default()
+        };
+        for (name, server) in &api.servers {
+            let path = join_pointer("/servers", name);
+            if let Some(server) = self.server_ref(server, &path) {
+                downgraded.servers.insert(name.clone(), server);
+            }
+        }
+
+        // Channels first, so operations can be added to them by channel id.
+        let mut addresses = IndexMap::new();
+        for (id, channel) in &api.channels {
+            let path = join_pointer("/channels", id);
+            let components = self.components().map(|components| &components.channels);
+            let channel = match resolve_local(components, "#/components/channels/", channel) {
+                Ok(channel) => channel,
+                Err(error) => {
+                    self.warn(path, unresolved(error));
+                    continue;
+                }
+            };
+            let Some(address) = &channel.address else {
+                self.warn(path, DowngradeWarningKind::MissingAddress);
+                continue;
+            };
+            if downgraded.channels.contains_key(address) {
+                self.warn(path, DowngradeWarningKind::DuplicateAddress);
+                continue;
+            }
+            let downgraded_channel = self.channel(channel, &path);
+            downgraded
+                .channels
+                .insert(address.clone(), downgraded_channel);
+            addresses.insert(id.as_str(), (address.as_str(), channel));
+        }
+
+        for (id, operation) in &api.operations {
+            let path = join_pointer("/operations", id);
+            let components = self.components().map(|components| &components.operations);
+            let operation = match resolve_local(components, "#/components/operations/", operation) {
+                Ok(operation) => operation,
+                Err(error) => {
+                    self.warn(path, unresolved(error));
+                    continue;
+                }
+            };
+            let reference = &operation.channel.reference;
+            let channel = reference
+                .strip_prefix("#/channels/")
+                .and_then(|_| addresses.get(reference_name(reference).as_str()));
+            let Some(&(address, channel)) = channel else {
+                self.warn(
+                    format!("{path}/channel"),
+                    DowngradeWarningKind::UnresolvedReference(reference.clone()),
+                );
+                continue;
+            };
+            let downgraded_operation = self.operation(id, operation, channel, &path);
+            let slot = match operation.action {
+                Action::Send => &mut downgraded.channels[address].subscribe,
+                Action::Receive => &mut downgraded.channels[address].publish,
+            };
+            if slot.is_some() {
+                self.warn(path, DowngradeWarningKind::DuplicateOperation);
+            } else {
+                *slot = Some(downgraded_operation);
+            }
+        }
+
+        downgraded.components = api
+            .components
+            .as_ref()
+            .map(|components| self.components_object(components));
+        downgraded
+    }
+
+    fn components_object(&mut self, components: &v3::Components) -> Components {
+        let mut downgraded = Components {
+            messages: components
+                .messages
+                .iter()
+                .map(|(name, message)| {
+                    let path = join_pointer("/components/messages", name);
+                    (name.clone(), self.message(message, &path))
+                })
+                .collect(),
+            security_schemes: components
+                .security_schemes
+                .iter()
+                .map(|(name, scheme)| {
+                    (name.clone(), upgrade_ref(scheme, downgrade_security_scheme))
+                })
+                .collect(),
+            server_variables: components.server_variables.clone(),
+            correlation_ids: components.correlation_ids.clone(),
+            message_traits: components
+                .message_traits
+                .iter()
+                .map(|(name, message_trait)| {
+                    let path = join_pointer("/components/messageTraits", name);
+                    (name.clone(), self.message_trait(message_trait, &path))
+                })
+                .collect(),
+            server_bindings: components.server_bindings.clone(),
+            channel_bindings: components.channel_bindings.clone(),
+            operation_bindings: components.operation_bindings.clone(),
+            message_bindings: components.message_bindings.clone(),
+            extensions: components.extensions.clone(),
+            ..Default::default()
+        };
+        for (name, schema) in &components.schemas {
+            let schema = match schema {
+                Payload::Schema(schema) => Some(schema.clone()),
+                Payload::MultiFormat(schema) if schema.is_asyncapi_schema() => {
+                    serde_json::from_value(schema.schema.clone()).ok()
+                }
+                Payload::MultiFormat(_) | Payload::Any(_) => None,
+            };
+            match schema {
+                Some(schema) => {
+                    downgraded.schemas.insert(name.clone(), schema);
+                }
+                None => self.warn(
+                    join_pointer("/components/schemas", name),
+                    DowngradeWarningKind::SchemaFormat,
+                ),
+            }
+        }
+        for (name, server) in &components.servers {
+            let path = join_pointer("/components/servers", name);
+            if let Some(server) = self.server_ref(server, &path) {
+                downgraded.servers.insert(name.clone(), server);
+            }
+        }
+        for (name, channel) in &components.channels {
+            let path = join_pointer("/components/channels", name);
+            match channel {
+                ReferenceOr::Item(channel) => {
+                    let channel = self.channel(channel, &path);
+                    downgraded.channels.insert(name.clone(), channel);
+                }
+                ReferenceOr::Reference { reference } => self.warn(
+                    path,
+                    DowngradeWarningKind::UnresolvedReference(reference.clone()),
+                ),
+            }
+        }
+        downgraded.parameters = components
+            .parameters
+            .iter()
+            .map(|(name, parameter)| (name.clone(), upgrade_ref(parameter, downgrade_parameter)))
+            .collect();
+        for (name, operation_trait) in &components.operation_traits {
+            let path = join_pointer("/components/operationTraits", name);
+            let operation_trait = match operation_trait {
+                ReferenceOr::Item(operation_trait) => {
+                    ReferenceOr::Item(self.operation_trait(operation_trait, &path))
+                }
+                ReferenceOr::Reference { reference } => ReferenceOr::ref_(reference),
+            };
+            downgraded
+                .operation_traits
+                .insert(name.clone(), operation_trait);
+        }
+
+        for (field, unsupported) in [
+            ("operations", components.operations.is_empty()),
+            ("replies", components.replies.is_empty()),
+            ("replyAddresses", components.reply_addresses.is_empty()),
+            ("tags", components.tags.is_empty()),
+            ("externalDocs", components.external_docs.is_empty()),
+        ] {
+            if !unsupported {
+                self.warn(
+                    format!("/components/{field}"),
+                    DowngradeWarningKind::UnsupportedField(field),
+                );
+            }
+        }
+        downgraded
+    }
+
+    fn server_ref(
+        &mut self,
+        server: &ReferenceOr<v3::Server>,
+        path: &str,
+    ) -> Option<ReferenceOr<Server>> {
+        match server {
+            ReferenceOr::Item(server) => Some(ReferenceOr::Item(self.server(server, path))),
+            ReferenceOr::Reference { reference } => Some(ReferenceOr::ref_(reference)),
+        }
+    }
+
+    fn server(&mut self, server: &v3::Server, path: &str) -> Server {
+        self.unsupported(path, "title", server.title.is_some());
+        self.unsupported(path, "summary", server.summary.is_some());
+        self.unsupported(path, "externalDocs", server.external_docs.is_some());
+        // 3.0 splits the URL into host and pathname and leaves the scheme to
+        // the protocol, 2.x URLs commonly carry it.
+        let url = match server.protocol.as_str() {
+            "" => server.url(),
+            protocol => format!("{protocol}://{}", server.url()),
+        };
+        Server {
+            url,
+            protocol: server.protocol.clone(),
+            protocol_version: server.protocol_version.clone(),
+            description: server.description.clone(),
+            variables: server.variables.clone(),
+            security: self.security(&server.security, &format!("{path}/security")),
+            tags: self.tags(&server.tags, &format!("{path}/tags")),
+            bindings: server.bindings.clone(),
+            extensions: server.extensions.clone(),
+        }
+    }
+
+    fn channel(&mut self, channel: &v3::Channel, path: &str) -> Channel {
+        self.unsupported(path, "title", channel.title.is_some());
+        self.unsupported(path, "summary", channel.summary.is_some());
+        self.unsupported(path, "tags", !channel.tags.is_empty());
+        self.unsupported(path, "externalDocs", channel.external_docs.is_some());
+        Channel {
+            description: channel.description.clone(),
+            servers: channel
+                .servers
+                .iter()
+                .map(|server| reference_name(&server.reference))
+                .collect(),
+            parameters: channel
+                .parameters
+                .iter()
+                .map(|(name, parameter)| {
+                    (name.clone(), upgrade_ref(parameter, downgrade_parameter))
+                })
+                .collect(),
+            bindings: channel.bindings.clone(),
+            extensions: channel.extensions.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn operation(
+        &mut self,
+        id: &str,
+        operation: &v3::Operation,
+        channel: &v3::Channel,
+        path: &str,
+    ) -> Operation {
+        self.unsupported(path, "title", operation.title.is_some());
+        self.unsupported(path, "reply", operation.reply.is_some());
+
+        // Without messages, the operation is about all messages of the channel.
+        let channel_path = operation.channel.reference.trim_start_matches('#');
+        let mut messages = Vec::new();
+        if operation.messages.is_empty() {
+            for (name, message) in &channel.messages {
+                let message_path = join_pointer(&format!("{channel_path}/messages"), name);
+                messages.push(self.message(message, &message_path));
+            }
+        }
+        for (i, message) in operation.messages.iter().enumerate() {
+            let reference = &message.reference;
+            let name = reference_name(reference);
+            let channel_message = reference
+                .strip_prefix(&format!("{}/messages/", operation.channel.reference))
+                .and_then(|_| channel.messages.get(&name));
+            match channel_message {
+                Some(message) => {
+                    let message_path = join_pointer(&format!("{channel_path}/messages"), &name);
+                    messages.push(self.message(message, &message_path));
+                }
+                None if reference.starts_with("#/components/messages/") => {
+                    messages.push(ReferenceOr::ref_(reference))
+                }
+                None => self.warn(
+                    format!("{path}/messages/{i}"),
+                    DowngradeWarningKind::UnresolvedReference(reference.clone()),
+                ),
+            }
+        }
+        let message = match messages.len() {
+            0 => None,
+            1 => messages.pop().map(OperationMessageType::Single),
+            _ => Some(OperationMessageType::OneOf { one_of: messages }),
+        };
+
+        let mut traits = Vec::new();
+        for (i, operation_trait) in operation.traits.iter().enumerate() {
+            traits.push(match operation_trait {
+                ReferenceOr::Item(operation_trait) => ReferenceOr::Item(
+                    self.operation_trait(operation_trait, &format!("{path}/traits/{i}")),
+                ),
+                ReferenceOr::Reference { reference } => ReferenceOr::ref_(reference),
+            });
+        }
+
+        Operation {
+            operation_id: Some(id.to_string()),
+            summary: operation.summary.clone(),
+            description: operation.description.clone(),
+            security: self.security(&operation.security, &format!("{path}/security")),
+            tags: self.tags(&operation.tags, &format!("{path}/tags")),
+            external_docs: self
+                .external_docs(&operation.external_docs, &format!("{path}/externalDocs")),
+            bindings: operation.bindings.clone(),
+            traits,
+            message,
+            extensions: operation.extensions.clone(),
+        }
+    }
+
+    fn operation_trait(
+        &mut self,
+        operation_trait: &v3::OperationTrait,
+        path: &str,
+    ) -> OperationTrait {
+        self.unsupported(path, "title", operation_trait.title.is_some());
+        OperationTrait {
+            operation_id: None,
+            summary: operation_trait.summary.clone(),
+            description: operation_trait.description.clone(),
+            security: self.security(&operation_trait.security, &format!("{path}/security")),
+            tags: self.tags(&operation_trait.tags, &format!("{path}/tags")),
+            external_docs: self.external_docs(
+                &operation_trait.external_docs,
+                &format!("{path}/externalDocs"),
+            ),
+            bindings: operation_trait.bindings.clone(),
+            extensions: operation_trait.extensions.clone(),
+        }
+    }
+
+    /// Converts a message back to 2.x: a multi format payload is split into
+    /// the `schemaFormat` and the `payload`.
+    fn message(&mut self, message: &ReferenceOr<v3::Message>, path: &str) -> ReferenceOr<Message> {
+        let message = match message {
+            ReferenceOr::Item(message) => message,
+            ReferenceOr::Reference { reference } => return ReferenceOr::ref_(reference),
+        };
+        let (schema_format, payload) = match &message.payload {
+            Some(Payload::MultiFormat(payload)) => (
+                Some(payload.schema_format.clone()),
+                Some(if payload.is_asyncapi_schema() {
+                    serde_json::from_value(payload.schema.clone())
+                        .map(Payload::Schema)
+                        .unwrap_or_else(|_| Payload::Any(payload.schema.clone()))
+                } else {
+                    Payload::Any(payload.schema.clone())
+                }),
+            ),
+            payload => (None, payload.clone()),
+        };
+        let traits = message
+            .traits
+            .iter()
+            .enumerate()
+            .map(|(i, message_trait)| {
+                self.message_trait(message_trait, &format!("{path}/traits/{i}"))
+            })
+            .collect();
+        ReferenceOr::Item(Message {
+            message_id: None,
+            headers: message.headers.clone(),
+            payload,
+            correlation_id: message.correlation_id.clone(),
+            schema_format,
+            content_type: message.content_type.clone(),
+            name: message.name.clone(),
+            title: message.title.clone(),
+            summary: message.summary.clone(),
+            description: message.description.clone(),
+            tags: self.tags(&message.tags, &format!("{path}/tags")),
+            external_docs: self
+                .external_docs(&message.external_docs, &format!("{path}/externalDocs")),
+            bindings: message.bindings.clone(),
+            examples: message.examples.clone(),
+            traits,
+            extensions: message.extensions.clone(),
+        })
+    }
+
+    fn message_trait(
+        &mut self,
+        message_trait: &ReferenceOr<v3::MessageTrait>,
+        path: &str,
+    ) -> ReferenceOr<MessageTrait> {
+        let message_trait = match message_trait {
+            ReferenceOr::Item(message_trait) => message_trait,
+            ReferenceOr::Reference { reference } => return ReferenceOr::ref_(reference),
+        };
+        ReferenceOr::Item(MessageTrait {
+            message_id: None,
+            headers: message_trait.headers.clone(),
+            correlation_id: message_trait.correlation_id.clone(),
+            schema_format: None,
+            content_type: message_trait.content_type.clone(),
+            name: message_trait.name.clone(),
+            title: message_trait.title.clone(),
+            summary: message_trait.summary.clone(),
+            description: message_trait.description.clone(),
+            tags: self.tags(&message_trait.tags, &format!("{path}/tags")),
+            external_docs: self.external_docs(
+                &message_trait.external_docs,
+                &format!("{path}/externalDocs"),
+            ),
+            bindings: message_trait.bindings.clone(),
+            examples: message_trait.examples.clone(),
+            extensions: message_trait.extensions.clone(),
+        })
+    }
+
+    fn unsupported(&mut self, path: &str, field: &'static str, present: bool) {
+        if present {
+            self.warn(
+                format!("{path}/{field}"),
+                DowngradeWarningKind::UnsupportedField(field),
+            );
+        }
+    }
+
+    fn security(
+        &mut self,
+        security: &[ReferenceOr<v3::SecurityScheme>],
+        path: &str,
+    ) -> Vec<SecurityRequirement> {
+        let schemes = self
+            .components()
+            .map(|components| &components.security_schemes);
+        let mut requirements = Vec::new();
+        for (i, scheme) in security.iter().enumerate() {
+            match scheme {
+                ReferenceOr::Reference { reference }
+                    if reference.starts_with("#/components/securitySchemes/") =>
+                {
+                    let scopes =
+                        match resolve_local(schemes, "#/components/securitySchemes/", scheme) {
+                            Ok(
+                                v3::SecurityScheme::OAuth2 { scopes, .. }
+                                | v3::SecurityScheme::OpenIdConnect { scopes, .. },
+                            ) => scopes.clone(),
+                            _ => Vec::new(),
+                        };
+                    requirements.push(SecurityRequirement {
+                        values: [(reference_name(reference), scopes)].into_iter().collect(),
+                    });
+                }
+                ReferenceOr::Reference { reference } => self.warn(
+                    format!("{path}/{i}"),
+                    DowngradeWarningKind::UnresolvedReference(reference.clone()),
+                ),
+                ReferenceOr::Item(_) => self.warn(
+                    format!("{path}/{i}"),
+                    DowngradeWarningKind::InlineSecurityScheme,
+                ),
+            }
+        }
+        requirements
+    }
+
+    fn tags(&mut self, tags: &[ReferenceOr<Tag>], path: &str) -> Vec<Tag> {
+        let components = self.components().map(|components| &components.tags);
+        let mut downgraded = Vec::new();
+        for (i, tag) in tags.iter().enumerate() {
+            match resolve_local(components, "#/components/tags/", tag) {
+                Ok(tag) => downgraded.push(tag.clone()),
+                Err(error) => self.warn(format!("{path}/{i}"), unresolved(error)),
+            }
+        }
+        downgraded
+    }
+
+    fn external_docs(
+        &mut self,
+        external_docs: &Option<ReferenceOr<ExternalDocumentation>>,
+        path: &str,
+    ) -> Option<ExternalDocumentation> {
+        let components = self
+            .components()
+            .map(|components| &components.external_docs);
+        match resolve_local(
+            components,
+            "#/components/externalDocs/",
+            external_docs.as_ref()?,
+        ) {
+            Ok(external_docs) => Some(external_docs.clone()),
+            Err(error) => {
+                self.warn(path, unresolved(error));
+                None
+            }
+        }
+    }
+}
+
+fn unresolved(error: ResolveError) -> DowngradeWarningKind {
+    let (ResolveError::External(reference)
+    | ResolveError::NotFound(reference)
+    | ResolveError::Cycle(reference)
+    | ResolveError::UnknownComponentKind(reference)) = error;
+    DowngradeWarningKind::UnresolvedReference(reference)
+}
+
+/// Converts a security scheme back to 2.x. The `scopes` the scheme requires
+/// are dropped, [security requirements][SecurityRequirement] list them
+/// instead.
+fn downgrade_security_scheme(scheme: &v3::SecurityScheme) -> SecurityScheme {
+    match scheme {
+        v3::SecurityScheme::OAuth2 {
+            description,
+            flows,
+            extensions,
+            ..
+        } => SecurityScheme::OAuth2 {
+            description: description.clone(),
+            flows: downgrade_oauth_flows(flows),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::OpenIdConnect {
+            description,
+            open_id_connect_url,
+            extensions,
+            ..
+        } => SecurityScheme::OpenIdConnect {
+            description: description.clone(),
+            open_id_connect_url: open_id_connect_url.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::UserPassword {
+            description,
+            extensions,
+        } => SecurityScheme::UserPassword {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::X509 {
+            description,
+            extensions,
+        } => SecurityScheme::X509 {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::SymmetricEncryption {
+            description,
+            extensions,
+        } => SecurityScheme::SymmetricEncryption {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::AsymmetricEncryption {
+            description,
+            extensions,
+        } => SecurityScheme::AsymmetricEncryption {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::Plain {
+            description,
+            extensions,
+        } => SecurityScheme::Plain {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::ScramSha256 {
+            description,
+            extensions,
+        } => SecurityScheme::ScramSha256 {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::ScramSha512 {
+            description,
+            extensions,
+        } => SecurityScheme::ScramSha512 {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::Gssapi {
+            description,
+            extensions,
+        } => SecurityScheme::Gssapi {
+            description: description.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::ApiKey {
+            description,
+            location,
+            extensions,
+        } => SecurityScheme::ApiKey {
+            description: description.clone(),
+            location: location.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::HttpApiKey {
+            description,
+            name,
+            location,
+            extensions,
+        } => SecurityScheme::HttpApiKey {
+            description: description.clone(),
+            name: name.clone(),
+            location: location.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::Http {
+            description,
+            scheme,
+            bearer_format,
+            extensions,
+        } => SecurityScheme::Http {
+            description: description.clone(),
+            scheme: scheme.clone(),
+            bearer_format: bearer_format.clone(),
+            extensions: extensions.clone(),
+        },
+        v3::SecurityScheme::Other { typ, fields } => SecurityScheme::Other {
+            typ: typ.clone(),
+            fields: fields.clone(),
+        },
+    }
+}
+
+fn downgrade_oauth_flows(flows: &v3::OAuthFlows) -> crate::OAuthFlows {
+    crate::OAuthFlows {
+        implicit: flows
+            .implicit
+            .as_ref()
+            .map(|flow| crate::OAuthFlowImplicit {
+                authorization_url: flow.authorization_url.clone(),
+                refresh_url: flow.refresh_url.clone(),
+                scopes: flow.available_scopes.clone(),
+                extensions: flow.extensions.clone(),
+            }),
+        password: flows
+            .password
+            .as_ref()
+            .map(|flow| crate::OAuthFlowPassword {
+                token_url: flow.token_url.clone(),
+                refresh_url: flow.refresh_url.clone(),
+                scopes: flow.available_scopes.clone(),
+                extensions: flow.extensions.clone(),
+            }),
+        client_credentials: flows.client_credentials.as_ref().map(|flow| {
+            crate::OAuthFlowClientCredentials {
+                token_url: flow.token_url.clone(),
+                refresh_url: flow.refresh_url.clone(),
+                scopes: flow.available_scopes.clone(),
+                extensions: flow.extensions.clone(),
+            }
+        }),
+        authorization_code: flows.authorization_code.as_ref().map(|flow| {
+            crate::OAuthFlowAuthorizationCode {
+                authorization_url: flow.authorization_url.clone(),
+                token_url: flow.token_url.clone(),
+                refresh_url: flow.refresh_url.clone(),
+                scopes: flow.available_scopes.clone(),
+                extensions: flow.extensions.clone(),
+            }
+        }),
+        extensions: flows.extensions.clone(),
+    }
+}
+
+/// Converts a 3.0 parameter, its values become a string schema.
+fn downgrade_parameter(parameter: &v3::Parameter) -> Parameter {
+    let mut schema = serde_json::Map::new();
+    if !parameter.en.is_empty() {
+        schema.insert("enum".to_string(), parameter.en.clone().into());
+    }
+    if let Some(default) = &parameter.default {
+        schema.insert("default".to_string(), default.clone().into());
+    }
+    if let Some(example) = parameter.examples.first() {
+        schema.insert("example".to_string(), example.clone().into());
+    }
+    let schema = (!schema.is_empty()).then(|| {
+        schema.insert("type".to_string(), "string".into());
+        serde_json::from_value(schema.into()).expect("string schemas are valid schemas")
+    });
+    Parameter {
+        description: parameter.description.clone(),
+        schema: schema.map(ReferenceOr::Item),
+        location: parameter.location.clone(),
+        extensions: parameter.extensions.clone(),
+    }
+}
+
+impl From<OperationKind> for Action {
+    /// The action of the application for a 2.x operation, which is described
+    /// from the point of view of its clients.
+    fn from(kind: OperationKind) -> Self {
+        match kind {
+            OperationKind::Subscribe => Action::Send,
+            OperationKind::Publish => Action::Receive,
+        }
+    }
+}
+
+#[test]
+fn test_upgrade_v2_to_v3() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Streetlights API
+      version: 1.0.0
+    tags:
+      - name: lights
+    servers:
+      production:
+        url: mqtt://api.streetlights.smartylighting.com:{port}/mqtt
+        protocol: mqtt
+        security:
+          - apiKey: []
+    channels:
+      smartylighting/streetlights/1/0/event/{streetlightId}/lighting/measured:
+        parameters:
+          streetlightId:
+            $ref: '#/components/parameters/streetlightId'
+        subscribe:
+          summary: Inform about environmental lighting conditions.
+          operationId: onLightMeasured
+          message:
+            $ref: '#/components/messages/lightMeasured'
+        publish:
+          message:
+            name: dimLight
+            payload:
+              type: object
+      light/dimmed:
+        $ref: '#/components/channels/dimmed'
+    components:
+      channels:
+        dimmed:
+          subscribe:
+            message:
+              $ref: '#/components/messages/lightMeasured'
+      parameters:
+        streetlightId:
+          description: The ID of the streetlight.
+          schema:
+            type: string
+            enum: [a, b]
+    "#
+    .parse()
+    .unwrap();
+
+    let upgraded = upgrade_v2_to_v3(&api);
+    assert_eq!(upgraded.asyncapi, "3.0.0");
+    assert!(matches!(&upgraded.info.tags[0], ReferenceOr::Item(tag) if tag.name == "lights"));
+
+    let ReferenceOr::Item(server) = &upgraded.servers["production"] else {
+        panic!("expected an inline server");
+    };
+    assert_eq!(server.host, "api.streetlights.smartylighting.com:{port}");
+    assert_eq!(server.pathname.as_deref(), Some("/mqtt"));
+    assert_eq!(
+        server.security,
+        [ReferenceOr::ref_("#/components/securitySchemes/apiKey")]
+    );
+
+    let id = "smartylighting_streetlights_1_0_event_streetlightId_lighting_measured";
+    let ReferenceOr::Item(channel) = &upgraded.channels[id] else {
+        panic!("expected an inline channel");
+    };
+    assert_eq!(
+        channel.address.as_deref(),
+        Some("smartylighting/streetlights/1/0/event/{streetlightId}/lighting/measured")
+    );
+    assert_eq!(
+        channel.messages.keys().collect::<Vec<_>>(),
+        ["dimLight", "lightMeasured"]
+    );
+
+    assert_eq!(
+        upgraded.operations.keys().collect::<Vec<_>>(),
+        [
+            &format!("{id}.publish"),
+            "onLightMeasured",
+            "light_dimmed.subscribe"
+        ]
+    );
+    let ReferenceOr::Item(operation) = &upgraded.operations["onLightMeasured"] else {
+        panic!("expected an inline operation");
+    };
+    assert_eq!(operation.action, Action::Send);
+    assert_eq!(operation.channel.reference, format!("#/channels/{id}"));
+    assert_eq!(
+        operation.messages,
+        [Reference::new(format!(
+            "#/channels/{id}/messages/lightMeasured"
+        ))]
+    );
+    let ReferenceOr::Item(operation) = &upgraded.operations[&format!("{id}.publish")] else {
+        panic!("expected an inline operation");
+    };
+    assert_eq!(operation.action, Action::Receive);
+
+    let ReferenceOr::Item(dimmed) = &upgraded.channels["light_dimmed"] else {
+        panic!("expected the referenced channel to be inlined");
+    };
+    assert_eq!(dimmed.address.as_deref(), Some("light/dimmed"));
+
+    let components = upgraded.components.as_ref().unwrap();
+    assert!(components.channels.contains_key("dimmed"));
+    assert!(components.operations.is_empty());
+    let ReferenceOr::Item(parameter) = &components.parameters["streetlightId"] else {
+        panic!("expected an inline parameter");
+    };
+    assert_eq!(parameter.en, ["a", "b"]);
+}
+
+#[test]
+fn test_downgrade_v3_to_v2() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Streetlights API
+      version: 1.0.0
+    servers:
+      production:
+        url: mqtt://api.streetlights.smartylighting.com/mqtt
+        protocol: mqtt
+        security:
+          - apiKey: []
+    channels:
+      light/measured:
+        servers: [production]
+        subscribe:
+          operationId: onLightMeasured
+          message:
+            oneOf:
+              - $ref: '#/components/messages/lightMeasured'
+              - name: dimLight
+        publish:
+          message:
+            $ref: '#/components/messages/lightMeasured'
+    components:
+      messages:
+        lightMeasured:
+          payload:
+            type: object
+    "#
+    .parse()
+    .unwrap();
+
+    let mut upgraded = upgrade_v2_to_v3(&api);
+    upgraded
+        .components
+        .as_mut()
+        .unwrap()
+        .replies
+        .insert("reply".to_string(), ReferenceOr::Item(v3::Reply::default()));
+    let duplicate = v3::Channel {
+        address: Some("light/measured".to_string()),
+        description: Some("dropped".to_string()),
+        ..Default::default()
+    };
+    upgraded
+        .channels
+        .insert("duplicate".to_string(), ReferenceOr::Item(duplicate));
+    let (downgraded, warnings) = downgrade_v3_to_v2(&upgraded);
+    assert_eq!(downgraded.asyncapi, "2.6.0");
+    assert_eq!(
+        warnings,
+        [
+            DowngradeWarning {
+                path: "/channels/duplicate".to_string(),
+                kind: DowngradeWarningKind::DuplicateAddress,
+            },
+            DowngradeWarning {
+                path: "/components/replies".to_string(),
+                kind: DowngradeWarningKind::UnsupportedField("replies"),
+            }
+        ]
+    );
+
+    let ReferenceOr::Item(server) = &downgraded.servers["production"] else {
+        panic!("expected an inline server");
+    };
+    assert_eq!(
+        server.url,
+        "mqtt://api.streetlights.smartylighting.com/mqtt"
+    );
+    assert_eq!(server.security[0].values["apiKey"], Vec::<String>::new());
+
+    let channel = &downgraded.channels["light/measured"];
+    assert_eq!(channel.description, None);
+    assert_eq!(channel.servers, ["production"]);
+    let subscribe = channel.subscribe.as_ref().unwrap();
+    assert_eq!(subscribe.operation_id.as_deref(), Some("onLightMeasured"));
+    let Some(OperationMessageType::OneOf { one_of }) = &subscribe.message else {
+        panic!("expected a oneOf message");
+    };
+    assert_eq!(
+        one_of[0],
+        ReferenceOr::ref_("#/components/messages/lightMeasured")
+    );
+    assert!(
+        matches!(&one_of[1], ReferenceOr::Item(message) if message.name.as_deref() == Some("dimLight"))
+    );
+    assert!(matches!(
+        &channel.publish.as_ref().unwrap().message,
+        Some(OperationMessageType::Single(message))
+            if *message == ReferenceOr::ref_("#/components/messages/lightMeasured")
+    ));
+}
+
+#[test]
+fn test_convert_security_schemes() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Streetlights API
+      version: 1.0.0
+    servers:
+      production:
+        url: api.streetlights.smartylighting.com
+        protocol: mqtt
+        security:
+          - oauth: ['streetlights:dim']
+    channels: {}
+    components:
+      securitySchemes:
+        oauth:
+          type: oauth2
+          flows:
+            clientCredentials:
+              tokenUrl: https://example.com/api/oauth/token
+              scopes:
+                streetlights:dim: Dim the lights
+    "#
+    .parse()
+    .unwrap();
+
+    let mut upgraded = upgrade_v2_to_v3(&api);
+    let components = upgraded.components.as_mut().unwrap();
+    let Some(ReferenceOr::Item(v3::SecurityScheme::OAuth2 { flows, scopes, .. })) =
+        components.security_schemes.get_mut("oauth")
+    else {
+        panic!("expected an inline oauth2 scheme");
+    };
+    let flow = flows.client_credentials.as_ref().unwrap();
+    assert_eq!(flow.available_scopes["streetlights:dim"], "Dim the lights");
+    assert!(scopes.is_empty());
+    scopes.push("streetlights:dim".to_string());
+
+    let (downgraded, warnings) = downgrade_v3_to_v2(&upgraded);
+    assert!(warnings.is_empty());
+    let ReferenceOr::Item(server) = &downgraded.servers["production"] else {
+        panic!("expected an inline server");
+    };
+    assert_eq!(server.security[0].values["oauth"], ["streetlights:dim"]);
+    assert_eq!(
+        downgraded.components.unwrap().security_schemes["oauth"],
+        api.components.unwrap().security_schemes["oauth"]
+    );
+}
+
+#[test]
+fn test_upgrade_messages() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Streetlights API
+      version: 1.0.0
+    channels:
+      light/measured:
+        subscribe:
+          message:
+            messageId: lightMeasured
+            name: LightMeasured
+            schemaFormat: application/vnd.apache.avro;version=1.9.0
+            payload:
+              type: object
+    components:
+      messages:
+        dimLight:
+          name: DimLight
+          payload:
+            type: string
+          traits:
+            - $ref: '#/components/messageTraits/avro'
+      messageTraits:
+        avro:
+          messageId: avro
+          schemaFormat: application/vnd.apache.avro;version=1.9.0
+    "#
+    .parse()
+    .unwrap();
+
+    let upgraded = upgrade_v2_to_v3(&api);
+    let ReferenceOr::Item(channel) = &upgraded.channels["light_measured"] else {
+        panic!("expected an inline channel");
+    };
+    let ReferenceOr::Item(message) = &channel.messages["lightMeasured"] else {
+        panic!("expected an inline message");
+    };
+    let Some(Payload::MultiFormat(payload)) = &message.payload else {
+        panic!("expected a multi format payload, got {:?}", message.payload);
+    };
+    assert_eq!(
+        payload.schema_format,
+        "application/vnd.apache.avro;version=1.9.0"
+    );
+    assert_eq!(payload.schema["type"], "object");
+
+    let components = upgraded.components.as_ref().unwrap();
+    let ReferenceOr::Item(message) = &components.messages["dimLight"] else {
+        panic!("expected an inline message");
+    };
+    assert!(matches!(
+        &message.payload,
+        Some(Payload::MultiFormat(payload)) if payload.schema["type"] == "string"
+    ));
+    let ReferenceOr::Item(message_trait) = &components.message_traits["avro"] else {
+        panic!("expected an inline message trait");
+    };
+    assert_eq!(
+        serde_json::to_value(message_trait).unwrap(),
+        serde_json::json!({})
+    );
+}
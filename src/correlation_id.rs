@@ -20,6 +20,6 @@ pub struct CorrelationId {
     pub location: String,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
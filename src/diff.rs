@@ -0,0 +1,412 @@
+//! Comparison of two versions of a document.
+//!
+//! [`diff`] lists the channels, operations, servers and messages that were
+//! added or removed, and the changes to the top-level payload fields of the
+//! messages. [`Diff::to_changelog`] renders the changes as Markdown for
+//! release notes.
+//!
+//! # Examples
+//!
+//! ```
+//! let old: asyncapi::AsyncAPI = r#"
+//! asyncapi: 2.3.0
+//! info:
+//!   title: Account Service
+//!   version: 1.0.0
+//! channels: {}
+//! "#
+//! .parse()
+//! .unwrap();
+//! let mut new = old.clone();
+//! new.channels.insert("order/created".to_string(), Default::default());
+//!
+//! let diff = asyncapi::diff::diff(&old, &new);
+//! assert_eq!(
+//!     diff.to_changelog(),
+//!     "## Added\n\n- Added channel `order/created`\n"
+//! );
+//! ```
+use std::fmt::{self, Write};
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    message::Payload,
+    reference::resolve_local,
+    schema::{SchemaKind, Type},
+    AsyncAPI, Message, OperationKind, ReferenceOr,
+};
+
+/// The changes between two versions of a document, in the order they were found.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+/// A single difference between two versions of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// The `info.version` of the document changed.
+    VersionChanged {
+        from: String,
+        to: String,
+    },
+    ServerAdded(String),
+    ServerRemoved(String),
+    ChannelAdded(String),
+    ChannelRemoved(String),
+    OperationAdded {
+        channel: String,
+        kind: OperationKind,
+    },
+    OperationRemoved {
+        channel: String,
+        kind: OperationKind,
+    },
+    /// A message, named by its key in the
+    /// [Components Object][crate::Components] or its `name`, was added.
+    MessageAdded(String),
+    MessageRemoved(String),
+    /// A top-level field was added to the payload of a message.
+    FieldAdded {
+        message: String,
+        field: String,
+    },
+    FieldRemoved {
+        message: String,
+        field: String,
+    },
+    /// A payload field became required.
+    FieldRequired {
+        message: String,
+        field: String,
+    },
+    /// A payload field is no longer required.
+    FieldOptional {
+        message: String,
+        field: String,
+    },
+}
+
+/// The section of a changelog a [`Change`] is listed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeCategory {
+    Added,
+    Changed,
+    Removed,
+}
+
+impl Change {
+    pub fn category(&self) -> ChangeCategory {
+        match self {
+            Change::ServerAdded(_)
+            | Change::ChannelAdded(_)
+            | Change::OperationAdded { .. }
+            | Change::MessageAdded(_)
+            | Change::FieldAdded { .. } => ChangeCategory::Added,
+            Change::ServerRemoved(_)
+            | Change::ChannelRemoved(_)
+            | Change::OperationRemoved { .. }
+            | Change::MessageRemoved(_)
+            | Change::FieldRemoved { .. } => ChangeCategory::Removed,
+            Change::VersionChanged { .. }
+            | Change::FieldRequired { .. }
+            | Change::FieldOptional { .. } => ChangeCategory::Changed,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::VersionChanged { from, to } => {
+                write!(f, "Version changed from `{from}` to `{to}`")
+            }
+            Change::ServerAdded(name) => write!(f, "Added server `{name}`"),
+            Change::ServerRemoved(name) => write!(f, "Removed server `{name}`"),
+            Change::ChannelAdded(name) => write!(f, "Added channel `{name}`"),
+            Change::ChannelRemoved(name) => write!(f, "Removed channel `{name}`"),
+            Change::OperationAdded { channel, kind } => {
+                write!(f, "Added {kind} operation to channel `{channel}`")
+            }
+            Change::OperationRemoved { channel, kind } => {
+                write!(f, "Removed {kind} operation from channel `{channel}`")
+            }
+            Change::MessageAdded(name) => write!(f, "Added message `{name}`"),
+            Change::MessageRemoved(name) => write!(f, "Removed message `{name}`"),
+            Change::FieldAdded { message, field } => {
+                write!(f, "Added field `{field}` to `{message}`")
+            }
+            Change::FieldRemoved { message, field } => {
+                write!(f, "Removed field `{field}` from `{message}`")
+            }
+            Change::FieldRequired { message, field } => {
+                write!(f, "Field `{field}` is now required in `{message}`")
+            }
+            Change::FieldOptional { message, field } => {
+                write!(f, "Field `{field}` is no longer required in `{message}`")
+            }
+        }
+    }
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Renders the changes as a Markdown changelog with an `Added`, `Changed`
+    /// and `Removed` section. Empty sections are left out.
+    pub fn to_changelog(&self) -> String {
+        let mut out = String::new();
+        for (category, title) in [
+            (ChangeCategory::Added, "Added"),
+            (ChangeCategory::Changed, "Changed"),
+            (ChangeCategory::Removed, "Removed"),
+        ] {
+            let mut changes = self
+                .changes
+                .iter()
+                .filter(|change| change.category() == category)
+                .peekable();
+            if changes.peek().is_none() {
+                continue;
+            }
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            let _ = writeln!(out, "## {title}\n");
+            for change in changes {
+                let _ = writeln!(out, "- {change}");
+            }
+        }
+        out
+    }
+}
+
+/// Compares the `old` and the `new` version of a document.
+pub fn diff(old: &AsyncAPI, new: &AsyncAPI) -> Diff {
+    let mut changes = Vec::new();
+    if old.info.version != new.info.version {
+        changes.push(Change::VersionChanged {
+            from: old.info.version.clone(),
+            to: new.info.version.clone(),
+        });
+    }
+
+    let (added, removed) = compare_keys(old.servers.keys(), new.servers.keys());
+    changes.extend(added.into_iter().map(Change::ServerAdded));
+    changes.extend(removed.into_iter().map(Change::ServerRemoved));
+
+    for name in new
+        .channels
+        .keys()
+        .filter(|name| !old.channels.contains_key(*name))
+    {
+        changes.push(Change::ChannelAdded(name.clone()));
+    }
+    for (name, old_channel) in &old.channels {
+        let new_channel = match new.channels.get(name) {
+            Some(channel) => channel,
+            None => {
+                changes.push(Change::ChannelRemoved(name.clone()));
+                continue;
+            }
+        };
+        for kind in [OperationKind::Subscribe, OperationKind::Publish] {
+            match (old_channel.operation(kind), new_channel.operation(kind)) {
+                (None, Some(_)) => changes.push(Change::OperationAdded {
+                    channel: name.clone(),
+                    kind,
+                }),
+                (Some(_), None) => changes.push(Change::OperationRemoved {
+                    channel: name.clone(),
+                    kind,
+                }),
+                _ => {}
+            }
+        }
+    }
+
+    let old_messages = messages(old);
+    let new_messages = messages(new);
+    let (added, removed) = compare_keys(old_messages.keys(), new_messages.keys());
+    changes.extend(added.into_iter().map(Change::MessageAdded));
+    for (name, old_message) in &old_messages {
+        let new_message = match new_messages.get(name) {
+            Some(message) => message,
+            None => continue,
+        };
+        let (old_fields, old_required) = payload_fields(old, old_message);
+        let (new_fields, new_required) = payload_fields(new, new_message);
+        let field = |field: &String| (name.clone(), field.clone());
+        let (added, removed) = compare_keys(old_fields.iter(), new_fields.iter());
+        for (message, field) in added.iter().map(field) {
+            changes.push(Change::FieldAdded { message, field });
+        }
+        for (message, field) in removed.iter().map(field) {
+            changes.push(Change::FieldRemoved { message, field });
+        }
+        let (required, optional) = compare_keys(old_required.iter(), new_required.iter());
+        for (message, field) in required.iter().map(field) {
+            changes.push(Change::FieldRequired { message, field });
+        }
+        for (message, field) in optional.iter().map(field) {
+            changes.push(Change::FieldOptional { message, field });
+        }
+    }
+    changes.extend(removed.into_iter().map(Change::MessageRemoved));
+
+    Diff { changes }
+}
+
+/// Returns the keys only in `new` and the keys only in `old`.
+fn compare_keys<'a>(
+    old: impl Iterator<Item = &'a String>,
+    new: impl Iterator<Item = &'a String>,
+) -> (Vec<String>, Vec<String>) {
+    let old: IndexSet<_> = old.collect();
+    let new: IndexSet<_> = new.collect();
+    (
+        new.difference(&old).map(|key| key.to_string()).collect(),
+        old.difference(&new).map(|key| key.to_string()).collect(),
+    )
+}
+
+/// The messages of the components, and the named messages of the
+/// operations that are defined in place.
+fn messages(api: &AsyncAPI) -> IndexMap<String, &Message> {
+    let mut messages = IndexMap::new();
+    if let Some(components) = &api.components {
+        for (name, message) in &components.messages {
+            if let Ok(message) = resolve_local(
+                Some(&components.messages),
+                "#/components/messages/",
+                message,
+            ) {
+                messages.insert(name.clone(), message);
+            }
+        }
+    }
+    for (_, _, operation) in api.operations() {
+        for message in operation.messages() {
+            if let ReferenceOr::Item(message) = message {
+                if let Some(name) = &message.name {
+                    messages.entry(name.clone()).or_insert(message);
+                }
+            }
+        }
+    }
+    messages
+}
+
+/// The top-level properties and the required properties of a message payload.
+fn payload_fields(api: &AsyncAPI, message: &Message) -> (Vec<String>, Vec<String>) {
+    let schemas = api
+        .components
+        .as_ref()
+        .map(|components| &components.schemas);
+    let schema = match &message.payload {
+        Some(Payload::Schema(schema)) => {
+            match resolve_local(schemas, "#/components/schemas/", schema) {
+                Ok(schema) => schema,
+                Err(_) => return Default::default(),
+            }
+        }
+        _ => return Default::default(),
+    };
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (
+            object.properties.keys().cloned().collect(),
+            object.required.clone(),
+        ),
+        SchemaKind::Any(any) => (
+            any.properties.keys().cloned().collect(),
+            any.required.clone(),
+        ),
+        _ => Default::default(),
+    }
+}
+
+#[test]
+fn test_diff_changelog() {
+    let old: AsyncAPI = r#"
+    asyncapi: 2.3.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+      user/deleted:
+        subscribe:
+          message:
+            name: UserDeleted
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            $ref: '#/components/schemas/User'
+      schemas:
+        User:
+          type: object
+          properties:
+            email:
+              type: string
+            nickname:
+              type: string
+    "#
+    .parse()
+    .unwrap();
+    let new: AsyncAPI = r#"
+    asyncapi: 2.3.0
+    info:
+      title: Account Service
+      version: 1.1.0
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+        publish:
+          operationId: signUp
+      order/created: {}
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            type: object
+            required:
+              - email
+            properties:
+              email:
+                type: string
+              name:
+                type: string
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        diff(&old, &new).to_changelog(),
+        "## Added
+
+- Added channel `order/created`
+- Added publish operation to channel `user/signedup`
+- Added field `name` to `UserSignedUp`
+
+## Changed
+
+- Version changed from `1.0.0` to `1.1.0`
+- Field `email` is now required in `UserSignedUp`
+
+## Removed
+
+- Removed channel `user/deleted`
+- Removed field `nickname` from `UserSignedUp`
+- Removed message `UserDeleted`
+"
+    );
+}
@@ -18,6 +18,6 @@ pub struct Discriminator {
     #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub mapping: IndexMap<String, String>,
     /// Inline extensions to this object.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
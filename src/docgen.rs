@@ -0,0 +1,529 @@
+//! Rendering of an [AsyncAPI document][AsyncAPI] as human-readable documentation.
+//!
+//! [`to_markdown`] produces a Markdown page listing the servers, the channels
+//! grouped by the tags of their operations, the messages of every operation and
+//! the properties of their payload schemas. With the `html` feature enabled,
+//! [`to_html`] renders the same content as a single, self-contained HTML page.
+use std::{borrow::Borrow, fmt::Write};
+
+use indexmap::IndexMap;
+
+use crate::{
+    message::Payload,
+    reference::reference_name,
+    schema::{SchemaKind, Type},
+    AsyncAPI, Channel, Message, Operation, OperationKind, ReferenceOr, Schema,
+};
+
+#[cfg(feature = "html")]
+mod html;
+
+#[cfg(feature = "html")]
+pub use html::to_html;
+
+/// Renders `api` as a Markdown document.
+///
+/// # Examples
+///
+/// ```
+/// # use asyncapi::AsyncAPI;
+/// let api: AsyncAPI = serde_yaml::from_str(
+///     r#"
+///     asyncapi: 2.3.0
+///     info:
+///       title: Account Service
+///       version: 1.0.0
+///     channels:
+///       user/signedup:
+///         subscribe:
+///           message:
+///             name: UserSignedUp
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let markdown = asyncapi::docgen::to_markdown(&api);
+/// assert!(markdown.starts_with("# Account Service 1.0.0"));
+/// ```
+pub fn to_markdown(api: &AsyncAPI) -> String {
+    let mut markdown = Markdown::default();
+    render(api, &mut markdown);
+    markdown.out
+}
+
+/// The building blocks the documentation is made of. Each output format
+/// implements them once, the structure of the document is decided by [`render`].
+pub(crate) trait Renderer {
+    fn heading(&mut self, level: usize, text: &str);
+    /// A paragraph of CommonMark text taken verbatim from the document.
+    fn paragraph(&mut self, text: &str);
+    /// A `name: value` pair, `value` is shown as code.
+    fn field(&mut self, name: &str, value: &str);
+    fn table(&mut self, header: &[&str], rows: &[Vec<String>]);
+}
+
+pub(crate) fn render(api: &AsyncAPI, out: &mut impl Renderer) {
+    out.heading(1, &format!("{} {}", api.info.title, api.info.version));
+    if let Some(description) = &api.info.description {
+        out.paragraph(description);
+    }
+    if let Some(terms_of_service) = &api.info.terms_of_service {
+        out.field("Terms of service", terms_of_service);
+    }
+    if let Some(contact) = &api.info.contact {
+        let contact = [&contact.name, &contact.email, &contact.url]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        if !contact.is_empty() {
+            out.field("Contact", &contact.join(", "));
+        }
+    }
+    if let Some(license) = &api.info.license {
+        out.field("License", &license.name);
+    }
+    if let Some(content_type) = &api.default_content_type {
+        out.field("Default content type", content_type.as_str());
+    }
+
+    if !api.servers.is_empty() {
+        out.heading(2, "Servers");
+        let rows = api
+            .servers
+            .iter()
+            .map(|(name, server)| match server {
+                ReferenceOr::Item(server) => vec![
+                    name.clone(),
+                    server.url.clone(),
+                    server
+                        .protocol_version
+                        .as_ref()
+                        .map(|version| format!("{} {}", server.protocol, version))
+                        .unwrap_or_else(|| server.protocol.clone()),
+                    server.description.clone().unwrap_or_default(),
+                ],
+                ReferenceOr::Reference { reference } => {
+                    vec![
+                        name.clone(),
+                        reference.clone(),
+                        String::new(),
+                        String::new(),
+                    ]
+                }
+            })
+            .collect::<Vec<_>>();
+        out.table(&["Name", "URL", "Protocol", "Description"], &rows);
+    }
+
+    if !api.channels.is_empty() {
+        out.heading(2, "Channels");
+        for (tag, channels) in channels_by_tag(api) {
+            let tag_level = match &tag {
+                Some(tag) => {
+                    out.heading(3, tag);
+                    if let Some(description) = api
+                        .tags
+                        .iter()
+                        .find(|t| &t.name == tag)
+                        .and_then(|t| t.description.as_ref())
+                    {
+                        out.paragraph(description);
+                    }
+                    4
+                }
+                None => 3,
+            };
+            for (name, channel) in channels {
+                render_channel(api, name, channel, tag_level, out);
+            }
+        }
+    }
+}
+
+/// Groups the channels by the tags of their operations, keeping the order of
+/// the top-level `tags` list first. Channels without any tagged operation are
+/// listed under `None`, a channel with several tags shows up in every group.
+fn channels_by_tag(api: &AsyncAPI) -> IndexMap<Option<String>, Vec<(&String, &Channel)>> {
+    let mut groups: IndexMap<Option<String>, Vec<(&String, &Channel)>> = api
+        .tags
+        .iter()
+        .map(|tag| (Some(tag.name.clone()), Vec::new()))
+        .collect();
+    for (name, channel) in &api.channels {
+        let mut tags = Vec::new();
+        for (_, operation) in channel.operations() {
+            for tag in &operation.tags {
+                if !tags.contains(&tag.name) {
+                    tags.push(tag.name.clone());
+                }
+            }
+        }
+        if tags.is_empty() {
+            groups.entry(None).or_default().push((name, channel));
+        }
+        for tag in tags {
+            groups.entry(Some(tag)).or_default().push((name, channel));
+        }
+    }
+    groups.retain(|_, channels| !channels.is_empty());
+    // Untagged channels come last.
+    if let Some(untagged) = groups.shift_remove(&None) {
+        groups.insert(None, untagged);
+    }
+    groups
+}
+
+fn render_channel(
+    api: &AsyncAPI,
+    name: &str,
+    channel: &Channel,
+    level: usize,
+    out: &mut impl Renderer,
+) {
+    out.heading(level, &format!("`{name}`"));
+    if let Some(description) = &channel.description {
+        out.paragraph(description);
+    }
+    if !channel.servers.is_empty() {
+        out.field("Servers", &channel.servers.join(", "));
+    }
+    if !channel.parameters.is_empty() {
+        let rows = channel
+            .parameters
+            .iter()
+            .map(|(name, parameter)| match parameter {
+                ReferenceOr::Item(parameter) => vec![
+                    name.clone(),
+                    parameter
+                        .schema
+                        .as_ref()
+                        .map(ref_or_schema_type)
+                        .unwrap_or_default(),
+                    parameter.description.clone().unwrap_or_default(),
+                ],
+                ReferenceOr::Reference { reference } => {
+                    vec![name.clone(), reference_name(reference), String::new()]
+                }
+            })
+            .collect::<Vec<_>>();
+        out.table(&["Parameter", "Type", "Description"], &rows);
+    }
+    for (kind, operation) in channel.operations() {
+        let title = match kind {
+            OperationKind::Publish => "Publish",
+            OperationKind::Subscribe => "Subscribe",
+        };
+        render_operation(api, title, operation, level + 1, out);
+    }
+}
+
+fn render_operation(
+    api: &AsyncAPI,
+    kind: &str,
+    operation: &Operation,
+    level: usize,
+    out: &mut impl Renderer,
+) {
+    match &operation.operation_id {
+        Some(id) => out.heading(level, &format!("{kind} `{id}`")),
+        None => out.heading(level, kind),
+    }
+    if let Some(summary) = &operation.summary {
+        out.paragraph(summary);
+    }
+    if let Some(description) = &operation.description {
+        out.paragraph(description);
+    }
+    if !operation.tags.is_empty() {
+        let tags = operation
+            .tags
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect::<Vec<_>>();
+        out.field("Tags", &tags.join(", "));
+    }
+
+    let messages = operation.messages();
+    if messages.is_empty() {
+        return;
+    }
+    let messages = messages
+        .into_iter()
+        .filter_map(|message| resolve_message(api, message))
+        .collect::<Vec<_>>();
+    let rows = messages
+        .iter()
+        .map(|(name, message)| {
+            vec![
+                name.clone(),
+                message.title.clone().unwrap_or_default(),
+                message
+                    .content_type
+                    .as_ref()
+                    .or(api.default_content_type.as_ref())
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+                message.summary.clone().unwrap_or_default(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    out.table(&["Message", "Title", "Content type", "Summary"], &rows);
+
+    for (name, message) in messages {
+        if let Some(description) = &message.description {
+            out.heading(level + 1, &format!("Message `{name}`"));
+            out.paragraph(description);
+        } else if message.payload.is_some() || message.headers.is_some() {
+            out.heading(level + 1, &format!("Message `{name}`"));
+        }
+        if let Some(ReferenceOr::Item(headers)) = &message.headers {
+            out.field("Headers", &schema_type(headers));
+            render_properties(headers, out);
+        }
+        if let Some(payload) = &message.payload {
+            match payload {
+                Payload::Schema(ReferenceOr::Item(schema)) => {
+                    out.field("Payload", &schema_type(schema));
+                    render_properties(schema, out);
+                }
+                Payload::Schema(ReferenceOr::Reference { reference }) => {
+                    out.field("Payload", &reference_name(reference))
+                }
+                Payload::MultiFormat(schema) => out.field("Payload", &schema.schema_format),
+                Payload::Any(_) => out.field(
+                    "Payload",
+                    &message.schema_format.clone().unwrap_or_default(),
+                ),
+            }
+        }
+    }
+}
+
+/// Looks up a message referenced from `#/components/messages`, other references
+/// are rendered by name only.
+fn resolve_message<'a>(
+    api: &'a AsyncAPI,
+    message: &'a ReferenceOr<Message>,
+) -> Option<(String, &'a Message)> {
+    match message {
+        ReferenceOr::Item(message) => Some((
+            message
+                .name
+                .clone()
+                .unwrap_or_else(|| "(anonymous)".to_string()),
+            message,
+        )),
+        ReferenceOr::Reference { reference } => {
+            let name = reference.strip_prefix("#/components/messages/")?;
+            match api.components.as_ref()?.messages.get(name)? {
+                ReferenceOr::Item(message) => Some((
+                    message.name.clone().unwrap_or_else(|| name.to_string()),
+                    message,
+                )),
+                ReferenceOr::Reference { .. } => None,
+            }
+        }
+    }
+}
+
+/// Renders the properties of an object schema, nested objects are flattened
+/// into dotted property names.
+fn render_properties(schema: &Schema, out: &mut impl Renderer) {
+    let mut rows = Vec::new();
+    collect_properties("", schema, &mut rows);
+    if !rows.is_empty() {
+        out.table(&["Property", "Type", "Required", "Description"], &rows);
+    }
+}
+
+fn collect_properties(prefix: &str, schema: &Schema, rows: &mut Vec<Vec<String>>) {
+    let (properties, required) = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (&object.properties, &object.required),
+        SchemaKind::Any(any) => (&any.properties, &any.required),
+        _ => return,
+    };
+    for (name, property) in properties {
+        let path = format!("{prefix}{name}");
+        let required = if required.contains(name) { "yes" } else { "no" };
+        match property {
+            ReferenceOr::Item(property) => {
+                rows.push(vec![
+                    path.clone(),
+                    schema_type(property),
+                    required.to_string(),
+                    property.schema_data.description.clone().unwrap_or_default(),
+                ]);
+                collect_properties(&format!("{path}."), property, rows);
+            }
+            ReferenceOr::Reference { reference } => rows.push(vec![
+                path,
+                reference_name(reference),
+                required.to_string(),
+                String::new(),
+            ]),
+        }
+    }
+}
+
+fn ref_or_schema_type<T: Borrow<Schema>>(schema: &ReferenceOr<T>) -> String {
+    match schema {
+        ReferenceOr::Item(schema) => schema_type(schema.borrow()),
+        ReferenceOr::Reference { reference } => reference_name(reference),
+    }
+}
+
+/// A short, human-readable description of the type of `schema`.
+pub(crate) fn schema_type(schema: &Schema) -> String {
+    use crate::VariantOrUnknownOrEmpty::{Empty, Item, Unknown};
+
+    fn with_format<T: serde::Serialize>(
+        name: &str,
+        format: &crate::VariantOrUnknownOrEmpty<T>,
+    ) -> String {
+        match format {
+            Item(format) => match serde_json::to_value(format) {
+                Ok(serde_json::Value::String(format)) => format!("{name}({format})"),
+                _ => name.to_string(),
+            },
+            Unknown(format) => format!("{name}({format})"),
+            Empty => name.to_string(),
+        }
+    }
+    fn list(schemas: &[ReferenceOr<Schema>], separator: &str) -> String {
+        schemas
+            .iter()
+            .map(|schema| match schema {
+                ReferenceOr::Item(schema) => schema_type(schema),
+                ReferenceOr::Reference { reference } => reference_name(reference),
+            })
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string)) => {
+            let mut ty = with_format("string", &string.format);
+            if !string.enumeration.is_empty() {
+                let values = string
+                    .enumeration
+                    .iter()
+                    .flatten()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>();
+                let _ = write!(ty, " ({})", values.join(", "));
+            }
+            ty
+        }
+        SchemaKind::Type(Type::Number(number)) => with_format("number", &number.format),
+        SchemaKind::Type(Type::Integer(integer)) => with_format("integer", &integer.format),
+        SchemaKind::Type(Type::Boolean {}) => "boolean".to_string(),
+        SchemaKind::Type(Type::Object(_)) => "object".to_string(),
+        SchemaKind::Type(Type::Array(array)) => match &array.items {
+            Some(items) => format!("array<{}>", ref_or_schema_type(items)),
+            None => "array".to_string(),
+        },
+        SchemaKind::OneOf { one_of } => list(one_of, " | "),
+        SchemaKind::AnyOf { any_of } => list(any_of, " | "),
+        SchemaKind::AllOf { all_of } => list(all_of, " & "),
+        SchemaKind::Any(any) => {
+            if !any.properties.is_empty() {
+                "object".to_string()
+            } else if let Some(items) = &any.items {
+                format!("array<{}>", ref_or_schema_type(items))
+            } else {
+                any.format.clone().unwrap_or_else(|| "any".to_string())
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Markdown {
+    out: String,
+}
+
+impl Markdown {
+    fn cell(text: &str) -> String {
+        text.replace('|', "\\|").replace('\n', " ")
+    }
+}
+
+impl Renderer for Markdown {
+    fn heading(&mut self, level: usize, text: &str) {
+        let _ = writeln!(self.out, "{} {}\n", "#".repeat(level.min(6)), text);
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        let _ = writeln!(self.out, "{}\n", text.trim_end());
+    }
+
+    fn field(&mut self, name: &str, value: &str) {
+        let _ = writeln!(self.out, "**{name}:** `{value}`\n");
+    }
+
+    fn table(&mut self, header: &[&str], rows: &[Vec<String>]) {
+        let _ = writeln!(self.out, "| {} |", header.join(" | "));
+        let _ = writeln!(self.out, "|{}", "---|".repeat(header.len()));
+        for row in rows {
+            let row = row.iter().map(|cell| Self::cell(cell)).collect::<Vec<_>>();
+            let _ = writeln!(self.out, "| {} |", row.join(" | "));
+        }
+        self.out.push('\n');
+    }
+}
+
+#[test]
+fn test_markdown_groups_channels_by_tag() {
+    let api: AsyncAPI = serde_yaml::from_str(
+        r#"
+        asyncapi: 2.3.0
+        info:
+          title: Streetlights
+          version: 1.0.0
+        servers:
+          production:
+            url: broker.example.com
+            protocol: kafka
+        tags:
+          - name: lights
+        channels:
+          light/measured:
+            subscribe:
+              operationId: receiveLight
+              tags:
+                - name: lights
+              message:
+                $ref: '#/components/messages/LightMeasured'
+          health:
+            publish:
+              message:
+                name: Ping
+        components:
+          messages:
+            LightMeasured:
+              name: lightMeasured
+              contentType: application/json
+              payload:
+                type: object
+                required:
+                  - lumens
+                properties:
+                  lumens:
+                    type: integer
+                    description: Light intensity
+                  sentAt:
+                    $ref: '#/components/schemas/sentAt'
+        "#,
+    )
+    .unwrap();
+
+    let markdown = to_markdown(&api);
+    assert!(markdown.contains("| production | broker.example.com | kafka |  |"));
+    assert!(markdown.contains("### lights\n\n#### `light/measured`"));
+    assert!(markdown.contains("##### Subscribe `receiveLight`"));
+    assert!(markdown.contains("| lightMeasured |  | application/json |  |"));
+    assert!(markdown.contains("| lumens | integer | yes | Light intensity |"));
+    assert!(markdown.contains("| sentAt | sentAt | no |  |"));
+    assert!(markdown.find("`light/measured`") < markdown.find("### `health`"));
+}
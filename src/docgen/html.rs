@@ -0,0 +1,215 @@
+use std::{collections::HashSet, fmt::Write};
+
+use super::Renderer;
+use crate::AsyncAPI;
+
+const TEMPLATE: &str = include_str!("template.html");
+
+/// Renders `api` as a standalone HTML page.
+///
+/// The page embeds its style sheet and doesn't load any scripts, so it can be
+/// served as is.
+///
+/// This function is only available with the `html` feature enabled.
+pub fn to_html(api: &AsyncAPI) -> String {
+    let mut html = Html::default();
+    super::render(api, &mut html);
+    let title = escape(&format!("{} {}", api.info.title, api.info.version));
+    fill(TEMPLATE, &[("title", &title), ("content", &html.out)])
+}
+
+/// Replaces the `{{name}}` placeholders of `template` with their values in a
+/// single pass, so placeholders inside the values are kept as they are.
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find("}}").and_then(|end| {
+            let name = &rest[2..end];
+            let (_, value) = values
+                .iter()
+                .find(|(placeholder, _)| *placeholder == name)?;
+            Some((*value, end + 2))
+        });
+        match value {
+            Some((value, end)) => {
+                filled.push_str(value);
+                rest = &rest[end..];
+            }
+            None => {
+                filled.push_str("{{");
+                rest = &rest[2..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+#[derive(Default)]
+struct Html {
+    out: String,
+    /// The ids given to headings so far.
+    ids: HashSet<String>,
+}
+
+impl Html {
+    /// Returns the slug of `text`, with a number appended if a previous
+    /// heading already has that slug.
+    fn id(&mut self, text: &str) -> String {
+        let mut base = slug(text);
+        if base.is_empty() {
+            base.push_str("section");
+        }
+        let mut id = base.clone();
+        let mut number = 1;
+        while self.ids.contains(&id) {
+            number += 1;
+            id = format!("{base}-{number}");
+        }
+        self.ids.insert(id.clone());
+        id
+    }
+}
+
+impl Renderer for Html {
+    fn heading(&mut self, level: usize, text: &str) {
+        let level = level.min(6);
+        let id = self.id(text);
+        let _ = writeln!(
+            self.out,
+            "<h{level} id=\"{id}\">{}</h{level}>",
+            inline(text)
+        );
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
+            let _ = writeln!(self.out, "<p>{}</p>", inline(paragraph.trim()));
+        }
+    }
+
+    fn field(&mut self, name: &str, value: &str) {
+        let _ = writeln!(
+            self.out,
+            "<p><strong>{}:</strong> <code>{}</code></p>",
+            escape(name),
+            escape(value)
+        );
+    }
+
+    fn table(&mut self, header: &[&str], rows: &[Vec<String>]) {
+        self.out.push_str("<table>\n<thead><tr>");
+        for cell in header {
+            let _ = write!(self.out, "<th>{}</th>", escape(cell));
+        }
+        self.out.push_str("</tr></thead>\n<tbody>\n");
+        for row in rows {
+            self.out.push_str("<tr>");
+            for cell in row {
+                let _ = write!(self.out, "<td>{}</td>", escape(cell));
+            }
+            self.out.push_str("</tr>\n");
+        }
+        self.out.push_str("</tbody>\n</table>\n");
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escapes `text` and turns `` `code` `` spans into `<code>` elements, the
+/// only inline markup the generated headings rely on.
+fn inline(text: &str) -> String {
+    let mut html = String::new();
+    for (i, part) in text.split('`').enumerate() {
+        if i % 2 == 1 {
+            let _ = write!(html, "<code>{}</code>", escape(part));
+        } else {
+            html.push_str(&escape(part));
+        }
+    }
+    html
+}
+
+fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+#[test]
+fn test_html_is_escaped() {
+    let api: AsyncAPI = serde_yaml::from_str(
+        r#"
+        asyncapi: 2.3.0
+        info:
+          title: Chat <Service>
+          version: 1.0.0
+        channels:
+          room/{roomId}:
+            description: Messages & events of a room.
+            subscribe:
+              operationId: onMessage
+        "#,
+    )
+    .unwrap();
+
+    let html = to_html(&api);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Chat &lt;Service&gt; 1.0.0</title>"));
+    assert!(html.contains("<h3 id=\"room-roomid\"><code>room/{roomId}</code></h3>"));
+    assert!(html.contains("<p>Messages &amp; events of a room.</p>"));
+    assert!(html.contains("<h4 id=\"subscribe-onmessage\">Subscribe <code>onMessage</code></h4>"));
+}
+
+#[test]
+fn test_html_placeholders_and_ids() {
+    let api: AsyncAPI = serde_yaml::from_str(
+        r#"
+        asyncapi: 2.3.0
+        info:
+          title: '{{content}}'
+          version: 1.0.0
+        channels:
+          room/a:
+            publish: {}
+          room-a:
+            publish: {}
+        "#,
+    )
+    .unwrap();
+
+    let html = to_html(&api);
+    assert!(html.contains("<title>{{content}} 1.0.0</title>"));
+    assert_eq!(html.matches("<title>").count(), 1);
+    assert!(html.contains("<h3 id=\"room-a\"><code>room/a</code></h3>"));
+    assert!(html.contains("<h3 id=\"room-a-2\"><code>room-a</code></h3>"));
+    assert!(html.contains("<h4 id=\"publish\">"));
+    assert!(html.contains("<h4 id=\"publish-2\">"));
+
+    assert_eq!(
+        fill("{{a}}{{b}} {{c}}", &[("a", "{{b}}"), ("b", "2")]),
+        "{{b}}2 {{c}}"
+    );
+}
@@ -0,0 +1,215 @@
+//! Export of the topology of an [AsyncAPI document][AsyncAPI] as a
+//! [Graphviz](https://graphviz.org) DOT graph.
+//!
+//! The graph contains a node for every server, channel, operation and
+//! message. Servers point to the channels available on them, channels to
+//! their operations and operations to the messages they carry. Messages
+//! referenced from several operations are shown once.
+use std::fmt::Write;
+
+use indexmap::IndexSet;
+
+use crate::{reference::reference_name, AsyncAPI, Channel, ReferenceOr};
+
+/// Controls which parts of the document end up in the graph.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DotOptions {
+    /// Only include channels with at least one operation tagged with one of
+    /// these tags. All channels are included when empty.
+    pub tags: Vec<String>,
+}
+
+impl DotOptions {
+    /// Only include channels with an operation tagged with `tag`.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+/// Renders `api` as a DOT graph.
+///
+/// # Examples
+///
+/// ```
+/// # use asyncapi::{AsyncAPI, dot::DotOptions};
+/// let api: AsyncAPI = serde_yaml::from_str(
+///     r#"
+///     asyncapi: 2.3.0
+///     info:
+///       title: Account Service
+///       version: 1.0.0
+///     channels:
+///       user/signedup:
+///         subscribe:
+///           tags:
+///             - name: user
+///           message:
+///             name: UserSignedUp
+///       health:
+///         publish:
+///           message:
+///             name: Ping
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let dot = asyncapi::dot::to_dot(&api, &DotOptions::default().tag("user"));
+/// assert!(dot.contains("\"channel:user/signedup\""));
+/// assert!(!dot.contains("\"channel:health\""));
+/// ```
+pub fn to_dot(api: &AsyncAPI, options: &DotOptions) -> String {
+    let channels = api
+        .channels
+        .iter()
+        .filter(|(_, channel)| matches_tags(channel, &options.tags))
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "digraph {} {{", quote(&api.info.title));
+    out.push_str("    rankdir=LR;\n");
+
+    for (name, server) in &api.servers {
+        let label = match server {
+            ReferenceOr::Item(server) => format!("{name}\n{}", server.protocol),
+            ReferenceOr::Reference { .. } => name.clone(),
+        };
+        let _ = writeln!(
+            out,
+            "    {} [shape=box, label={}];",
+            quote(&format!("server:{name}")),
+            quote(&label)
+        );
+    }
+
+    let mut messages = IndexSet::new();
+    let mut edges = Vec::new();
+    for (name, channel) in &channels {
+        let channel_id = format!("channel:{name}");
+        let _ = writeln!(
+            out,
+            "    {} [shape=ellipse, label={}];",
+            quote(&channel_id),
+            quote(name)
+        );
+
+        // Without a `servers` list the channel is available on all servers.
+        let servers = if channel.servers.is_empty() {
+            api.servers.keys().collect::<Vec<_>>()
+        } else {
+            channel.servers.iter().collect()
+        };
+        for server in servers {
+            edges.push((format!("server:{server}"), channel_id.clone()));
+        }
+
+        for (kind, operation) in channel.operations() {
+            let operation_id = format!("operation:{name}:{kind}");
+            let label = match &operation.operation_id {
+                Some(id) => format!("{kind}\n{id}"),
+                None => kind.to_string(),
+            };
+            let _ = writeln!(
+                out,
+                "    {} [shape=cds, label={}];",
+                quote(&operation_id),
+                quote(&label)
+            );
+            edges.push((channel_id.clone(), operation_id.clone()));
+
+            for (i, message) in operation.messages().into_iter().enumerate() {
+                let (message_id, label) = match message {
+                    ReferenceOr::Reference { reference } => {
+                        (format!("message:{reference}"), reference_name(reference))
+                    }
+                    ReferenceOr::Item(message) => (
+                        format!("message:{operation_id}:{i}"),
+                        message
+                            .name
+                            .clone()
+                            .or_else(|| message.title.clone())
+                            .unwrap_or_else(|| "message".to_string()),
+                    ),
+                };
+                if messages.insert(message_id.clone()) {
+                    let _ = writeln!(
+                        out,
+                        "    {} [shape=note, label={}];",
+                        quote(&message_id),
+                        quote(&label)
+                    );
+                }
+                edges.push((operation_id.clone(), message_id));
+            }
+        }
+    }
+
+    for (from, to) in edges {
+        let _ = writeln!(out, "    {} -> {};", quote(&from), quote(&to));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn matches_tags(channel: &Channel, tags: &[String]) -> bool {
+    tags.is_empty()
+        || channel
+            .operations()
+            .flat_map(|(_, operation)| &operation.tags)
+            .any(|tag| tags.contains(&tag.name))
+}
+
+fn quote(text: &str) -> String {
+    format!(
+        "\"{}\"",
+        text.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+#[test]
+fn test_dot_topology() {
+    let api: AsyncAPI = serde_yaml::from_str(
+        r#"
+        asyncapi: 2.3.0
+        info:
+          title: Streetlights
+          version: 1.0.0
+        servers:
+          production:
+            url: broker.example.com
+            protocol: mqtt
+          test:
+            url: test.example.com
+            protocol: mqtt
+        channels:
+          light/measured:
+            servers:
+              - production
+            publish:
+              operationId: receiveLight
+              message:
+                $ref: '#/components/messages/lightMeasured'
+          light/dimmed:
+            subscribe:
+              message:
+                $ref: '#/components/messages/lightMeasured'
+        "#,
+    )
+    .unwrap();
+
+    let dot = to_dot(&api, &DotOptions::default());
+    assert!(dot.starts_with("digraph \"Streetlights\" {\n"));
+    assert!(dot.contains("\"server:production\" [shape=box, label=\"production\\nmqtt\"];"));
+    assert!(dot.contains(
+        "\"operation:light/measured:publish\" [shape=cds, label=\"publish\\nreceiveLight\"];"
+    ));
+    assert!(dot.contains("\"server:production\" -> \"channel:light/measured\";"));
+    assert!(!dot.contains("\"server:test\" -> \"channel:light/measured\";"));
+    assert!(dot.contains("\"server:test\" -> \"channel:light/dimmed\";"));
+    assert_eq!(
+        dot.matches("[shape=note, label=\"lightMeasured\"]").count(),
+        1
+    );
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+
+use crate::validation::ValidationError;
+
+/// The error type of all fallible operations of this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be deserialized into the model.
+    Parse(FormatError),
+    /// The model could not be serialized.
+    Serialize(FormatError),
+    /// A reference could not be resolved.
+    Resolve(ResolveError),
+    /// A referenced document could not be loaded.
+    Load {
+        uri: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The document, or the values checked against it, break rules of the
+    /// specification. Every violation found is reported.
+    Validate(Vec<ValidationError>),
+}
+
+/// An error reported by the underlying serialization format.
+#[derive(Debug)]
+pub enum FormatError {
+    Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
+}
+
+/// The reasons a reference can't be resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolveError {
+    /// The reference points to another document.
+    External(String),
+    /// Nothing exists at the location the reference points to.
+    NotFound(String),
+    /// Following the reference leads back to itself.
+    Cycle(String),
+    /// The reference is used where no kind of component is expected, so it
+    /// can't be bundled into the components.
+    UnknownComponentKind(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "failed to parse document: {error}"),
+            Error::Serialize(error) => write!(f, "failed to serialize document: {error}"),
+            Error::Resolve(error) => error.fmt(f),
+            Error::Load { uri, source } => write!(f, "failed to load `{uri}`: {source}"),
+            Error::Validate(errors) => {
+                f.write_str("validation failed:")?;
+                for error in errors {
+                    write!(f, "\n  {error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(error) | Error::Serialize(error) => Some(error),
+            Error::Resolve(error) => Some(error),
+            Error::Load { source, .. } => Some(source.as_ref()),
+            Error::Validate(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Json(error) => error.fmt(f),
+            FormatError::Yaml(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FormatError::Json(error) => Some(error),
+            FormatError::Yaml(error) => Some(error),
+        }
+    }
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::External(reference) => {
+                write!(f, "`{reference}` points to another document")
+            }
+            ResolveError::NotFound(reference) => write!(f, "`{reference}` does not exist"),
+            ResolveError::Cycle(reference) => write!(f, "`{reference}` refers to itself"),
+            ResolveError::UnknownComponentKind(reference) => {
+                write!(
+                    f,
+                    "`{reference}` is used where no kind of component is expected"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+impl From<ResolveError> for Error {
+    fn from(error: ResolveError) -> Self {
+        Error::Resolve(error)
+    }
+}
+
+impl From<Vec<ValidationError>> for Error {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Error::Validate(errors)
+    }
+}
@@ -0,0 +1,57 @@
+//! Canned documents and assertions for testing code built on this crate.
+//!
+//! This module is only available with the `test-util` feature enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use asyncapi::fixtures;
+//!
+//! let api = fixtures::kafka_streetlights();
+//! let reparsed = serde_json::from_str(&serde_json::to_string(&api).unwrap()).unwrap();
+//! fixtures::assert_normalized_eq(&api, &reparsed);
+//! ```
+use crate::AsyncAPI;
+
+/// The smallest valid document: an API without any channels.
+pub fn minimal() -> AsyncAPI {
+    AsyncAPI::new("Minimal API", "1.0.0")
+}
+
+/// The Streetlights Kafka example of the AsyncAPI specification, using
+/// servers, security schemes, parameters, traits, bindings and references.
+pub fn kafka_streetlights() -> AsyncAPI {
+    include_str!("fixtures/streetlights_kafka.yml")
+        .parse()
+        .expect("the streetlights fixture is a valid document")
+}
+
+/// Converts a document into a [`serde_json::Value`] so that documents that
+/// only differ in the order of map entries or in empty optional collections
+/// compare equal.
+pub fn normalize(api: &AsyncAPI) -> serde_json::Value {
+    serde_json::to_value(api).expect("documents can always be serialized to JSON")
+}
+
+/// Asserts that two documents are equal after [normalization][normalize].
+///
+/// On failure both documents are printed as YAML.
+#[track_caller]
+pub fn assert_normalized_eq(left: &AsyncAPI, right: &AsyncAPI) {
+    if normalize(left) != normalize(right) {
+        panic!(
+            "documents are not equal\n--- left ---\n{}\n--- right ---\n{}",
+            serde_yaml::to_string(left).unwrap_or_default(),
+            serde_yaml::to_string(right).unwrap_or_default(),
+        );
+    }
+}
+
+#[test]
+fn test_fixtures_parse() {
+    assert!(minimal().channels.is_empty());
+    let api = kafka_streetlights();
+    assert_eq!(api.channels.len(), 4);
+    let yaml = serde_yaml::to_string(&api).unwrap();
+    assert_normalized_eq(&api, &yaml.parse().unwrap());
+}
@@ -0,0 +1,451 @@
+//! Construction of [`http::request::Builder`] values from HTTP bindings.
+//!
+//! The [HTTP operation binding][HTTPOperationBinding] describes the method and
+//! the query parameters of a request, while the
+//! [HTTP message binding][HTTPMessageBinding] describes its headers. A
+//! [`RequestBuilder`] checks the values supplied by the caller against those
+//! schemas before handing out an [`http::request::Builder`], so that webhook
+//! style integrations stay in line with the specification.
+//!
+//! This module is only available with the `http` feature enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! # use asyncapi::operation_binding::HTTPOperationBinding;
+//! # use asyncapi::http_request::RequestBuilder;
+//! let binding: HTTPOperationBinding = serde_yaml::from_str(
+//!     r#"
+//!     type: request
+//!     method: GET
+//!     query:
+//!       type: object
+//!       required:
+//!         - companyId
+//!       properties:
+//!         companyId:
+//!           type: number
+//!           minimum: 1
+//!       additionalProperties: false
+//!     "#,
+//! )
+//! .unwrap();
+//!
+//! let request = RequestBuilder::new("https://example.com/employees", &binding)
+//!     .query("companyId", 42)
+//!     .build()
+//!     .unwrap()
+//!     .body(())
+//!     .unwrap();
+//!
+//! assert_eq!(request.uri(), "https://example.com/employees?companyId=42");
+//! ```
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::{
+    message_binding::HTTPMessageBinding,
+    operation_binding::HTTPOperationBinding,
+    schema::{AdditionalProperties, SchemaKind, Type},
+    validation::{self, ValidationError},
+    Error, ReferenceOr, Schema,
+};
+
+/// Collects the parts of an HTTP request described by an
+/// [HTTP operation binding][HTTPOperationBinding] and an optional
+/// [HTTP message binding][HTTPMessageBinding].
+#[derive(Debug, Clone)]
+pub struct RequestBuilder<'a> {
+    uri: String,
+    operation: &'a HTTPOperationBinding,
+    message: Option<&'a HTTPMessageBinding>,
+    query: IndexMap<String, Value>,
+    headers: IndexMap<String, String>,
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Starts a request to `uri` for the operation described by `operation`.
+    ///
+    /// Query parameters passed to [`query`][RequestBuilder::query] are
+    /// appended to `uri`, after its query string if it already has one. The
+    /// parameters of that query string are not checked against the binding.
+    pub fn new(uri: impl Into<String>, operation: &'a HTTPOperationBinding) -> Self {
+        RequestBuilder {
+            uri: uri.into(),
+            operation,
+            message: None,
+            query: IndexMap::new(),
+            headers: IndexMap::new(),
+        }
+    }
+
+    /// Sets the message binding the headers are checked against.
+    pub fn message(mut self, message: &'a HTTPMessageBinding) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Adds a query parameter. Arrays are sent as a repeated parameter.
+    pub fn query(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.query.insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds a header.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Checks the collected values against the bindings and returns the
+    /// resulting [`http::request::Builder`].
+    ///
+    /// When the operation binding does not declare a `method`, `GET` is used.
+    /// All violations found are returned in an [`Error::Validate`], their
+    /// paths point into the bindings, e.g. `/query/companyId` or
+    /// `/headers/X-Request-Id`.
+    pub fn build(self) -> Result<http::request::Builder, Error> {
+        let mut errors = Vec::new();
+        if self.operation.typ != "request" {
+            errors.push(ValidationError::new(
+                "/type",
+                format!(
+                    "binding of type `{}` does not describe a request",
+                    self.operation.typ
+                ),
+            ));
+        }
+        let method = match &self.operation.method {
+            Some(method) => {
+                http::Method::from_bytes(method.as_str().as_bytes()).unwrap_or_else(|_| {
+                    errors.push(ValidationError::new(
+                        "/method",
+                        format!("invalid HTTP method `{method}`"),
+                    ));
+                    http::Method::GET
+                })
+            }
+            None => http::Method::GET,
+        };
+
+        if let Some(schema) = &self.operation.query {
+            check_parameters(schema, &self.query, Location::Query, &mut errors);
+        }
+        if let Some(schema) = self.message.and_then(|message| message.headers.as_ref()) {
+            let headers = self
+                .headers
+                .iter()
+                .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+                .collect();
+            check_parameters(schema, &headers, Location::Header, &mut errors);
+        }
+        if !errors.is_empty() {
+            return Err(Error::Validate(errors));
+        }
+
+        let mut uri = self.uri;
+        let mut separator = if uri.contains('?') { '&' } else { '?' };
+        for (name, value) in &self.query {
+            let values = match value {
+                Value::Array(values) => values.iter().collect(),
+                value => vec![value],
+            };
+            for value in values {
+                uri.push(separator);
+                uri.push_str(&percent_encode(name));
+                uri.push('=');
+                uri.push_str(&percent_encode(&to_plain_string(value)));
+                separator = '&';
+            }
+        }
+
+        let mut builder = http::Request::builder().method(method).uri(uri);
+        for (name, value) in &self.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        Ok(builder)
+    }
+}
+
+/// Where a request parameter is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Query,
+    Header,
+}
+
+impl Location {
+    fn path(self, name: &str) -> String {
+        match self {
+            Location::Query => validation::join_pointer("/query", name),
+            Location::Header => validation::join_pointer("/headers", name),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Location::Query => f.write_str("query parameter"),
+            Location::Header => f.write_str("header"),
+        }
+    }
+}
+
+/// Checks `values` against an object schema describing query parameters or headers.
+fn check_parameters(
+    schema: &Schema,
+    values: &IndexMap<String, Value>,
+    location: Location,
+    errors: &mut Vec<ValidationError>,
+) {
+    let (properties, required, additional_properties) = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (
+            &object.properties,
+            &object.required,
+            &object.additional_properties,
+        ),
+        SchemaKind::Any(any) => (&any.properties, &any.required, &any.additional_properties),
+        _ => return,
+    };
+
+    // Header names are case-insensitive.
+    let matches = |key: &str, name: &str| match location {
+        Location::Query => key == name,
+        Location::Header => key.eq_ignore_ascii_case(name),
+    };
+    for name in required {
+        if !values.keys().any(|key| matches(key, name)) {
+            errors.push(ValidationError::new(
+                location.path(name),
+                format!("missing required {location}"),
+            ));
+        }
+    }
+
+    for (name, value) in values {
+        match properties.iter().find(|(key, _)| matches(key, name)) {
+            Some((_, ReferenceOr::Item(property))) => {
+                if let Err(reason) = check_value(property, value, location) {
+                    errors.push(ValidationError::new(
+                        location.path(name),
+                        format!("invalid {location}: {reason}"),
+                    ));
+                }
+            }
+            // References can't be followed here, the value is passed on unchecked.
+            Some((_, ReferenceOr::Reference { .. })) => {}
+            None => {
+                if let Some(AdditionalProperties::Any(false)) = additional_properties {
+                    errors.push(ValidationError::new(
+                        location.path(name),
+                        format!("{location} is not allowed by the binding"),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Checks a single value against the type, enumeration and range constraints of `schema`.
+///
+/// Headers are always sent as strings, so for headers a string that parses as the
+/// expected type is accepted as well.
+fn check_value(schema: &Schema, value: &Value, location: Location) -> Result<(), String> {
+    let ty = match &schema.schema_kind {
+        SchemaKind::Type(ty) => ty,
+        _ => return Ok(()),
+    };
+    let text = value.as_str().filter(|_| location == Location::Header);
+    match ty {
+        Type::String(string) => {
+            let value = value
+                .as_str()
+                .ok_or_else(|| format!("expected a string, found `{value}`"))?;
+            if !string.enumeration.is_empty()
+                && !string
+                    .enumeration
+                    .iter()
+                    .any(|allowed| allowed.as_deref() == Some(value))
+            {
+                return Err(format!("`{value}` is not one of the allowed values"));
+            }
+            let length = value.chars().count();
+            if string.min_length.is_some_and(|min| length < min) {
+                return Err(format!("`{value}` is shorter than allowed"));
+            }
+            if string.max_length.is_some_and(|max| length > max) {
+                return Err(format!("`{value}` is longer than allowed"));
+            }
+        }
+        Type::Number(number) => {
+            let n = value
+                .as_f64()
+                .or_else(|| text.and_then(|text| text.parse().ok()))
+                .ok_or_else(|| format!("expected a number, found `{value}`"))?;
+            if number.minimum.is_some_and(|min| n < min)
+                || number.maximum.is_some_and(|max| n > max)
+                || number.exclusive_minimum.is_some_and(|min| n <= min)
+                || number.exclusive_maximum.is_some_and(|max| n >= max)
+            {
+                return Err(format!("`{n}` is out of range"));
+            }
+            if !number.enumeration.is_empty() && !number.enumeration.contains(&n) {
+                return Err(format!("`{n}` is not one of the allowed values"));
+            }
+        }
+        Type::Integer(integer) => {
+            let n = value
+                .as_i64()
+                .or_else(|| text.and_then(|text| text.parse().ok()))
+                .ok_or_else(|| format!("expected an integer, found `{value}`"))?;
+            if integer.minimum.is_some_and(|min| n < min)
+                || integer.maximum.is_some_and(|max| n > max)
+                || integer.exclusive_minimum.is_some_and(|min| n <= min)
+                || integer.exclusive_maximum.is_some_and(|max| n >= max)
+            {
+                return Err(format!("`{n}` is out of range"));
+            }
+            if !integer.enumeration.is_empty() && !integer.enumeration.contains(&n) {
+                return Err(format!("`{n}` is not one of the allowed values"));
+            }
+        }
+        Type::Boolean {} => {
+            let is_bool =
+                value.is_boolean() || text.is_some_and(|text| text == "true" || text == "false");
+            if !is_bool {
+                return Err(format!("expected a boolean, found `{value}`"));
+            }
+        }
+        Type::Array(array) => {
+            let items = value
+                .as_array()
+                .ok_or_else(|| format!("expected an array, found `{value}`"))?;
+            if let Some(ReferenceOr::Item(item_schema)) = &array.items {
+                for item in items {
+                    check_value(item_schema, item, location)?;
+                }
+            }
+        }
+        Type::Object(_) => return Err("objects can't be sent as a parameter".to_string()),
+    }
+    Ok(())
+}
+
+fn to_plain_string(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Percent-encodes everything except the unreserved characters of RFC 3986.
+fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            byte => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[test]
+fn test_build_request_from_bindings() {
+    let operation: HTTPOperationBinding = serde_yaml::from_str(
+        r#"
+        type: request
+        method: POST
+        query:
+          type: object
+          required:
+            - companyId
+          properties:
+            companyId:
+              type: integer
+              minimum: 1
+            tag:
+              type: string
+          additionalProperties: false
+        "#,
+    )
+    .unwrap();
+    let message: HTTPMessageBinding = serde_yaml::from_str(
+        r#"
+        headers:
+          type: object
+          required:
+            - X-Request-Id
+          properties:
+            X-Request-Id:
+              type: string
+        "#,
+    )
+    .unwrap();
+
+    let request = RequestBuilder::new("https://example.com/employees", &operation)
+        .message(&message)
+        .query("companyId", 7)
+        .query("tag", "a b")
+        .header("x-request-id", "42")
+        .build()
+        .unwrap()
+        .body(())
+        .unwrap();
+    assert_eq!(request.method(), http::Method::POST);
+    assert_eq!(
+        request.uri(),
+        "https://example.com/employees?companyId=7&tag=a%20b"
+    );
+    assert_eq!(request.headers()["x-request-id"], "42");
+
+    let request = RequestBuilder::new("https://example.com/employees?sort=name", &operation)
+        .query("companyId", 7)
+        .build()
+        .unwrap()
+        .body(())
+        .unwrap();
+    assert_eq!(
+        request.uri(),
+        "https://example.com/employees?sort=name&companyId=7"
+    );
+
+    let errors = |result: Result<http::request::Builder, Error>| match result {
+        Err(Error::Validate(errors)) => errors,
+        result => panic!("expected validation errors, got {result:?}"),
+    };
+
+    let missing = RequestBuilder::new("https://example.com/employees", &operation).build();
+    assert_eq!(
+        errors(missing),
+        vec![ValidationError::new(
+            "/query/companyId",
+            "missing required query parameter"
+        )]
+    );
+
+    let out_of_range = RequestBuilder::new("https://example.com/employees", &operation)
+        .query("companyId", 0)
+        .build();
+    assert_eq!(errors(out_of_range)[0].path, "/query/companyId");
+
+    let unknown = RequestBuilder::new("https://example.com/employees", &operation)
+        .query("companyId", 1)
+        .query("page", 2)
+        .build();
+    assert_eq!(errors(unknown)[0].path, "/query/page");
+
+    let missing_header = RequestBuilder::new("https://example.com/employees", &operation)
+        .message(&message)
+        .build();
+    let paths: Vec<_> = errors(missing_header)
+        .into_iter()
+        .map(|error| error.path)
+        .collect();
+    assert_eq!(paths, ["/query/companyId", "/headers/X-Request-Id"]);
+}
@@ -0,0 +1,160 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    validation::{self, Validate, ValidationError},
+    Error,
+};
+
+/// Identifier of the application an AsyncAPI document is defining, either a
+/// [URN](https://tools.ietf.org/html/rfc8141) or another absolute
+/// [URI](https://tools.ietf.org/html/rfc3986).
+///
+/// Any string is accepted when parsing a document, use
+/// [`validate`][Validate::validate] to check its format. In code,
+/// [`str::parse`] creates a validated identifier while
+/// [`Identifier::new`] accepts any string.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::Identifier;
+///
+/// let id = Identifier::urn("com", "smartylighting:streetlights:server");
+/// assert_eq!(id.as_str(), "urn:com:smartylighting:streetlights:server");
+/// assert_eq!(id.urn_namespace(), Some("com"));
+/// assert_eq!(
+///     id.urn_specific_string(),
+///     Some("smartylighting:streetlights:server")
+/// );
+///
+/// let id: Identifier = "https://github.com/smartylighting/streetlights-server"
+///     .parse()
+///     .unwrap();
+/// assert!(!id.is_urn());
+/// assert!("streetlights-server".parse::<Identifier>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Identifier(String);
+
+impl Identifier {
+    pub fn new(id: impl Into<String>) -> Self {
+        Identifier(id.into())
+    }
+
+    /// Creates the URN `urn:{namespace}:{specific_string}`.
+    pub fn urn(namespace: &str, specific_string: &str) -> Self {
+        Identifier(format!("urn:{namespace}:{specific_string}"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the identifier uses the `urn` scheme.
+    pub fn is_urn(&self) -> bool {
+        self.0
+            .get(..4)
+            .is_some_and(|scheme| scheme.eq_ignore_ascii_case("urn:"))
+    }
+
+    /// The namespace identifier of a URN, e.g. `com` for
+    /// `urn:com:smartylighting:streetlights:server`.
+    pub fn urn_namespace(&self) -> Option<&str> {
+        self.urn_parts().map(|(namespace, _)| namespace)
+    }
+
+    /// The namespace specific string of a URN, e.g.
+    /// `smartylighting:streetlights:server` for
+    /// `urn:com:smartylighting:streetlights:server`.
+    pub fn urn_specific_string(&self) -> Option<&str> {
+        self.urn_parts().map(|(_, specific_string)| specific_string)
+    }
+
+    fn urn_parts(&self) -> Option<(&str, &str)> {
+        if !self.is_urn() {
+            return None;
+        }
+        self.0[4..].split_once(':')
+    }
+}
+
+impl Validate for Identifier {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if !self.is_urn() {
+            validation::check_url(path.to_string(), &self.0, errors);
+            return;
+        }
+        // RFC 8141: the namespace identifier is 2 to 32 letters, digits or
+        // hyphens, neither starting nor ending with a hyphen.
+        let valid = self
+            .urn_parts()
+            .is_some_and(|(namespace, specific_string)| {
+                (2..=32).contains(&namespace.len())
+                    && namespace
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+                    && !namespace.starts_with('-')
+                    && !namespace.ends_with('-')
+                    && !specific_string.is_empty()
+                    && !specific_string.chars().any(char::is_whitespace)
+            });
+        if !valid {
+            errors.push(ValidationError::new(
+                path,
+                format!("`{}` is not a valid URN", self.0),
+            ));
+        }
+    }
+}
+
+impl FromStr for Identifier {
+    type Err = Error;
+
+    /// Parses a URN or an absolute URI, rejecting other strings with
+    /// [`Error::Validate`].
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let id = Identifier::new(id);
+        id.validate()?;
+        Ok(id)
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Identifier {
+    fn from(id: String) -> Self {
+        Identifier(id)
+    }
+}
+
+impl From<&str> for Identifier {
+    fn from(id: &str) -> Self {
+        Identifier(id.to_string())
+    }
+}
+
+impl AsRef<str> for Identifier {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[test]
+fn test_validate_identifier() {
+    for valid in [
+        "urn:com:smartylighting:streetlights:server",
+        "https://github.com/smartylighting/streetlights-server",
+    ] {
+        assert!(Identifier::new(valid).validate().is_ok(), "{valid}");
+    }
+    for invalid in ["urn:c:lights", "urn:com:", "streetlights-server"] {
+        assert!(Identifier::new(invalid).validate().is_err(), "{invalid}");
+    }
+}
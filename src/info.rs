@@ -1,6 +1,8 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
+use crate::validation::{self, Validate, ValidationError};
+
 /// The object provides metadata about the API. The metadata can be used by the clients if needed.
 ///
 /// # Examples
@@ -59,7 +61,7 @@ pub struct Info {
     pub license: Option<License>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -95,7 +97,7 @@ pub struct Contact {
     pub email: Option<String>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -124,6 +126,146 @@ pub struct License {
     pub url: Option<String>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
+
+impl Info {
+    /// Creates the metadata of an API called `title` in `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{Contact, Info, License};
+    ///
+    /// let info = Info::new("Account Service", "1.0.0")
+    ///     .with_description("Manages user accounts.")
+    ///     .with_contact(Contact::new("API Support").with_email("support@example.com"))
+    ///     .with_license(License::new("Apache 2.0"));
+    /// assert_eq!(info.title, "Account Service");
+    /// assert_eq!(info.license.unwrap().name, "Apache 2.0");
+    /// ```
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        Info {
+            title: title.into(),
+            version: version.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the [description][Info::description].
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the [terms of service][Info::terms_of_service].
+    pub fn with_terms_of_service(mut self, terms_of_service: impl Into<String>) -> Self {
+        self.terms_of_service = Some(terms_of_service.into());
+        self
+    }
+
+    /// Sets the [contact][Info::contact].
+    pub fn with_contact(mut self, contact: Contact) -> Self {
+        self.contact = Some(contact);
+        self
+    }
+
+    /// Sets the [license][Info::license].
+    pub fn with_license(mut self, license: License) -> Self {
+        self.license = Some(license);
+        self
+    }
+}
+
+impl Contact {
+    /// Creates the contact information of `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Contact {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the [url][Contact::url].
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the [email][Contact::email].
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+}
+
+impl License {
+    /// Creates the license information of the license called `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        License {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the [url][License::url].
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+}
+
+impl Validate for Info {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(terms_of_service) = &self.terms_of_service {
+            validation::check_url(format!("{path}/termsOfService"), terms_of_service, errors);
+        }
+        if let Some(contact) = &self.contact {
+            contact.validate_at(&format!("{path}/contact"), errors);
+        }
+        if let Some(license) = &self.license {
+            license.validate_at(&format!("{path}/license"), errors);
+        }
+    }
+}
+
+impl Validate for Contact {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(url) = &self.url {
+            validation::check_url(format!("{path}/url"), url, errors);
+        }
+        if let Some(email) = &self.email {
+            validation::check_email(format!("{path}/email"), email, errors);
+        }
+    }
+}
+
+impl Validate for License {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(url) = &self.url {
+            validation::check_url(format!("{path}/url"), url, errors);
+        }
+    }
+}
+
+#[test]
+fn test_validate_contact_and_license() {
+    let info: Info = serde_yaml::from_str(
+        r#"
+        title: Account Service
+        version: 1.0.0
+        contact:
+          url: https://www.example.com/support
+          email: support.example.com
+        license:
+          name: Apache 2.0
+          url: LICENSE-2.0.html
+        "#,
+    )
+    .unwrap();
+    let mut errors = Vec::new();
+    info.validate_at("/info", &mut errors);
+    let paths: Vec<_> = errors.iter().map(|error| error.path.as_str()).collect();
+    assert_eq!(paths, ["/info/contact/email", "/info/license/url"]);
+}
@@ -0,0 +1,226 @@
+//! Typed access to NATS JetStream settings kept in the `x-jetstream`
+//! extension of a channel binding.
+//!
+//! The NATS channel binding defines no properties, so documents describe the
+//! stream a channel is stored in and the consumers reading from it in an
+//! extension. The settings use the field names of the JetStream API, so they
+//! can be handed to a NATS client as they are.
+//!
+//! # Examples
+//!
+//! ```
+//! use asyncapi::{
+//!     jetstream::{JetStream, JetStreamRetention, JetStreamStorage},
+//!     ChannelBinding,
+//! };
+//!
+//! let binding: ChannelBinding = serde_yaml::from_str(
+//!     r#"
+//!     nats: {}
+//!     x-jetstream:
+//!       stream:
+//!         name: ORDERS
+//!         subjects: [orders.>]
+//!         retention: workqueue
+//!         storage: file
+//!       consumer:
+//!         durable_name: billing
+//!         ack_policy: explicit
+//!     "#,
+//! )
+//! .unwrap();
+//!
+//! let jetstream = binding.jetstream().unwrap().unwrap();
+//! let stream = jetstream.stream.as_ref().unwrap();
+//! assert_eq!(stream.name.as_deref(), Some("ORDERS"));
+//! assert_eq!(stream.retention, Some(JetStreamRetention::WorkQueue));
+//! assert_eq!(stream.storage, Some(JetStreamStorage::File));
+//!
+//! let mut copy = ChannelBinding::default();
+//! copy.set_jetstream(&jetstream).unwrap();
+//! assert_eq!(copy.jetstream().unwrap(), Some(jetstream));
+//! ```
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::FormatError, ChannelBinding, Error};
+
+/// The extension of a [`ChannelBinding`] holding its [`JetStream`] settings.
+pub const EXTENSION: &str = "x-jetstream";
+
+/// The JetStream settings of a channel.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct JetStream {
+    /// The stream the messages of the channel are stored in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<JetStreamStream>,
+    /// The consumer reading the messages of the channel from the stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer: Option<JetStreamConsumer>,
+}
+
+/// The configuration of a JetStream stream.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct JetStreamStream {
+    /// The name of the stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The subjects stored in the stream, wildcards are allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjects: Vec<String>,
+    /// When messages are removed from the stream.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<JetStreamRetention>,
+    /// Where the messages are stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<JetStreamStorage>,
+    /// How many replicas of the stream the cluster keeps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_replicas: Option<u32>,
+    /// How many messages the stream keeps, `-1` for no limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_msgs: Option<i64>,
+    /// How many bytes the stream keeps, `-1` for no limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<i64>,
+    /// How long messages are kept, in nanoseconds. `0` keeps them forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<u64>,
+    /// Settings of the stream not modelled here.
+    #[serde(flatten)]
+    pub other: IndexMap<String, serde_json::Value>,
+}
+
+/// The retention policy of a [`JetStreamStream`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JetStreamRetention {
+    /// Messages are kept until a limit of the stream is reached.
+    Limits,
+    /// Messages are kept as long as a consumer hasn't acknowledged them.
+    Interest,
+    /// Messages are removed once a consumer acknowledged them.
+    #[serde(rename = "workqueue")]
+    WorkQueue,
+    /// A retention policy not known to this crate.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+/// The storage of a [`JetStreamStream`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JetStreamStorage {
+    File,
+    Memory,
+    /// A storage not known to this crate.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+/// The configuration of a JetStream consumer.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct JetStreamConsumer {
+    /// The name of a durable consumer. Consumers without one are ephemeral.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durable_name: Option<String>,
+    /// Only messages of this subject are delivered to the consumer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_subject: Option<String>,
+    /// Which messages of the stream are delivered first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_policy: Option<JetStreamDeliverPolicy>,
+    /// How messages are acknowledged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ack_policy: Option<JetStreamAckPolicy>,
+    /// How long the server waits for an acknowledgement before delivering a
+    /// message again, in nanoseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ack_wait: Option<u64>,
+    /// How often a message is delivered at most, `-1` for no limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_deliver: Option<i64>,
+    /// Settings of the consumer not modelled here.
+    #[serde(flatten)]
+    pub other: IndexMap<String, serde_json::Value>,
+}
+
+/// The deliver policy of a [`JetStreamConsumer`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JetStreamDeliverPolicy {
+    All,
+    Last,
+    New,
+    ByStartSequence,
+    ByStartTime,
+    LastPerSubject,
+    /// A deliver policy not known to this crate.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+/// The acknowledgement policy of a [`JetStreamConsumer`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JetStreamAckPolicy {
+    /// Messages don't need to be acknowledged.
+    None,
+    /// Acknowledging a message acknowledges all messages before it.
+    All,
+    /// Every message is acknowledged on its own.
+    Explicit,
+    /// An acknowledgement policy not known to this crate.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+impl ChannelBinding {
+    /// The JetStream settings in the [`x-jetstream`][EXTENSION] extension, if
+    /// there are any. Fails if the extension doesn't hold valid settings.
+    pub fn jetstream(&self) -> Result<Option<JetStream>, Error> {
+        self.extensions
+            .get(EXTENSION)
+            .map(JetStream::deserialize)
+            .transpose()
+            .map_err(|error| Error::Parse(FormatError::Json(error)))
+    }
+
+    /// Stores `jetstream` in the [`x-jetstream`][EXTENSION] extension,
+    /// replacing the settings there.
+    pub fn set_jetstream(&mut self, jetstream: &JetStream) -> Result<(), Error> {
+        let value = serde_json::to_value(jetstream)
+            .map_err(|error| Error::Serialize(FormatError::Json(error)))?;
+        self.extensions.insert(EXTENSION.to_string(), value);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_jetstream_keeps_unknown_settings() {
+    let mut binding = ChannelBinding::default();
+    binding.extensions.insert(
+        EXTENSION.to_string(),
+        serde_json::json!({
+            "stream": {"name": "ORDERS", "discard": "new"},
+            "consumer": {"deliver_policy": "by_start_time", "opt_start_time": "2024-01-01T00:00:00Z"},
+        }),
+    );
+    let jetstream = binding.jetstream().unwrap().unwrap();
+    let consumer = jetstream.consumer.as_ref().unwrap();
+    assert_eq!(
+        consumer.deliver_policy,
+        Some(JetStreamDeliverPolicy::ByStartTime)
+    );
+    assert!(consumer.other.contains_key("opt_start_time"));
+
+    let original = binding.extensions[EXTENSION].clone();
+    binding.set_jetstream(&jetstream).unwrap();
+    assert_eq!(binding.extensions[EXTENSION], original);
+
+    binding
+        .extensions
+        .insert(EXTENSION.to_string(), serde_json::json!({"stream": []}));
+    assert!(binding.jetstream().is_err());
+}
@@ -0,0 +1,488 @@
+//! Checks values against JSON schemas, used to validate the examples of
+//! messages against their payload and headers schemas.
+//!
+//! The checker works on the serialized document, so references into the
+//! document can be followed. It covers the keywords that constrain the
+//! structure of a value: `type`, `nullable`, `enum`, `const`, the numeric and
+//! length bounds, `properties`, `required`, `additionalProperties`, `items`,
+//! `uniqueItems`, `allOf`, `anyOf`, `oneOf`, `not` and `$ref`. `pattern`,
+//! `patternProperties` and `format` are not checked and neither are
+//! references to other documents. Instead of being ignored, every place where
+//! one of them would apply is reported as unchecked.
+use serde_json::Value;
+
+use crate::{
+    validation::{join_pointer, ValidationError},
+    MediaType,
+};
+
+/// The essences of the schema formats whose schemas are JSON schemas.
+const JSON_SCHEMA_FORMATS: [&str; 5] = [
+    "application/vnd.aai.asyncapi",
+    "application/vnd.aai.asyncapi+json",
+    "application/vnd.aai.asyncapi+yaml",
+    "application/schema+json",
+    "application/schema+yaml",
+];
+
+/// Returns whether schemas of `schema_format` can be checked, with `None`
+/// standing for the default format.
+pub(crate) fn is_json_schema_format(schema_format: Option<&str>) -> bool {
+    schema_format.is_none_or(|format| {
+        let media_type = MediaType::new(format);
+        JSON_SCHEMA_FORMATS
+            .iter()
+            .any(|essence| media_type.essence().eq_ignore_ascii_case(essence))
+    })
+}
+
+/// Appends every way `instance` at `path` violates `schema` to `errors`,
+/// resolving references against the document `root`. The parts of `schema`
+/// that apply to the instance but can't be checked are appended to
+/// `unchecked`.
+pub(crate) fn check(
+    root: &Value,
+    schema: &Value,
+    instance: &Value,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+    unchecked: &mut Vec<ValidationError>,
+) {
+    let mut checker = Checker {
+        root,
+        references: Vec::new(),
+        unchecked: Vec::new(),
+    };
+    checker.check(schema, instance, path, errors);
+    unchecked.append(&mut checker.unchecked);
+}
+
+struct Checker<'a> {
+    root: &'a Value,
+    /// The references being followed, to stop at cycles that don't consume
+    /// any of the instance.
+    references: Vec<(&'a str, String)>,
+    unchecked: Vec<ValidationError>,
+}
+
+impl<'a> Checker<'a> {
+    fn check(
+        &mut self,
+        schema: &'a Value,
+        instance: &Value,
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                errors.push(ValidationError::new(path, "no value is allowed"));
+                return;
+            }
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(Value::String(reference)) = schema.get("$ref") {
+            let Some(pointer) = reference.strip_prefix('#') else {
+                self.skip(path, format!("`{reference}` points to another document"));
+                return;
+            };
+            let Some(target) = self.root.pointer(pointer) else {
+                self.skip(path, format!("`{reference}` points to nothing"));
+                return;
+            };
+            let key = (reference.as_str(), path.to_string());
+            if self.references.contains(&key) {
+                return;
+            }
+            self.references.push(key);
+            self.check(target, instance, path, errors);
+            self.references.pop();
+            return;
+        }
+
+        if instance.is_null() && schema.get("nullable") == Some(&Value::Bool(true)) {
+            return;
+        }
+        let types: Vec<&str> = match schema.get("type") {
+            Some(Value::String(name)) => vec![name],
+            Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|name| has_type(instance, name)) {
+            errors.push(ValidationError::new(
+                path,
+                format!(
+                    "expected {}, found {}",
+                    types.join(" or "),
+                    type_of(instance)
+                ),
+            ));
+            return;
+        }
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            if !values.contains(instance) {
+                errors.push(ValidationError::new(
+                    path,
+                    "value is not one of the allowed values",
+                ));
+            }
+        }
+        if let Some(value) = schema.get("const") {
+            if value != instance {
+                errors.push(ValidationError::new(path, format!("value is not {value}")));
+            }
+        }
+
+        for keyword in ["pattern", "format"] {
+            if instance.is_string() && schema.contains_key(keyword) {
+                self.skip(path, format!("`{keyword}` is not supported"));
+            }
+        }
+        if instance.is_object() && schema.contains_key("patternProperties") {
+            self.skip(path, "`patternProperties` is not supported".to_string());
+        }
+
+        match instance {
+            Value::Number(number) => {
+                if let Some(number) = number.as_f64() {
+                    check_number(schema, number, path, errors);
+                }
+            }
+            Value::String(string) => {
+                let length = string.chars().count();
+                if let Some(min) = bound(schema, "minLength").filter(|min| length < *min) {
+                    errors.push(ValidationError::new(
+                        path,
+                        format!("expected at least {min} characters, found {length}"),
+                    ));
+                }
+                if let Some(max) = bound(schema, "maxLength").filter(|max| length > *max) {
+                    errors.push(ValidationError::new(
+                        path,
+                        format!("expected at most {max} characters, found {length}"),
+                    ));
+                }
+            }
+            Value::Array(items) => self.check_array(schema, items, path, errors),
+            Value::Object(object) => self.check_object(schema, object, path, errors),
+            Value::Bool(_) | Value::Null => {}
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.check(schema, instance, path, errors);
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("anyOf") {
+            if !schemas
+                .iter()
+                .any(|schema| self.matches(schema, instance, path))
+            {
+                errors.push(ValidationError::new(
+                    path,
+                    "value matches none of the schemas of anyOf",
+                ));
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+            let matching = schemas
+                .iter()
+                .filter(|schema| self.matches(schema, instance, path))
+                .count();
+            if matching != 1 {
+                errors.push(ValidationError::new(
+                    path,
+                    format!("value matches {matching} of the schemas of oneOf, not exactly one"),
+                ));
+            }
+        }
+        if let Some(schema) = schema.get("not") {
+            if self.matches(schema, instance, path) {
+                errors.push(ValidationError::new(
+                    path,
+                    "value matches the schema of not",
+                ));
+            }
+        }
+    }
+
+    /// Records that a part of a schema was not checked at `path`, once.
+    fn skip(&mut self, path: &str, reason: String) {
+        let unchecked = ValidationError::new(path, format!("not checked: {reason}"));
+        if !self.unchecked.contains(&unchecked) {
+            self.unchecked.push(unchecked);
+        }
+    }
+
+    fn matches(&mut self, schema: &'a Value, instance: &Value, path: &str) -> bool {
+        let mut errors = Vec::new();
+        self.check(schema, instance, path, &mut errors);
+        errors.is_empty()
+    }
+
+    fn check_array(
+        &mut self,
+        schema: &'a serde_json::Map<String, Value>,
+        items: &[Value],
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(min) = bound(schema, "minItems").filter(|min| items.len() < *min) {
+            errors.push(ValidationError::new(
+                path,
+                format!("expected at least {min} items, found {}", items.len()),
+            ));
+        }
+        if let Some(max) = bound(schema, "maxItems").filter(|max| items.len() > *max) {
+            errors.push(ValidationError::new(
+                path,
+                format!("expected at most {max} items, found {}", items.len()),
+            ));
+        }
+        if schema.get("uniqueItems") == Some(&Value::Bool(true))
+            && items
+                .iter()
+                .enumerate()
+                .any(|(index, item)| items[..index].contains(item))
+        {
+            errors.push(ValidationError::new(path, "items are not unique"));
+        }
+        match schema.get("items") {
+            Some(Value::Array(schemas)) => {
+                for (index, (schema, item)) in schemas.iter().zip(items).enumerate() {
+                    self.check(schema, item, &format!("{path}/{index}"), errors);
+                }
+                if let Some(additional) = schema.get("additionalItems") {
+                    for (index, item) in items.iter().enumerate().skip(schemas.len()) {
+                        self.check(additional, item, &format!("{path}/{index}"), errors);
+                    }
+                }
+            }
+            Some(schema) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.check(schema, item, &format!("{path}/{index}"), errors);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        schema: &'a serde_json::Map<String, Value>,
+        object: &serde_json::Map<String, Value>,
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(min) = bound(schema, "minProperties").filter(|min| object.len() < *min) {
+            errors.push(ValidationError::new(
+                path,
+                format!("expected at least {min} properties, found {}", object.len()),
+            ));
+        }
+        if let Some(max) = bound(schema, "maxProperties").filter(|max| object.len() > *max) {
+            errors.push(ValidationError::new(
+                path,
+                format!("expected at most {max} properties, found {}", object.len()),
+            ));
+        }
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    errors.push(ValidationError::new(
+                        path,
+                        format!("property `{name}` is required"),
+                    ));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        // Properties matching `patternProperties` can't be told apart without
+        // regular expressions, so `additionalProperties` is skipped then.
+        let additional = schema
+            .get("additionalProperties")
+            .filter(|_| !schema.contains_key("patternProperties"));
+        for (name, value) in object {
+            let path = join_pointer(path, name);
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(schema) => self.check(schema, value, &path, errors),
+                None => match additional {
+                    Some(Value::Bool(false)) => errors.push(ValidationError::new(
+                        path,
+                        format!("property `{name}` is not allowed"),
+                    )),
+                    Some(schema) => self.check(schema, value, &path, errors),
+                    None => {}
+                },
+            }
+        }
+    }
+}
+
+fn check_number(
+    schema: &serde_json::Map<String, Value>,
+    number: f64,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    let limit = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+    // Before draft 6 `exclusiveMinimum` and `exclusiveMaximum` were flags
+    // turning `minimum` and `maximum` exclusive.
+    let flag = |keyword: &str| schema.get(keyword) == Some(&Value::Bool(true));
+    if let Some(minimum) = limit("minimum") {
+        if number < minimum || (flag("exclusiveMinimum") && number == minimum) {
+            errors.push(ValidationError::new(
+                path,
+                format!("{number} is less than the minimum of {minimum}"),
+            ));
+        }
+    }
+    if let Some(maximum) = limit("maximum") {
+        if number > maximum || (flag("exclusiveMaximum") && number == maximum) {
+            errors.push(ValidationError::new(
+                path,
+                format!("{number} is greater than the maximum of {maximum}"),
+            ));
+        }
+    }
+    if let Some(minimum) = limit("exclusiveMinimum").filter(|minimum| number <= *minimum) {
+        errors.push(ValidationError::new(
+            path,
+            format!("{number} is not greater than {minimum}"),
+        ));
+    }
+    if let Some(maximum) = limit("exclusiveMaximum").filter(|maximum| number >= *maximum) {
+        errors.push(ValidationError::new(
+            path,
+            format!("{number} is not less than {maximum}"),
+        ));
+    }
+    if let Some(divisor) = limit("multipleOf").filter(|divisor| *divisor > 0.0) {
+        let quotient = number / divisor;
+        if (quotient - quotient.round()).abs() > 1e-9 {
+            errors.push(ValidationError::new(
+                path,
+                format!("{number} is not a multiple of {divisor}"),
+            ));
+        }
+    }
+}
+
+fn bound(schema: &serde_json::Map<String, Value>, keyword: &str) -> Option<usize> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_u64)
+        .and_then(|bound| usize::try_from(bound).ok())
+}
+
+fn has_type(instance: &Value, name: &str) -> bool {
+    match name {
+        "integer" => match instance {
+            Value::Number(number) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|n| n.fract() == 0.0)
+            }
+            _ => false,
+        },
+        name => type_of(instance) == name,
+    }
+}
+
+fn type_of(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[test]
+fn test_check() {
+    let root = serde_json::json!({
+        "components": {"schemas": {"Id": {"type": "integer", "minimum": 1}}}
+    });
+    let schema = serde_json::json!({
+        "type": "object",
+        "required": ["id", "name"],
+        "additionalProperties": false,
+        "properties": {
+            "id": {"$ref": "#/components/schemas/Id"},
+            "name": {"type": "string", "maxLength": 3},
+            "tags": {"type": "array", "items": {"enum": ["a", "b"]}, "uniqueItems": true},
+            "nickname": {"type": "string", "nullable": true},
+            "email": {"type": "string", "format": "email"},
+            "avatar": {"$ref": "images.yaml#/Avatar"},
+            "manager": {"$ref": "#/components/schemas/User"},
+            "kind": {"oneOf": [{"const": "user"}, {"type": "string", "minLength": 2}]},
+        }
+    });
+    let instance = serde_json::json!({
+        "id": 0,
+        "name": "Alice",
+        "tags": ["a", "c", "a"],
+        "nickname": null,
+        "email": "alice",
+        "avatar": {},
+        "manager": {},
+        "kind": "user",
+        "age": 30,
+    });
+    let mut errors = Vec::new();
+    let mut unchecked = Vec::new();
+    check(
+        &root,
+        &schema,
+        &instance,
+        "/payload",
+        &mut errors,
+        &mut unchecked,
+    );
+    assert_eq!(
+        errors,
+        [
+            ValidationError::new("/payload/id", "0 is less than the minimum of 1"),
+            ValidationError::new("/payload/name", "expected at most 3 characters, found 5"),
+            ValidationError::new("/payload/tags", "items are not unique"),
+            ValidationError::new("/payload/tags/1", "value is not one of the allowed values"),
+            ValidationError::new(
+                "/payload/kind",
+                "value matches 2 of the schemas of oneOf, not exactly one"
+            ),
+            ValidationError::new("/payload/age", "property `age` is not allowed"),
+        ]
+    );
+    assert_eq!(
+        unchecked,
+        [
+            ValidationError::new("/payload/email", "not checked: `format` is not supported"),
+            ValidationError::new(
+                "/payload/avatar",
+                "not checked: `images.yaml#/Avatar` points to another document"
+            ),
+            ValidationError::new(
+                "/payload/manager",
+                "not checked: `#/components/schemas/User` points to nothing"
+            ),
+        ]
+    );
+
+    let mut errors = Vec::new();
+    check(
+        &root,
+        &schema,
+        &serde_json::json!([]),
+        "",
+        &mut errors,
+        &mut unchecked,
+    );
+    assert_eq!(
+        errors,
+        [ValidationError::new("", "expected object, found array")]
+    );
+}
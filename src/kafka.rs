@@ -0,0 +1,321 @@
+//! The Kafka configuration of an operation, merged from the bindings of all
+//! levels of a document.
+//!
+//! Kafka bindings are spread over the document: the server binding names the
+//! schema registry, the channel binding describes the topic, the operation
+//! binding the consumer group and the message bindings the keys.
+//! [`AsyncAPI::kafka_config_for`] collects them for one operation, following
+//! references into the [Components Object][crate::Components].
+//!
+//! # Examples
+//!
+//! ```
+//! use asyncapi::{schema::StringOrSchema, AsyncAPI, OperationKind};
+//!
+//! let api: AsyncAPI = r#"
+//! asyncapi: 2.6.0
+//! info:
+//!   title: Account Service
+//!   version: 1.0.0
+//! servers:
+//!   production:
+//!     url: broker.example.com:9092
+//!     protocol: kafka
+//!     bindings:
+//!       kafka:
+//!         schemaRegistryUrl: https://registry.example.com
+//! channels:
+//!   user.signedup:
+//!     bindings:
+//!       $ref: '#/components/channelBindings/userTopic'
+//!     subscribe:
+//!       bindings:
+//!         kafka:
+//!           groupId: accounts
+//!       message:
+//!         name: UserSignedUp
+//!         bindings:
+//!           kafka:
+//!             key:
+//!               type: string
+//! components:
+//!   channelBindings:
+//!     userTopic:
+//!       kafka:
+//!         topic: users
+//!         partitions: 12
+//! "#
+//! .parse()
+//! .unwrap();
+//!
+//! let config = api
+//!     .kafka_config_for("user.signedup", OperationKind::Subscribe)
+//!     .unwrap();
+//! assert_eq!(config.topic, "users");
+//! assert_eq!(config.partitions, Some(12));
+//! assert_eq!(
+//!     config.group_id,
+//!     Some(StringOrSchema::String("accounts".to_string()))
+//! );
+//! assert_eq!(
+//!     config.servers["production"].schema_registry_url.as_deref(),
+//!     Some("https://registry.example.com")
+//! );
+//! assert!(config.messages[0].key.is_some());
+//! ```
+
+use indexmap::IndexMap;
+
+use crate::{
+    channel_binding::KafkaChannelBindingTopicConfiguration, message_binding::KafkaMessageBinding,
+    reference::resolve_local, schema::StringOrSchema, server_binding::KafkaServerBinding,
+    validation::join_pointer, AsyncAPI, Error, OperationBinding, OperationKind, ResolveError,
+};
+
+/// The Kafka bindings that apply to an operation, see
+/// [`AsyncAPI::kafka_config_for`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KafkaConfig {
+    /// The Kafka bindings of the servers the channel is available on, by
+    /// server name. Servers without a Kafka binding are left out.
+    pub servers: IndexMap<String, KafkaServerBinding>,
+    /// The topic of the channel binding, or the channel name if it doesn't
+    /// name one.
+    pub topic: String,
+    /// The number of partitions of the topic.
+    pub partitions: Option<u32>,
+    /// The number of replicas of the topic.
+    pub replicas: Option<u32>,
+    /// The topic configuration properties.
+    pub topic_configuration: Option<KafkaChannelBindingTopicConfiguration>,
+    /// The consumer group, from the last trait of the operation that has one
+    /// or else from the operation binding. Traits are merged into the
+    /// operation in order, so later traits override earlier ones and the
+    /// operation itself.
+    pub group_id: Option<StringOrSchema>,
+    /// The consumer inside the group, looked up like
+    /// [group_id][KafkaConfig::group_id].
+    pub client_id: Option<StringOrSchema>,
+    /// The Kafka bindings of the messages of the operation, in the order of
+    /// the messages, with the bindings of their traits merged in like those of
+    /// the operation traits. Messages without a Kafka binding are left out.
+    pub messages: Vec<KafkaMessageBinding>,
+}
+
+impl AsyncAPI {
+    /// Merges the Kafka bindings that apply to the `kind` operation of the
+    /// channel called `channel`.
+    ///
+    /// The servers are the ones listed by the channel, or all servers if it
+    /// doesn't list any. Fails if the channel or operation doesn't exist or a
+    /// reference on the way can't be resolved.
+    pub fn kafka_config_for(
+        &self,
+        channel: &str,
+        kind: OperationKind,
+    ) -> Result<KafkaConfig, Error> {
+        let components = self.components.as_ref();
+        let channel_path = join_pointer("#/channels", channel);
+        let channel_object = self
+            .channels
+            .get(channel)
+            .ok_or_else(|| ResolveError::NotFound(channel_path.clone()))?;
+        let operation = channel_object
+            .operation(kind)
+            .ok_or_else(|| ResolveError::NotFound(format!("{channel_path}/{}", kind.as_str())))?;
+
+        let mut config = KafkaConfig {
+            topic: channel.to_string(),
+            ..Default::default()
+        };
+
+        let server_names: Vec<&String> = if channel_object.servers.is_empty() {
+            self.servers.keys().collect()
+        } else {
+            channel_object.servers.iter().collect()
+        };
+        for name in server_names {
+            let server = self
+                .servers
+                .get(name)
+                .ok_or_else(|| ResolveError::NotFound(join_pointer("#/servers", name)))?;
+            let server = resolve_local(
+                components.map(|components| &components.servers),
+                "#/components/servers/",
+                server,
+            )?;
+            if let Some(bindings) = &server.bindings {
+                let bindings = resolve_local(
+                    components.map(|components| &components.server_bindings),
+                    "#/components/serverBindings/",
+                    bindings,
+                )?;
+                if let Some(kafka) = &bindings.kafka {
+                    config.servers.insert(name.clone(), kafka.clone());
+                }
+            }
+        }
+
+        if let Some(bindings) = &channel_object.bindings {
+            let bindings = resolve_local(
+                components.map(|components| &components.channel_bindings),
+                "#/components/channelBindings/",
+                bindings,
+            )?;
+            if let Some(kafka) = &bindings.kafka {
+                if let Some(topic) = &kafka.topic {
+                    config.topic = topic.clone();
+                }
+                config.partitions = kafka.partitions;
+                config.replicas = kafka.replicas;
+                config.topic_configuration = kafka.topic_configuration.clone();
+            }
+        }
+
+        // Traits are merged into the operation in order, so the last binding
+        // that sets a field wins.
+        let mut operation_bindings = vec![&operation.bindings];
+        for operation_trait in &operation.traits {
+            let operation_trait = resolve_local(
+                components.map(|components| &components.operation_traits),
+                "#/components/operationTraits/",
+                operation_trait,
+            )?;
+            operation_bindings.push(&operation_trait.bindings);
+        }
+        for bindings in operation_bindings.into_iter().flatten() {
+            let bindings: &OperationBinding = resolve_local(
+                components.map(|components| &components.operation_bindings),
+                "#/components/operationBindings/",
+                bindings,
+            )?;
+            if let Some(kafka) = &bindings.kafka {
+                if let Some(group_id) = &kafka.group_id {
+                    config.group_id = Some(group_id.clone());
+                }
+                if let Some(client_id) = &kafka.client_id {
+                    config.client_id = Some(client_id.clone());
+                }
+            }
+        }
+
+        for message in operation.messages() {
+            let message = resolve_local(
+                components.map(|components| &components.messages),
+                "#/components/messages/",
+                message,
+            )?;
+            let mut message_bindings = vec![&message.bindings];
+            for message_trait in &message.traits {
+                let message_trait = resolve_local(
+                    components.map(|components| &components.message_traits),
+                    "#/components/messageTraits/",
+                    message_trait,
+                )?;
+                message_bindings.push(&message_trait.bindings);
+            }
+            let mut merged: Option<KafkaMessageBinding> = None;
+            for bindings in message_bindings.into_iter().flatten() {
+                let bindings = resolve_local(
+                    components.map(|components| &components.message_bindings),
+                    "#/components/messageBindings/",
+                    bindings,
+                )?;
+                if let Some(kafka) = &bindings.kafka {
+                    match &mut merged {
+                        Some(merged) => merge_message_binding(merged, kafka),
+                        None => merged = Some(kafka.clone()),
+                    }
+                }
+            }
+            config.messages.extend(merged);
+        }
+        Ok(config)
+    }
+}
+
+/// Overrides the fields of `binding` that `patch` sets.
+fn merge_message_binding(binding: &mut KafkaMessageBinding, patch: &KafkaMessageBinding) {
+    if patch.key.is_some() {
+        binding.key = patch.key.clone();
+    }
+    if patch.schema_id_location.is_some() {
+        binding.schema_id_location = patch.schema_id_location.clone();
+    }
+    if patch.schema_id_payload_encoding.is_some() {
+        binding.schema_id_payload_encoding = patch.schema_id_payload_encoding.clone();
+    }
+    if patch.schema_lookup_strategy.is_some() {
+        binding.schema_lookup_strategy = patch.schema_lookup_strategy.clone();
+    }
+    if !patch.binding_version.is_latest() {
+        binding.binding_version = patch.binding_version.clone();
+    }
+}
+
+#[test]
+fn test_kafka_config_from_traits() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user.signedup:
+        publish:
+          traits:
+            - $ref: '#/components/operationTraits/consumer'
+            - bindings:
+                kafka:
+                  groupId: accounts
+          bindings:
+            kafka:
+              groupId: overridden
+          message:
+            bindings:
+              kafka:
+                schemaIdLocation: header
+            traits:
+              - $ref: '#/components/messageTraits/keyed'
+    components:
+      messageTraits:
+        keyed:
+          bindings:
+            kafka:
+              key:
+                type: string
+              schemaIdLocation: payload
+      operationTraits:
+        consumer:
+          bindings:
+            kafka:
+              groupId: overridden-as-well
+              clientId: accounts-1
+    "#
+    .parse()
+    .unwrap();
+
+    let config = api
+        .kafka_config_for("user.signedup", OperationKind::Publish)
+        .unwrap();
+    assert_eq!(config.topic, "user.signedup");
+    assert_eq!(
+        config.group_id,
+        Some(StringOrSchema::String("accounts".to_string()))
+    );
+    assert_eq!(
+        config.client_id,
+        Some(StringOrSchema::String("accounts-1".to_string()))
+    );
+    assert_eq!(config.messages.len(), 1);
+    assert!(config.messages[0].key.is_some());
+    assert_eq!(
+        config.messages[0].schema_id_location.as_deref(),
+        Some("payload")
+    );
+    assert!(config.servers.is_empty());
+    assert!(matches!(
+        api.kafka_config_for("user.signedup", OperationKind::Subscribe),
+        Err(Error::Resolve(ResolveError::NotFound(path))) if path == "#/channels/user.signedup/subscribe"
+    ));
+}
@@ -1,43 +1,106 @@
+mod any_api;
 mod api;
+mod binding_builder;
+mod binding_version;
 mod channel;
 pub mod channel_binding;
 mod components;
+pub mod convert;
 mod correlation_id;
+pub mod diff;
+pub mod docgen;
+pub mod dot;
+mod error;
 mod example;
 mod external_documentation;
+#[cfg(feature = "test-util")]
+pub mod fixtures;
+#[cfg(feature = "http")]
+pub mod http_request;
+mod identifier;
 mod info;
+#[cfg(feature = "jetstream")]
+pub mod jetstream;
+#[cfg(feature = "jsonschema")]
+mod json_schema;
+pub mod kafka;
+pub mod lint;
+pub mod loader;
+#[cfg(feature = "macros")]
+mod macros;
+mod media_type;
+pub mod mermaid;
 mod message;
 pub mod message_binding;
 mod message_trait;
+mod node;
 pub mod operation_binding;
 mod operation_trait;
 mod parameter;
+mod parse;
 mod reference;
+mod runtime_expression;
+pub mod sanitize;
 pub mod schema;
 mod security_scheme;
 mod server;
 pub mod server_binding;
+mod spec_version;
 mod tag;
+pub mod v3;
+pub mod validation;
 mod variant_or;
+mod workspace;
 
-pub use api::AsyncAPI;
-pub use channel::{Channel, Operation};
+pub use any_api::AnyAsyncApi;
+pub use api::{AsyncAPI, AsyncAPIBuilder};
+#[cfg(feature = "derive")]
+pub use asyncapi_derive::AsyncApiMessage;
+pub use binding_version::BindingVersion;
+pub use channel::{
+    Channel, ChannelBuilder, Operation, OperationBuilder, OperationKind, OperationMessageType,
+};
 pub use channel_binding::ChannelBinding;
-pub use components::Components;
+pub use components::{Component, ComponentKind, Components};
 pub use correlation_id::CorrelationId;
+pub use error::{Error, FormatError, ResolveError};
 pub use example::Example;
 pub use external_documentation::ExternalDocumentation;
+pub use identifier::Identifier;
 pub use info::{Contact, Info, License};
-pub use message::Message;
+pub use media_type::MediaType;
+pub use message::{AsyncApiMessage, Message, MessageBuilder, MultiFormatSchema, Payload};
 pub use message_binding::MessageBinding;
 pub use message_trait::MessageTrait;
+pub use node::Node;
 pub use operation_binding::OperationBinding;
 pub use operation_trait::OperationTrait;
 pub use parameter::Parameter;
+pub use parse::ParseOptions;
 pub use reference::ReferenceOr;
+pub use runtime_expression::{MessagePart, RuntimeExpression};
 pub use schema::Schema;
-pub use security_scheme::SecurityScheme;
-pub use server::{SecurityRequirement, Server, ServerVariable};
+pub use security_scheme::{
+    ApiKeyLocation, HttpApiKeyLocation, OAuthFlowAuthorizationCode, OAuthFlowClientCredentials,
+    OAuthFlowImplicit, OAuthFlowPassword, OAuthFlows, SecurityScheme,
+};
+pub use server::{SecurityRequirement, SecuritySchemeRequirement, Server, ServerVariable};
 pub use server_binding::ServerBinding;
+pub use spec_version::SpecVersion;
 pub use tag::Tag;
 pub use variant_or::{VariantOrUnknown, VariantOrUnknownOrEmpty};
+pub use workspace::Workspace;
+
+// Lets the code generated by the derive macros name this crate from inside it.
+#[cfg(feature = "derive")]
+extern crate self as asyncapi;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    //! Used by the code the derive macros generate, not part of the public API.
+
+    pub fn schema(json: &str) -> crate::Schema {
+        serde_json::from_str(json).expect("derived schemas are valid")
+    }
+}
@@ -0,0 +1,535 @@
+//! Lint rules that check a document for style and common mistakes, beyond
+//! what the specification requires.
+//!
+//! A [`Linter`] runs a set of [`Rule`]s over a document and returns what they
+//! found as [`Diagnostic`]s. [`Linter::default`] runs the [default
+//! rules][default_rules], which are modelled after the AsyncAPI ruleset of
+//! Spectral. Rules can be left out by name and custom rules added by
+//! implementing [`Rule`].
+//!
+//! Every diagnostic has a [`Severity`], the [default
+//! severity][Rule::severity] of its rule unless the linter is configured to
+//! use another one with [`Linter::with_severity`]. CI jobs can fail on
+//! errors and only report warnings and hints.
+//!
+//! # Examples
+//!
+//! ```
+//! use asyncapi::{
+//!     lint::{Linter, Rule, Severity},
+//!     validation::ValidationError,
+//!     AsyncAPI,
+//! };
+//!
+//! /// Channels must be named in lowercase.
+//! struct LowercaseChannels;
+//!
+//! impl Rule for LowercaseChannels {
+//!     fn name(&self) -> &'static str {
+//!         "channel-lowercase"
+//!     }
+//!
+//!     fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+//!         for name in api.channels.keys() {
+//!             if name.to_lowercase() != *name {
+//!                 problems.push(ValidationError::new(
+//!                     asyncapi::validation::join_pointer("/channels", name),
+//!                     "channel names should be lowercase",
+//!                 ));
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! let api: AsyncAPI = r#"
+//! asyncapi: 2.6.0
+//! info:
+//!   title: Account Service
+//!   version: 1.0.0
+//! channels:
+//!   user/signedUp:
+//!     subscribe:
+//!       operationId: onUserSignedUp
+//!       description: Sent when a user signed up.
+//!       message:
+//!         payload:
+//!           type: object
+//! "#
+//! .parse()
+//! .unwrap();
+//!
+//! let linter = Linter::default()
+//!     .without_rule("info-contact")
+//!     .with_rule(LowercaseChannels)
+//!     .with_severity("channel-lowercase", Severity::Error);
+//! let diagnostics = linter.lint(&api);
+//! let rules: Vec<&str> = diagnostics.iter().map(|diagnostic| diagnostic.rule).collect();
+//! assert_eq!(rules, ["info-description", "channel-lowercase"]);
+//! assert_eq!(diagnostics[0].severity, Severity::Hint);
+//! assert_eq!(diagnostics[1].severity, Severity::Error);
+//! assert_eq!(diagnostics[1].path, "/channels/user~1signedUp");
+//! ```
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    validation::{self, Validate, ValidationError},
+    AsyncAPI,
+};
+
+/// How serious a [`Diagnostic`] is, ordered from the most to the least
+/// serious.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The document is invalid or likely broken for its users.
+    Error,
+    /// The document works, but probably not as intended.
+    Warning,
+    /// The document could be improved, e.g. documented better.
+    Hint,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Hint => "hint",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A problem a [`Rule`] found in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// The [name][Rule::name] of the rule that found the problem.
+    pub rule: &'static str,
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// [JSON Pointer](https://tools.ietf.org/html/rfc6901) to the offending
+    /// value.
+    pub path: String,
+    /// Describes the problem.
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(
+            f,
+            "{}: {path}: {} ({})",
+            self.severity, self.message, self.rule
+        )
+    }
+}
+
+/// A check of a document, run by a [`Linter`].
+pub trait Rule {
+    /// The name of the rule in kebab case, e.g. `info-contact`. It is used to
+    /// leave the rule out, see [`Linter::without_rule`].
+    fn name(&self) -> &'static str;
+
+    /// The severity of the problems the rule finds, unless the [`Linter`] is
+    /// configured otherwise.
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Appends every problem found in `api` to `problems`.
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>);
+}
+
+/// Runs [`Rule`]s over documents.
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    severities: HashMap<String, Severity>,
+}
+
+impl Linter {
+    /// Creates a linter without any rules, to be added with
+    /// [`with_rule`][Linter::with_rule]. [`Linter::default`] runs the
+    /// [default rules][default_rules] instead.
+    pub fn empty() -> Self {
+        Linter {
+            rules: Vec::new(),
+            severities: HashMap::new(),
+        }
+    }
+
+    /// Adds `rule`, which runs after the rules added before.
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Leaves out the rule called `name`.
+    pub fn without_rule(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        self
+    }
+
+    /// Reports the problems the rule called `name` finds as `severity`,
+    /// instead of the [severity of the rule][Rule::severity].
+    pub fn with_severity(mut self, name: impl Into<String>, severity: Severity) -> Self {
+        self.severities.insert(name.into(), severity);
+        self
+    }
+
+    /// The severity the problems of `rule` are reported as.
+    fn severity(&self, rule: &dyn Rule) -> Severity {
+        self.severities
+            .get(rule.name())
+            .copied()
+            .unwrap_or_else(|| rule.severity())
+    }
+
+    /// The names of the rules, in the order they run.
+    pub fn rules(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Runs every rule over `api` and returns the problems found, ordered by
+    /// rule.
+    pub fn lint(&self, api: &AsyncAPI) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for rule in &self.rules {
+            let mut problems = Vec::new();
+            rule.check(api, &mut problems);
+            let severity = self.severity(rule.as_ref());
+            diagnostics.extend(problems.into_iter().map(|problem| Diagnostic {
+                rule: rule.name(),
+                severity,
+                path: problem.path,
+                message: problem.message,
+            }));
+        }
+        diagnostics
+    }
+}
+
+impl Default for Linter {
+    /// Creates a linter running the [default rules][default_rules].
+    fn default() -> Self {
+        Linter {
+            rules: default_rules(),
+            severities: HashMap::new(),
+        }
+    }
+}
+
+impl fmt::Debug for Linter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Linter")
+            .field("rules", &self.rules())
+            .field("severities", &self.severities)
+            .finish()
+    }
+}
+
+/// The rules [`Linter::default`] runs, in this order and with these
+/// severities:
+///
+/// - [`Valid`], `valid`, error
+/// - [`InfoContact`], `info-contact`, hint
+/// - [`InfoDescription`], `info-description`, hint
+/// - [`OperationId`], `operation-operation-id`, warning
+/// - [`OperationDescription`], `operation-description`, hint
+/// - [`ChannelNoTrailingSlash`], `channel-no-trailing-slash`, warning
+/// - [`ChannelNoQueryNorFragment`], `channel-no-query-nor-fragment`, error
+/// - [`ServerProtocol`], `server-protocol`, warning
+/// - [`UnusedComponent`], `unused-component`, warning
+pub fn default_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(Valid),
+        Box::new(InfoContact),
+        Box::new(InfoDescription),
+        Box::new(OperationId),
+        Box::new(OperationDescription),
+        Box::new(ChannelNoTrailingSlash),
+        Box::new(ChannelNoQueryNorFragment),
+        Box::new(ServerProtocol),
+        Box::new(UnusedComponent),
+    ]
+}
+
+/// The document follows the rules of the specification, see [`Validate`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Valid;
+
+impl Rule for Valid {
+    fn name(&self) -> &'static str {
+        "valid"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        api.validate_at("", problems);
+    }
+}
+
+/// The info names a contact.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InfoContact;
+
+impl Rule for InfoContact {
+    fn name(&self) -> &'static str {
+        "info-contact"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Hint
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        if api.info.contact.is_none() {
+            problems.push(ValidationError::new("/info", "info should have a contact"));
+        }
+    }
+}
+
+/// The info has a description.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InfoDescription;
+
+impl Rule for InfoDescription {
+    fn name(&self) -> &'static str {
+        "info-description"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Hint
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        if is_blank(&api.info.description) {
+            problems.push(ValidationError::new(
+                "/info",
+                "info should have a description",
+            ));
+        }
+    }
+}
+
+/// Every operation has an `operationId`, which code generators name
+/// functions after.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OperationId;
+
+impl Rule for OperationId {
+    fn name(&self) -> &'static str {
+        "operation-operation-id"
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        for (channel, kind, operation) in api.operations() {
+            if is_blank(&operation.operation_id) {
+                problems.push(ValidationError::new(
+                    operation_path(channel, kind.as_str()),
+                    "operation should have an operationId",
+                ));
+            }
+        }
+    }
+}
+
+/// Every operation has a summary or description.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OperationDescription;
+
+impl Rule for OperationDescription {
+    fn name(&self) -> &'static str {
+        "operation-description"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Hint
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        for (channel, kind, operation) in api.operations() {
+            if is_blank(&operation.summary) && is_blank(&operation.description) {
+                problems.push(ValidationError::new(
+                    operation_path(channel, kind.as_str()),
+                    "operation should have a summary or description",
+                ));
+            }
+        }
+    }
+}
+
+/// Channel names don't end with a `/`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChannelNoTrailingSlash;
+
+impl Rule for ChannelNoTrailingSlash {
+    fn name(&self) -> &'static str {
+        "channel-no-trailing-slash"
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        for name in api.channels.keys() {
+            if name.len() > 1 && name.ends_with('/') {
+                problems.push(ValidationError::new(
+                    validation::join_pointer("/channels", name),
+                    "channel name should not end with a slash",
+                ));
+            }
+        }
+    }
+}
+
+/// Channel names contain neither a query nor a fragment, which the
+/// specification leaves to bindings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChannelNoQueryNorFragment;
+
+impl Rule for ChannelNoQueryNorFragment {
+    fn name(&self) -> &'static str {
+        "channel-no-query-nor-fragment"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        for name in api.channels.keys() {
+            if name.contains(['?', '#']) {
+                problems.push(ValidationError::new(
+                    validation::join_pointer("/channels", name),
+                    "channel name should not contain a query or fragment",
+                ));
+            }
+        }
+    }
+}
+
+/// Servers use one of the [known protocols][crate::Server::KNOWN_PROTOCOLS],
+/// see [`AsyncAPI::protocol_warnings`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerProtocol;
+
+impl Rule for ServerProtocol {
+    fn name(&self) -> &'static str {
+        "server-protocol"
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        problems.extend(api.protocol_warnings());
+    }
+}
+
+/// Every component is used by the rest of the document, see
+/// [`AsyncAPI::prune_unused_components`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnusedComponent;
+
+impl Rule for UnusedComponent {
+    fn name(&self) -> &'static str {
+        "unused-component"
+    }
+
+    fn check(&self, api: &AsyncAPI, problems: &mut Vec<ValidationError>) {
+        let Ok(unused) = api.clone().prune_unused_components() else {
+            return;
+        };
+        for reference in unused {
+            problems.push(ValidationError::new(
+                reference.trim_start_matches('#'),
+                "component is not used",
+            ));
+        }
+    }
+}
+
+fn is_blank(value: &Option<String>) -> bool {
+    value.as_deref().is_none_or(|value| value.trim().is_empty())
+}
+
+fn operation_path(channel: &str, kind: &str) -> String {
+    format!("{}/{kind}", validation::join_pointer("/channels", channel))
+}
+
+#[test]
+fn test_default_rules() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+      description: Manages user accounts.
+      contact:
+        name: Accounts team
+    servers:
+      production:
+        url: broker.example.com:9092
+        protocol: kafak
+    channels:
+      user/signedup/:
+        subscribe:
+          summary: A user signed up.
+          message:
+            $ref: '#/components/messages/UserSignedUp'
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            type: object
+        UserDeleted:
+          payload:
+            type: object
+    "#
+    .parse()
+    .unwrap();
+
+    let diagnostics: Vec<String> = Linter::default()
+        .lint(&api)
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect();
+    assert_eq!(
+        diagnostics,
+        [
+            "warning: /channels/user~1signedup~1/subscribe: operation should have an operationId (operation-operation-id)",
+            "warning: /channels/user~1signedup~1: channel name should not end with a slash (channel-no-trailing-slash)",
+            "warning: /servers/production/protocol: `kafak` is not a known protocol (server-protocol)",
+            "warning: /components/messages/UserDeleted: component is not used (unused-component)",
+        ]
+    );
+    assert!(Linter::empty().lint(&api).is_empty());
+    assert_eq!(Linter::empty().with_rule(Valid).rules(), ["valid"]);
+
+    let severities: Vec<Severity> = Linter::default()
+        .with_severity("server-protocol", Severity::Error)
+        .with_severity("unused-component", Severity::Hint)
+        .lint(&api)
+        .iter()
+        .map(|diagnostic| diagnostic.severity)
+        .collect();
+    assert_eq!(
+        severities,
+        [
+            Severity::Warning,
+            Severity::Warning,
+            Severity::Error,
+            Severity::Hint
+        ]
+    );
+    assert!(!Linter::default()
+        .without_rule("unused-component")
+        .rules()
+        .contains(&"unused-component"));
+}
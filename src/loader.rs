@@ -0,0 +1,189 @@
+//! Loading of referenced documents from where they are stored.
+//!
+//! A [`RefLoader`] fetches the raw contents of a document by its URI,
+//! [`Workspace::load`][crate::Workspace::load] uses it to collect a document
+//! together with all documents it references. [`FileLoader`] reads from the
+//! file system and, with the `reqwest` or `http-refs` feature enabled, [`HttpLoader`] fetches
+//! documents over HTTP. Other storage, like an object store, can be supported
+//! by implementing [`RefLoader`].
+#[cfg(feature = "reqwest")]
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use std::{
+    future::Future,
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::Error;
+
+/// Fetches documents by their URI.
+///
+/// Implementations can use `async fn`:
+///
+/// ```
+/// use asyncapi::{loader::RefLoader, Error};
+///
+/// struct Static;
+///
+/// impl RefLoader for Static {
+///     async fn load(&self, uri: &str) -> Result<Vec<u8>, Error> {
+///         Ok(format!("title: {uri}").into_bytes())
+///     }
+/// }
+/// ```
+pub trait RefLoader {
+    /// Returns the contents of the document at `uri`.
+    fn load(&self, uri: &str) -> impl Future<Output = Result<Vec<u8>, Error>> + Send;
+}
+
+/// Loads documents from a directory of the file system.
+///
+/// URIs are resolved relative to the root directory, a `file://` prefix is
+/// ignored. URIs that are absolute paths or whose `..` segments lead out of
+/// the root directory are rejected, so a document can only reference files
+/// inside of it. Files are read with blocking I/O, which is fine for the
+/// small files documents usually are.
+#[derive(Debug, Clone)]
+pub struct FileLoader {
+    root: PathBuf,
+}
+
+impl FileLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        FileLoader { root: root.into() }
+    }
+}
+
+impl RefLoader for FileLoader {
+    async fn load(&self, uri: &str) -> Result<Vec<u8>, Error> {
+        let load_error = |error: io::Error| Error::Load {
+            uri: uri.to_string(),
+            source: Box::new(error),
+        };
+        let path = relative_path(Path::new(uri.strip_prefix("file://").unwrap_or(uri)))
+            .ok_or_else(|| {
+                load_error(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "the path leads out of the root directory",
+                ))
+            })?;
+        std::fs::read(self.root.join(path)).map_err(load_error)
+    }
+}
+
+/// Removes the `.` and `..` segments of `path`, or returns `None` if it is
+/// absolute or leads out of the directory it is relative to.
+fn relative_path(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(segment) => normalized.push(segment),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
+}
+
+/// Loads documents over HTTP.
+///
+/// Documents are fetched once and then served from a cache, which clones of
+/// the loader share. Many documents of a project reference the same shared
+/// schemas, e.g. in a schema registry, so this saves most requests.
+///
+/// This type is only available with the `reqwest` or `http-refs` feature
+/// enabled.
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct HttpLoader {
+    client: reqwest::Client,
+    cache: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses `client` for all requests, e.g. to send authentication headers.
+    pub fn with_client(client: reqwest::Client) -> Self {
+        HttpLoader {
+            client,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Forgets the documents fetched so far, so they are fetched again.
+    pub fn clear_cache(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl RefLoader for HttpLoader {
+    async fn load(&self, uri: &str) -> Result<Vec<u8>, Error> {
+        if let Some(contents) = self.cache.lock().unwrap().get(uri) {
+            return Ok(contents.clone());
+        }
+        let load_error = |error: reqwest::Error| Error::Load {
+            uri: uri.to_string(),
+            source: Box::new(error),
+        };
+        let response = self
+            .client
+            .get(uri)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(load_error)?;
+        let contents = response.bytes().await.map_err(load_error)?.to_vec();
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(uri.to_string(), contents.clone());
+        Ok(contents)
+    }
+}
+
+#[test]
+fn test_file_loader() {
+    use std::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    let loader = FileLoader::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/fixtures"));
+    // Files are read with blocking I/O, a single poll completes the future.
+    let load = |uri| match pin!(loader.load(uri)).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(result) => result,
+        Poll::Pending => panic!("loading did not complete"),
+    };
+
+    let contents = load("file://streetlights_kafka.yml").unwrap();
+    assert!(String::from_utf8(contents).unwrap().contains("asyncapi:"));
+    assert!(matches!(
+        load("missing.yml"),
+        Err(Error::Load { uri, .. }) if uri == "missing.yml"
+    ));
+    assert!(load("./nested/../streetlights_kafka.yml").is_ok());
+    for uri in [
+        "/etc/passwd",
+        "file:///etc/passwd",
+        "../../Cargo.toml",
+        "a/../../lib.rs",
+    ] {
+        assert!(
+            matches!(load(uri), Err(Error::Load { source, .. }) if source.to_string().contains("root directory")),
+            "{uri} was loaded"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+/// Declares a document in a compact form and expands to the calls of
+/// [`AsyncAPI::builder`][crate::AsyncAPI::builder].
+///
+/// The document lists the `info`, optionally the `servers`, the `channels`
+/// and optionally the `components`. Each channel lists its operations by
+/// kind, `subscribe` or `publish`, with an optional operation id and the
+/// messages. A message is either an expression that evaluates to a
+/// [`Message`][crate::Message] or `ref` followed by the reference to one.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::{asyncapi, Components, Info, Message, Server};
+///
+/// let mut components = Components::default();
+/// let user_signed_up = components.add_message("UserSignedUp", Message::default());
+///
+/// let api = asyncapi! {
+///     info: Info::new("Account Service", "1.0.0"),
+///     servers: {
+///         "production": Server::new("broker.example.com:9092", "kafka"),
+///     },
+///     channels: {
+///         "user/signedup": {
+///             subscribe "onUserSignedUp" { ref &user_signed_up },
+///             publish { Message::builder().name("SignUp").build() },
+///         },
+///         "user/deleted": {},
+///     },
+///     components: components,
+/// };
+///
+/// assert_eq!(api.info.title, "Account Service");
+/// let channel = &api.channels["user/signedup"];
+/// assert_eq!(
+///     channel.subscribe.as_ref().unwrap().operation_id.as_deref(),
+///     Some("onUserSignedUp")
+/// );
+/// assert!(channel.publish.is_some());
+/// assert!(api.channels.contains_key("user/deleted"));
+/// ```
+#[macro_export]
+macro_rules! asyncapi {
+    (@operation $($operation_id:literal)?; $($messages:tt)*) => {
+        $crate::asyncapi!(
+            @messages $crate::Operation::builder() $(.operation_id($operation_id))?;
+            $($messages)*
+        )
+        .build()
+    };
+    (@messages $operation:expr;) => {
+        $operation
+    };
+    (@messages $operation:expr; ref $reference:expr $(, $($rest:tt)*)?) => {
+        $crate::asyncapi!(@messages $operation.message_ref($reference); $($($rest)*)?)
+    };
+    (@messages $operation:expr; $message:expr $(, $($rest:tt)*)?) => {
+        $crate::asyncapi!(@messages $operation.message($message); $($($rest)*)?)
+    };
+    (
+        info: $info:expr,
+        $(servers: { $($server_name:literal: $server:expr),* $(,)? },)?
+        channels: {
+            $($channel_name:literal: {
+                $($kind:ident $($operation_id:literal)? { $($messages:tt)* }),* $(,)?
+            }),* $(,)?
+        }
+        $(, components: $components:expr)? $(,)?
+    ) => {
+        $crate::AsyncAPI::builder()
+            .info($info)
+            $($(.server($server_name, $server))*)?
+            $(.channel(
+                $channel_name,
+                $crate::Channel::builder()
+                    $(.$kind($crate::asyncapi!(@operation $($operation_id)?; $($messages)*)))*
+                    .build(),
+            ))*
+            $(.components($components))?
+            .build()
+    };
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{Validate, ValidationError};
+
+/// A media type such as `application/json` or
+/// `application/vnd.apache.avro+json;version=1.9.0`, used for content types.
+///
+/// The value is kept as written, so documents round-trip unchanged. Any
+/// string is accepted when parsing a document, use
+/// [`validate`][Validate::validate] to check its format.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::MediaType;
+///
+/// let media_type = MediaType::new("application/vnd.apache.avro+json; version=1.9.0");
+/// assert_eq!(media_type.type_(), "application");
+/// assert_eq!(media_type.subtype(), "vnd.apache.avro+json");
+/// assert_eq!(media_type.suffix(), Some("json"));
+/// assert_eq!(media_type.essence(), "application/vnd.apache.avro+json");
+/// assert_eq!(media_type.parameter("version"), Some("1.9.0"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MediaType(String);
+
+impl MediaType {
+    pub fn new(media_type: impl Into<String>) -> Self {
+        MediaType(media_type.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The media type without its parameters, e.g. `application/json` for
+    /// `application/json; charset=utf-8`.
+    pub fn essence(&self) -> &str {
+        self.0.split(';').next().unwrap_or_default().trim()
+    }
+
+    /// The top-level type, e.g. `application` for `application/json`.
+    pub fn type_(&self) -> &str {
+        self.essence().split('/').next().unwrap_or_default()
+    }
+
+    /// The subtype including its suffix, e.g. `vnd.aai.asyncapi+json` for
+    /// `application/vnd.aai.asyncapi+json`. Empty if there's no subtype.
+    pub fn subtype(&self) -> &str {
+        self.essence()
+            .split_once('/')
+            .map_or("", |(_, subtype)| subtype)
+    }
+
+    /// The structured syntax suffix of the subtype, e.g. `json` for
+    /// `application/vnd.aai.asyncapi+json`.
+    pub fn suffix(&self) -> Option<&str> {
+        self.subtype().rsplit_once('+').map(|(_, suffix)| suffix)
+    }
+
+    /// The parameters in the order they are written, with quotes removed from
+    /// the values.
+    pub fn parameters(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.split(';').skip(1).filter_map(|parameter| {
+            let (name, value) = parameter.split_once('=')?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            Some((name.trim(), value))
+        })
+    }
+
+    /// The value of the parameter `name`, which is case-insensitive.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters()
+            .find(|(parameter, _)| parameter.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+}
+
+impl Validate for MediaType {
+    /// Parameters such as the `version` of schema formats, e.g.
+    /// `application/vnd.aai.asyncapi+json;version=2.6.0`, must have a name and
+    /// a value, which is a token or a quoted string.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        // RFC 6838: type and subtype are restricted names of letters, digits
+        // and `!#$&-^_.+`.
+        let is_name = |name: &str| {
+            !name.is_empty()
+                && name.chars().all(|c| {
+                    c.is_ascii_alphanumeric()
+                        || matches!(c, '!' | '#' | '$' | '&' | '-' | '^' | '_' | '.' | '+')
+                })
+        };
+        // RFC 9110: parameters are `name=value` with both tokens, or the value
+        // enclosed in double quotes.
+        let is_token = |token: &str| {
+            !token.is_empty()
+                && token
+                    .chars()
+                    .all(|c| c.is_ascii_graphic() && !"\"(),/:;<=>?@[\\]{}".contains(c))
+        };
+        let is_parameter = |parameter: &str| {
+            parameter.split_once('=').is_some_and(|(name, value)| {
+                let value = value.trim_end();
+                is_token(name.trim_start())
+                    && (is_token(value)
+                        || value.len() >= 2
+                            && value.starts_with('"')
+                            && value.ends_with('"')
+                            && !value[1..value.len() - 1].contains('"'))
+            })
+        };
+        if !is_name(self.type_())
+            || !is_name(self.subtype())
+            || !self.0.split(';').skip(1).all(is_parameter)
+        {
+            errors.push(ValidationError::new(
+                path,
+                format!("`{}` is not a valid media type", self.0),
+            ));
+        }
+    }
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for MediaType {
+    fn from(media_type: String) -> Self {
+        MediaType(media_type)
+    }
+}
+
+impl From<&str> for MediaType {
+    fn from(media_type: &str) -> Self {
+        MediaType(media_type.to_string())
+    }
+}
+
+impl AsRef<str> for MediaType {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[test]
+fn test_validate_media_type() {
+    assert!(MediaType::new("application/json").validate().is_ok());
+    assert!(MediaType::new("text/plain; charset=\"utf-8\"")
+        .validate()
+        .is_ok());
+    for valid in [
+        "application/vnd.aai.asyncapi;version=2.6.0",
+        "application/vnd.aai.asyncapi+json; version=2.6.0",
+        "application/schema+yaml;version=draft-07",
+    ] {
+        assert!(MediaType::new(valid).validate().is_ok(), "{valid}");
+    }
+    for invalid in [
+        "json",
+        "application/",
+        "application/json;",
+        "text /plain",
+        "application/raml+yaml;version=",
+        "application/raml+yaml;=1.0",
+        "application/raml+yaml;version=1 0",
+    ] {
+        assert!(MediaType::new(invalid).validate().is_err(), "{invalid}");
+    }
+    assert_eq!(
+        MediaType::new("text/plain; charset=\"utf-8\"").parameter("Charset"),
+        Some("utf-8")
+    );
+}
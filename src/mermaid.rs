@@ -0,0 +1,156 @@
+//! Export of the message flows of an [AsyncAPI document][AsyncAPI] as
+//! [Mermaid](https://mermaid.js.org) diagrams.
+//!
+//! Following the AsyncAPI 2.x semantics, a `publish` operation describes the
+//! messages the application receives from a channel and a `subscribe`
+//! operation the messages the application sends to a channel.
+//!
+//! # Examples
+//!
+//! ```
+//! # use asyncapi::AsyncAPI;
+//! let api: AsyncAPI = serde_yaml::from_str(
+//!     r#"
+//!     asyncapi: 2.3.0
+//!     info:
+//!       title: Account Service
+//!       version: 1.0.0
+//!     channels:
+//!       user/signedup:
+//!         subscribe:
+//!           message:
+//!             name: UserSignedUp
+//!     "#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     asyncapi::mermaid::flowchart(&api),
+//!     "flowchart LR\n    app([\"Account Service\"])\n    channel0[[\"user/signedup\"]]\n    app -- \"UserSignedUp\" --> channel0\n"
+//! );
+//! ```
+use std::fmt::Write;
+
+use crate::{reference::reference_name, AsyncAPI, Message, Operation, ReferenceOr};
+
+/// Renders a flowchart with the application in the middle and one node per
+/// channel. Edges point in the direction the messages travel and are labelled
+/// with the message names.
+pub fn flowchart(api: &AsyncAPI) -> String {
+    let mut out = String::from("flowchart LR\n");
+    let _ = writeln!(out, "    app([\"{}\"])", escape(&api.info.title));
+    for (i, name) in api.channels.keys().enumerate() {
+        let _ = writeln!(out, "    channel{i}[[\"{}\"]]", escape(name));
+    }
+    for (i, channel) in api.channels.values().enumerate() {
+        for (kind, operation) in channel.operations() {
+            if kind.application_sends() {
+                let _ = writeln!(out, "    app{} channel{i}", edge(api, operation));
+            } else {
+                let _ = writeln!(out, "    channel{i}{} app", edge(api, operation));
+            }
+        }
+    }
+    out
+}
+
+/// Renders a sequence diagram with the application and every channel as
+/// participants, listing the messages sent and received in document order.
+pub fn sequence_diagram(api: &AsyncAPI) -> String {
+    let mut out = String::from("sequenceDiagram\n");
+    let _ = writeln!(
+        out,
+        "    participant app as {}",
+        sequence_text(&api.info.title)
+    );
+    for (i, name) in api.channels.keys().enumerate() {
+        let _ = writeln!(out, "    participant channel{i} as {}", sequence_text(name));
+    }
+    for (i, channel) in api.channels.values().enumerate() {
+        for (kind, operation) in channel.operations() {
+            for message in message_names(api, operation) {
+                let message = sequence_text(&message);
+                if kind.application_sends() {
+                    let _ = writeln!(out, "    app->>channel{i}: {message}");
+                } else {
+                    let _ = writeln!(out, "    channel{i}->>app: {message}");
+                }
+            }
+        }
+    }
+    out
+}
+
+fn edge(api: &AsyncAPI, operation: &Operation) -> String {
+    let names = message_names(api, operation);
+    if names.is_empty() {
+        " -->".to_string()
+    } else {
+        format!(" -- \"{}\" -->", escape(&names.join(", ")))
+    }
+}
+
+fn message_names(api: &AsyncAPI, operation: &Operation) -> Vec<String> {
+    operation
+        .messages()
+        .into_iter()
+        .map(|message| match message {
+            ReferenceOr::Item(message) => display_name(message)
+                .or_else(|| operation.operation_id.clone())
+                .unwrap_or_else(|| "message".to_string()),
+            ReferenceOr::Reference { reference } => reference
+                .strip_prefix("#/components/messages/")
+                .and_then(|name| api.components.as_ref()?.messages.get(name))
+                .and_then(|message| match message {
+                    ReferenceOr::Item(message) => display_name(message),
+                    ReferenceOr::Reference { .. } => None,
+                })
+                .unwrap_or_else(|| reference_name(reference)),
+        })
+        .collect()
+}
+
+fn display_name(message: &Message) -> Option<String> {
+    message.name.clone().or_else(|| message.title.clone())
+}
+
+/// Mermaid labels are quoted, quotes inside them must use an entity code.
+fn escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Sequence diagram texts are not quoted, line breaks and `;` would end them.
+fn sequence_text(text: &str) -> String {
+    text.replace(';', "#59;").replace('\n', " ")
+}
+
+#[test]
+fn test_sequence_diagram_directions() {
+    let api: AsyncAPI = serde_yaml::from_str(
+        r#"
+        asyncapi: 2.3.0
+        info:
+          title: Streetlights
+          version: 1.0.0
+        channels:
+          light/measured:
+            publish:
+              message:
+                $ref: '#/components/messages/lightMeasured'
+          light/turn/on:
+            subscribe:
+              message:
+                title: Turn on
+        components:
+          messages:
+            lightMeasured:
+              name: LightMeasured
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        sequence_diagram(&api),
+        "sequenceDiagram\n    participant app as Streetlights\n    participant channel0 as light/measured\n    participant channel1 as light/turn/on\n    channel0->>app: LightMeasured\n    app->>channel1: Turn on\n"
+    );
+}
@@ -2,7 +2,10 @@ use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    CorrelationId, Example, ExternalDocumentation, MessageBinding, ReferenceOr, Schema, Tag,
+    schema::{ObjectType, SchemaData, SchemaKind, Type},
+    validation::{Validate, ValidationError},
+    CorrelationId, Example, ExternalDocumentation, MediaType, MessageBinding, MessageTrait,
+    ReferenceOr, Schema, Tag,
 };
 
 /// Describes a message received on a given channel and operation.
@@ -169,6 +172,16 @@ use crate::{
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Message {
+    /// Unique string used to identify the message.
+    ///
+    /// The id MUST be unique among all messages described in the API. The
+    /// messageId value is case-sensitive. Tools and libraries MAY use the
+    /// messageId to uniquely identify a message, therefore, it is
+    /// RECOMMENDED to follow common programming naming conventions.
+    ///
+    /// Added in AsyncAPI 2.4.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
     /// Schema definition of the application headers.
     /// Schema MUST be of type "object". It **MUST NOT** define the protocol headers.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -192,7 +205,7 @@ pub struct Message {
     /// The value MUST be a specific media type (e.g. application/json).
     /// When omitted, the value MUST be the one specified on the defaultContentType field.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
+    pub content_type: Option<MediaType>,
     /// A machine-friendly name for the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -220,15 +233,487 @@ pub struct Message {
     /// An array with examples of valid message objects.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub examples: Vec<Example>, // TODO try to parse better
+    /// A list of traits to apply to the message object. Traits MUST be merged
+    /// into the message object in the same order they are listed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traits: Vec<ReferenceOr<MessageTrait>>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl Message {
+    /// Starts building a message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{
+    ///     schema::{SchemaKind, Type},
+    ///     Message, Payload, ReferenceOr, Schema,
+    /// };
+    ///
+    /// let string: Schema = serde_json::from_str(r#"{"type": "string"}"#).unwrap();
+    /// let message = Message::builder()
+    ///     .name("UserSignedUp")
+    ///     .content_type("application/json")
+    ///     .payload_ref("#/components/schemas/User")
+    ///     .header("traceId", string.clone())
+    ///     .header("tenant", string)
+    ///     .build();
+    ///
+    /// assert!(matches!(message.payload, Some(Payload::Schema(_))));
+    /// let Some(ReferenceOr::Item(headers)) = message.headers else {
+    ///     panic!("headers are inline");
+    /// };
+    /// let SchemaKind::Type(Type::Object(headers)) = headers.schema_kind else {
+    ///     panic!("headers are an object");
+    /// };
+    /// assert_eq!(headers.properties.len(), 2);
+    /// ```
+    pub fn builder() -> MessageBuilder {
+        MessageBuilder::default()
+    }
+}
+
+/// Builds a [`Message`], see [`Message::builder`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageBuilder {
+    message: Message,
+}
+
+impl Validate for Message {
+    /// Checks that the content type and the schema formats are media types.
+    /// Inline traits are checked too.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(content_type) = &self.content_type {
+            content_type.validate_at(&format!("{path}/contentType"), errors);
+        }
+        if let Some(schema_format) = &self.schema_format {
+            MediaType::new(schema_format).validate_at(&format!("{path}/schemaFormat"), errors);
+        }
+        if let Some(Payload::MultiFormat(payload)) = &self.payload {
+            MediaType::new(&payload.schema_format)
+                .validate_at(&format!("{path}/payload/schemaFormat"), errors);
+        }
+        for (index, message_trait) in self.traits.iter().enumerate() {
+            if let ReferenceOr::Item(message_trait) = message_trait {
+                message_trait.validate_at(&format!("{path}/traits/{index}"), errors);
+            }
+        }
+    }
+}
+
+impl MessageBuilder {
+    /// Sets the [message id][Message::message_id].
+    pub fn message_id(mut self, message_id: impl Into<String>) -> Self {
+        self.message.message_id = Some(message_id.into());
+        self
+    }
+
+    /// Sets the [name][Message::name].
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.message.name = Some(name.into());
+        self
+    }
+
+    /// Sets the [title][Message::title].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.message.title = Some(title.into());
+        self
+    }
+
+    /// Sets the [summary][Message::summary].
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.message.summary = Some(summary.into());
+        self
+    }
+
+    /// Sets the [description][Message::description].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.message.description = Some(description.into());
+        self
+    }
+
+    /// Sets the [content type][Message::content_type].
+    pub fn content_type(mut self, content_type: impl Into<MediaType>) -> Self {
+        self.message.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Sets the [schema format][Message::schema_format] of the payload.
+    pub fn schema_format(mut self, schema_format: impl Into<String>) -> Self {
+        self.message.schema_format = Some(schema_format.into());
+        self
+    }
+
+    /// Sets the [payload][Message::payload].
+    pub fn payload(mut self, payload: impl Into<Payload>) -> Self {
+        self.message.payload = Some(payload.into());
+        self
+    }
+
+    /// Sets the [payload][Message::payload] to `schema`.
+    pub fn payload_schema(self, schema: Schema) -> Self {
+        self.payload(schema)
+    }
+
+    /// Sets the [payload][Message::payload] to the schema defined at
+    /// `reference`.
+    pub fn payload_ref(self, reference: &str) -> Self {
+        self.payload(Payload::reference(reference))
+    }
+
+    /// Sets the [headers][Message::headers] schema.
+    pub fn headers(mut self, headers: Schema) -> Self {
+        self.message.headers = Some(ReferenceOr::Item(headers));
+        self
+    }
+
+    /// Sets the [headers][Message::headers] to the schema defined at
+    /// `reference`.
+    pub fn headers_ref(mut self, reference: &str) -> Self {
+        self.message.headers = Some(ReferenceOr::ref_(reference));
+        self
+    }
+
+    /// Adds the header `name` to the [headers][Message::headers] object
+    /// schema. Headers that aren't an inline object schema are replaced by
+    /// one.
+    pub fn header(mut self, name: impl Into<String>, schema: Schema) -> Self {
+        let is_object = matches!(
+            &self.message.headers,
+            Some(ReferenceOr::Item(Schema {
+                schema_kind: SchemaKind::Type(Type::Object(_)),
+                ..
+            }))
+        );
+        if !is_object {
+            self.message.headers = Some(ReferenceOr::Item(Schema {
+                schema_data: SchemaData::default(),
+                schema_kind: SchemaKind::Type(Type::Object(ObjectType::default())),
+            }));
+        }
+        if let Some(ReferenceOr::Item(Schema {
+            schema_kind: SchemaKind::Type(Type::Object(object)),
+            ..
+        })) = &mut self.message.headers
+        {
+            object
+                .properties
+                .insert(name.into(), ReferenceOr::Item(Box::new(schema)));
+        }
+        self
+    }
+
+    /// Sets the [correlation id][Message::correlation_id].
+    pub fn correlation_id(mut self, correlation_id: CorrelationId) -> Self {
+        self.message.correlation_id = Some(ReferenceOr::Item(correlation_id));
+        self
+    }
+
+    /// Adds a [tag][Message::tags].
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.message.tags.push(tag);
+        self
+    }
+
+    /// Sets the [external documentation][Message::external_docs].
+    pub fn external_docs(mut self, external_docs: ExternalDocumentation) -> Self {
+        self.message.external_docs = Some(external_docs);
+        self
+    }
+
+    /// Sets the [bindings][Message::bindings].
+    pub fn bindings(mut self, bindings: MessageBinding) -> Self {
+        self.message.bindings = Some(ReferenceOr::Item(bindings));
+        self
+    }
+
+    /// Sets the [bindings][Message::bindings] to those defined at `reference`.
+    pub fn bindings_ref(mut self, reference: &str) -> Self {
+        self.message.bindings = Some(ReferenceOr::ref_(reference));
+        self
+    }
+
+    /// Adds an [example][Message::examples].
+    pub fn example(mut self, example: Example) -> Self {
+        self.message.examples.push(example);
+        self
+    }
+
+    /// Adds a [specification extension][Message::extensions].
+    pub fn extension(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.message.extensions.insert(name.into(), value);
+        self
+    }
+
+    pub fn build(self) -> Message {
+        self.message
+    }
+}
+
+/// Rust types that describe a message.
+///
+/// With the `derive` feature, `#[derive(AsyncApiMessage)]` implements this
+/// for structs: the message is named after the struct and its payload is an
+/// object schema of the fields.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use asyncapi::{AsyncApiMessage, Payload};
+/// use serde::Serialize;
+///
+/// /// A user signed up.
+/// #[derive(AsyncApiMessage, Serialize)]
+/// #[asyncapi(content_type = "application/json")]
+/// #[serde(rename_all = "camelCase")]
+/// struct UserSignedUp {
+///     /// The name of the user.
+///     display_name: String,
+///     email: Option<String>,
+/// }
+///
+/// let message = UserSignedUp::message();
+/// assert_eq!(message.name.as_deref(), Some("UserSignedUp"));
+/// assert_eq!(message.description.as_deref(), Some("A user signed up."));
+/// assert!(matches!(message.payload, Some(Payload::Schema(_))));
+/// ```
+pub trait AsyncApiMessage {
+    /// The message describing this type.
+    fn message() -> Message;
+}
+
+/// The definition of a [message payload][Message::payload].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum Payload {
-    Schema(Schema),
+    /// A definition that declares its own schema format.
+    ///
+    /// Added in AsyncAPI 3.0.0.
+    MultiFormat(MultiFormatSchema),
+    /// A schema in the default format, or a reference to one.
+    Schema(ReferenceOr<Schema>),
+    /// A payload definition in another `schemaFormat`, e.g. Avro.
     Any(serde_json::Value),
 }
+
+impl Payload {
+    /// A payload defined by the schema at `reference`.
+    pub fn reference(reference: &str) -> Self {
+        Payload::Schema(ReferenceOr::ref_(reference))
+    }
+}
+
+impl From<Schema> for Payload {
+    fn from(schema: Schema) -> Self {
+        Payload::Schema(ReferenceOr::Item(schema))
+    }
+}
+
+impl From<ReferenceOr<Schema>> for Payload {
+    fn from(schema: ReferenceOr<Schema>) -> Self {
+        Payload::Schema(schema)
+    }
+}
+
+impl From<MultiFormatSchema> for Payload {
+    fn from(schema: MultiFormatSchema) -> Self {
+        Payload::MultiFormat(schema)
+    }
+}
+
+impl From<serde_json::Value> for Payload {
+    /// Picks the variant the value would be parsed as, so a value that is a
+    /// schema becomes [`Payload::Schema`].
+    fn from(value: serde_json::Value) -> Self {
+        Payload::deserialize(&value).unwrap_or(Payload::Any(value))
+    }
+}
+
+/// A schema together with the format it is written in, so that schemas of
+/// different formats can be used alongside each other.
+///
+/// Added in AsyncAPI 3.0.0.
+///
+/// # Examples
+///
+/// ```yaml
+/// schemaFormat: application/vnd.apache.avro;version=1.9.0
+/// schema:
+///   type: record
+///   name: User
+///   namespace: com.company
+///   doc: User information
+///   fields:
+///     - name: displayName
+///       type: string
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiFormatSchema {
+    /// **REQUIRED.** A string containing the name of the schema format that
+    /// is used to define the information, e.g.
+    /// `application/vnd.apache.avro;version=1.9.0`. The formats of the
+    /// [Schema formats table][Message] are supported, as well as
+    /// `application/vnd.aai.asyncapi+json;version={{asyncapi}}` for the
+    /// default format of a given AsyncAPI version.
+    pub schema_format: String,
+    /// **REQUIRED.** The definition of the schema, interpreted according to
+    /// `schemaFormat`.
+    pub schema: serde_json::Value,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl MultiFormatSchema {
+    /// Whether the schema uses the default AsyncAPI schema format, i.e. can be
+    /// read as a [Schema][crate::Schema].
+    ///
+    /// The essence of the media type is compared, so parameters like the
+    /// `version` are ignored but `application/vnd.aai.asyncapi-extra` is not
+    /// mistaken for the default format.
+    pub fn is_asyncapi_schema(&self) -> bool {
+        let media_type = MediaType::new(self.schema_format.as_str());
+        [
+            "application/vnd.aai.asyncapi",
+            "application/vnd.aai.asyncapi+json",
+            "application/vnd.aai.asyncapi+yaml",
+        ]
+        .iter()
+        .any(|essence| media_type.essence().eq_ignore_ascii_case(essence))
+    }
+}
+
+#[test]
+fn test_multi_format_payload() {
+    let example = r#"
+    payload:
+      schemaFormat: application/vnd.apache.avro;version=1.9.0
+      schema:
+        type: record
+        name: User
+        fields:
+          - name: displayName
+            type: string
+    "#;
+    let message: Message = serde_yaml::from_str(example).unwrap();
+    let Some(Payload::MultiFormat(payload)) = &message.payload else {
+        panic!("expected a multi format payload");
+    };
+    assert_eq!(
+        payload.schema_format,
+        "application/vnd.apache.avro;version=1.9.0"
+    );
+    assert!(!payload.is_asyncapi_schema());
+    assert_eq!(payload.schema["name"], "User");
+    let with_format = |schema_format: &str| MultiFormatSchema {
+        schema_format: schema_format.to_string(),
+        schema: serde_json::Value::Null,
+        extensions: IndexMap::new(),
+    };
+    assert!(with_format("application/vnd.aai.asyncapi+json;version=3.0.0").is_asyncapi_schema());
+    assert!(with_format("Application/Vnd.Aai.Asyncapi; version=3.0.0").is_asyncapi_schema());
+    assert!(!with_format("application/vnd.aai.asyncapi-extra").is_asyncapi_schema());
+
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(serde_json::from_value::<Message>(value).unwrap(), message);
+
+    let message: Message = serde_yaml::from_str("payload:\n  type: string").unwrap();
+    assert!(matches!(message.payload, Some(Payload::Schema(_))));
+}
+
+#[test]
+fn test_message_id_round_trip() {
+    let example = r#"
+    messageId: userSignup
+    name: UserSignup
+    payload:
+      type: object
+    "#;
+    let message: Message = serde_yaml::from_str(example).expect("Could not deserialize message");
+    assert_eq!(message.message_id.as_deref(), Some("userSignup"));
+    assert!(message.extensions.is_empty());
+
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["messageId"], "userSignup");
+    assert_eq!(serde_json::from_value::<Message>(value).unwrap(), message);
+}
+
+#[test]
+fn test_payload_from_value() {
+    let payload = Payload::from(serde_json::json!({"type": "string"}));
+    assert!(matches!(payload, Payload::Schema(ReferenceOr::Item(_))));
+    let payload = Payload::from(serde_json::json!("plain"));
+    assert_eq!(payload, Payload::Any(serde_json::json!("plain")));
+    let payload = Payload::from(serde_json::json!({"$ref": "#/components/schemas/User"}));
+    assert_eq!(payload, Payload::reference("#/components/schemas/User"));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_message() {
+    use crate::schema::{SchemaKind, Type};
+    use serde::Serialize;
+
+    #[derive(crate::AsyncApiMessage, Serialize)]
+    #[asyncapi(name = "userSignedUp", title = "User signed up")]
+    #[allow(dead_code)]
+    struct UserSignedUp {
+        #[serde(rename = "userId")]
+        id: u64,
+        tags: Vec<String>,
+        address: Option<Address>,
+        #[serde(skip)]
+        internal: bool,
+    }
+    #[derive(Serialize)]
+    struct Address;
+
+    let message = UserSignedUp::message();
+    assert_eq!(message.name.as_deref(), Some("userSignedUp"));
+    assert_eq!(message.title.as_deref(), Some("User signed up"));
+    let Some(Payload::Schema(ReferenceOr::Item(Schema {
+        schema_kind: SchemaKind::Type(Type::Object(payload)),
+        ..
+    }))) = message.payload
+    else {
+        panic!("the payload is an object schema");
+    };
+    assert_eq!(
+        payload.properties.keys().collect::<Vec<_>>(),
+        ["userId", "tags", "address"]
+    );
+    assert_eq!(payload.required, ["userId", "tags"]);
+    assert_eq!(
+        payload.properties["address"],
+        ReferenceOr::ref_("#/components/schemas/Address")
+    );
+}
+
+#[test]
+fn test_validate_message_media_types() {
+    let message: Message = serde_yaml::from_str(
+        r#"
+        contentType: application/json
+        payload:
+          schemaFormat: application/vnd.apache.avro;version=
+          schema:
+            type: record
+        traits:
+          - schemaFormat: avro
+        "#,
+    )
+    .unwrap();
+    let Err(crate::Error::Validate(errors)) = message.validate() else {
+        panic!("the message is invalid");
+    };
+    let paths: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+    assert_eq!(paths, ["/payload/schemaFormat", "/traits/0/schemaFormat"]);
+}
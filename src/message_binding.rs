@@ -1,7 +1,13 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::Schema;
+use crate::{
+    binding_builder::binding_builder,
+    operation_binding::HttpMethod,
+    schema::StringOrSchema,
+    validation::{Validate, ValidationError},
+    BindingVersion, MediaType, ReferenceOr, Schema,
+};
 
 /// Map describing protocol-specific definitions for a message.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -21,9 +27,11 @@ pub struct MessageBinding {
     /// Protocol-specific information for an AMQP 0-9-1 message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amqp: Option<AMQPMessageBinding>,
-    /// Protocol-specific information for an AMQP 1.0 message.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub qmqp1: Option<AMQP1MessageBinding>,
+    /// Protocol-specific information for an AMQP 1.0 message. Documents
+    /// written with the misspelled `qmqp1` key of earlier versions of this
+    /// crate are accepted as well.
+    #[serde(alias = "qmqp1", skip_serializing_if = "Option::is_none")]
+    pub amqp1: Option<AMQP1MessageBinding>,
     /// Protocol-specific information for an MQTT message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mqtt: Option<MQTTMessageBinding>,
@@ -57,12 +65,50 @@ pub struct MessageBinding {
     /// Protocol-specific information for an IBM MQ message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ibmmq: Option<IBMMQMessageBinding>,
+    /// Protocol-specific information for a Google Cloud Pub/Sub message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub googlepubsub: Option<GooglePubSubMessageBinding>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
+    /// Bindings of other protocols are kept here as well, see
+    /// [custom][MessageBinding::custom].
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl MessageBinding {
+    /// The bindings of protocols this crate doesn't define a binding object
+    /// for, i.e. the [extensions][MessageBinding::extensions] that aren't
+    /// specification extensions.
+    pub fn custom(&self) -> impl Iterator<Item = (&str, &serde_json::Value)> {
+        self.extensions
+            .iter()
+            .filter(|(protocol, _)| !protocol.starts_with("x-"))
+            .map(|(protocol, binding)| (protocol.as_str(), binding))
+    }
+
+    /// The binding for `protocol` among the [custom][MessageBinding::custom] ones.
+    pub fn custom_binding(&self, protocol: &str) -> Option<&serde_json::Value> {
+        self.extensions
+            .get(protocol)
+            .filter(|_| !protocol.starts_with("x-"))
+    }
+}
+
+impl Validate for MessageBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(http) = &self.http {
+            http.validate_at(&format!("{path}/http"), errors);
+        }
+        if let Some(ws) = &self.ws {
+            ws.validate_at(&format!("{path}/ws"), errors);
+        }
+        if let Some(ibmmq) = &self.ibmmq {
+            ibmmq.validate_at(&format!("{path}/ibmmq"), errors);
+        }
+    }
+}
+
 /// This object contains information about the message representation in HTTP.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -71,9 +117,16 @@ pub struct HTTPMessageBinding {
     /// This schema MUST be of type object and have a properties key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<Schema>,
-    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    /// The HTTP response status code according to
+    /// [RFC 9110](https://httpwg.org/specs/rfc9110.html#overview.of.status.codes).
+    /// `statusCode` is only relevant for messages referenced by the
+    /// Operation Reply Object, as it defines the status code for the response.
+    /// In all other cases, this value can be safely ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    pub status_code: Option<u16>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// When using WebSockets, the channel represents the connection.
@@ -86,7 +139,7 @@ pub struct HTTPMessageBinding {
 pub struct WebSocketMessageBinding {
     /// The HTTP method to use when establishing the connection. Its value MUST be either GET or POST.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub method: Option<String>,
+    pub method: Option<HttpMethod>,
     /// A Schema object containing the definitions for each query parameter.
     /// This schema MUST be of type object and have a properties key.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,20 +149,73 @@ pub struct WebSocketMessageBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<Schema>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+impl Validate for HTTPMessageBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(status_code) = self.status_code.filter(|code| !(100..=599).contains(code)) {
+            errors.push(ValidationError::new(
+                format!("{path}/statusCode"),
+                format!("`{status_code}` is not an HTTP status code"),
+            ));
+        }
+    }
+}
+
+impl Validate for WebSocketMessageBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(method) = &self.method {
+            if !matches!(method, HttpMethod::Get | HttpMethod::Post) {
+                errors.push(ValidationError::new(
+                    format!("{path}/method"),
+                    format!("`{method}` is neither GET nor POST"),
+                ));
+            }
+        }
+    }
 }
 
 /// This object contains information about the message representation in Kafka.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   test:
+///     publish:
+///       message:
+///         bindings:
+///           kafka:
+///             key:
+///               type: string
+///               enum: ['myKey']
+///             schemaIdLocation: 'payload'
+///             schemaIdPayloadEncoding: '4'
+///             bindingVersion: '0.4.0'
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaMessageBinding {
     /// The message key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<Schema>,
-    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    /// If a Schema Registry is used when performing this operation, tells
+    /// where the id of schema is stored, `header` or `payload`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id_location: Option<String>,
+    /// Number of bytes or vendor specific values when schema id is encoded in
+    /// payload, e.g. `confluent` or `apicurio-legacy`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    pub schema_id_payload_encoding: Option<String>,
+    /// Freeform string for any naming strategy class to use. Clients should
+    /// default to the vendor default if not supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_lookup_strategy: Option<String>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// The Anypoint MQ [Message Binding Object][MessageBinding] is defined by a
@@ -168,8 +274,8 @@ pub struct AnyPointMQMessageBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<Schema>,
     /// **Optional**, defaults to `latest`. The version of this binding.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// This object contains information about the message representation in AMQP.
@@ -183,8 +289,8 @@ pub struct AMQPMessageBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_type: Option<String>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
@@ -193,12 +299,42 @@ pub struct AMQPMessageBinding {
 pub struct AMQP1MessageBinding {}
 
 /// This object contains information about the message representation in MQTT.
+///
+/// # Examples
+///
+/// ```yaml
+/// bindings:
+///   mqtt:
+///     contentType: "application/json"
+///     correlationData:
+///       type: string
+///       format: uuid
+///     responseTopic: application/responses
+///     bindingVersion: 0.2.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MQTTMessageBinding {
-    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    /// Either `0` (zero), the payload is unspecified bytes, or `1`, the
+    /// payload is UTF-8 encoded character data. Defaults to `0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_format_indicator: Option<u8>,
+    /// Correlation Data is used by the sender of the request message to
+    /// identify which request the response message is for when it is received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_data: Option<ReferenceOr<Schema>>,
+    /// String describing the content type of the message payload. This should
+    /// not conflict with the `contentType` field of the associated AsyncAPI
+    /// Message object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<MediaType>,
+    /// The topic (channel URI) for a response message, or a schema describing
+    /// it.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    pub response_topic: Option<StringOrSchema>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
@@ -252,3 +388,132 @@ pub struct IBMMQMessageBinding {
     #[serde(rename = "type")]
     pub typ: Option<String>,
 }
+
+impl Validate for IBMMQMessageBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(typ) = &self.typ {
+            if !matches!(typ.as_str(), "string" | "jms" | "binary") {
+                errors.push(ValidationError::new(
+                    format!("{path}/type"),
+                    format!("`{typ}` is neither string, jms nor binary"),
+                ));
+            }
+        }
+    }
+}
+
+/// This object contains information about the message representation in
+/// Google Cloud Pub/Sub.
+///
+/// # Examples
+///
+/// ```yaml
+/// components:
+///   messages:
+///     messageAvro:
+///       bindings:
+///         googlepubsub:
+///           orderingKey: user-42
+///           schema:
+///             name: projects/your-project/schemas/message-avro
+///           bindingVersion: 0.2.0
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePubSubMessageBinding {
+    /// Attributes for this message. If empty, the message must contain
+    /// non-empty data.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub attributes: IndexMap<String, String>,
+    /// If non-empty, identifies related messages for which publish order
+    /// should be respected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering_key: Option<String>,
+    /// Describes the schema used to validate the payload of this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<GooglePubSubMessageBindingSchema>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePubSubMessageBindingSchema {
+    /// The name of the schema, e.g. `projects/your-project/schemas/message-avro`.
+    pub name: String,
+    /// The type of the schema, `avro` or `protobuf`. Required by binding
+    /// version 0.1.0 and removed in 0.2.0.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+}
+
+binding_builder! {
+    KafkaMessageBinding => KafkaMessageBindingBuilder {
+        key: Option<Schema>,
+        schema_id_location: Option<String>,
+        schema_id_payload_encoding: Option<String>,
+        schema_lookup_strategy: Option<String>,
+        binding_version: BindingVersion,
+    }
+    MQTTMessageBinding => MQTTMessageBindingBuilder {
+        payload_format_indicator: Option<u8>,
+        correlation_data: Option<ReferenceOr<Schema>>,
+        content_type: Option<MediaType>,
+        response_topic: Option<StringOrSchema>,
+        binding_version: BindingVersion,
+    }
+}
+
+#[test]
+fn test_anypointmq_message_binding() {
+    let binding: MessageBinding = serde_yaml::from_str(
+        r#"
+        anypointmq:
+          headers:
+            type: object
+            properties:
+              messageId:
+                type: string
+          bindingVersion: '0.0.1'
+        "#,
+    )
+    .unwrap();
+    let anypointmq = binding.anypointmq.unwrap();
+    assert!(anypointmq.headers.is_some());
+    assert_eq!(anypointmq.binding_version, "0.0.1");
+    assert!(binding.extensions.is_empty());
+}
+
+#[test]
+fn test_mqtt_message_binding() {
+    let binding: MessageBinding = serde_yaml::from_str(
+        r#"
+        mqtt:
+          payloadFormatIndicator: 1
+          contentType: "application/json"
+          correlationData:
+            type: string
+            format: uuid
+          responseTopic: application/responses
+          bindingVersion: 0.2.0
+        "#,
+    )
+    .unwrap();
+    let mqtt = binding.mqtt.unwrap();
+    assert_eq!(mqtt.payload_format_indicator, Some(1));
+    assert_eq!(mqtt.content_type, Some(MediaType::new("application/json")));
+    assert!(matches!(mqtt.correlation_data, Some(ReferenceOr::Item(_))));
+    assert_eq!(
+        mqtt.response_topic,
+        Some(StringOrSchema::String("application/responses".to_string()))
+    );
+}
+
+#[test]
+fn test_amqp1_message_binding_key() {
+    let binding: MessageBinding = serde_yaml::from_str("qmqp1: {}").unwrap();
+    assert_eq!(binding.amqp1, Some(AMQP1MessageBinding {}));
+    assert!(binding.extensions.is_empty());
+    assert_eq!(serde_yaml::to_string(&binding).unwrap(), "amqp1: {}\n");
+}
@@ -2,7 +2,9 @@ use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    message_binding::MessageBinding, CorrelationId, ExternalDocumentation, ReferenceOr, Schema, Tag,
+    message_binding::MessageBinding,
+    validation::{Validate, ValidationError},
+    CorrelationId, ExternalDocumentation, MediaType, ReferenceOr, Schema, Tag,
 };
 
 /// Describes a trait that MAY be applied to a
@@ -30,6 +32,16 @@ use crate::{
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MessageTrait {
+    /// Unique string used to identify the message.
+    ///
+    /// The id MUST be unique among all messages described in the API. The
+    /// messageId value is case-sensitive. Tools and libraries MAY use the
+    /// messageId to uniquely identify a message, therefore, it is
+    /// RECOMMENDED to follow common programming naming conventions.
+    ///
+    /// Added in AsyncAPI 2.4.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
     /// Schema definition of the application headers.
     /// Schema MUST be of type "object".
     /// It **MUST NOT** define the protocol headers.
@@ -49,7 +61,7 @@ pub struct MessageTrait {
     /// [defaultContentType](https://www.asyncapi.com/docs/specifications/v2.3.0#defaultContentTypeString)
     /// field.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
+    pub content_type: Option<MediaType>,
     /// A machine-friendly name for the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -80,10 +92,22 @@ pub struct MessageTrait {
     pub examples: Vec<MessageExample>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl Validate for MessageTrait {
+    /// Checks that the content type and the schema format are media types.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(content_type) = &self.content_type {
+            content_type.validate_at(&format!("{path}/contentType"), errors);
+        }
+        if let Some(schema_format) = &self.schema_format {
+            MediaType::new(schema_format).validate_at(&format!("{path}/schemaFormat"), errors);
+        }
+    }
+}
+
 /// Message Example Object represents an example of a
 /// [Message Object][crate::Message] and MUST contain either **headers**
 /// and/or **payload** fields.
@@ -140,6 +164,17 @@ pub struct MessageExample {
     pub summary: Option<String>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
+
+#[test]
+fn test_message_trait_message_id_round_trip() {
+    let message_trait: MessageTrait =
+        serde_yaml::from_str("messageId: userSignup").expect("Could not deserialize message trait");
+    assert_eq!(message_trait.message_id.as_deref(), Some("userSignup"));
+    assert_eq!(
+        serde_json::to_value(&message_trait).unwrap(),
+        serde_json::json!({ "messageId": "userSignup" })
+    );
+}
@@ -0,0 +1,398 @@
+use indexmap::IndexMap;
+
+use crate::{
+    channel::OperationMessageType,
+    schema::{AdditionalProperties, SchemaKind, Type},
+    AsyncAPI, Channel, ChannelBinding, ComponentKind, Components, Contact, CorrelationId,
+    ExternalDocumentation, Info, License, Message, MessageBinding, MessageTrait, Operation,
+    OperationBinding, OperationTrait, Parameter, Payload, ReferenceOr, Schema, SecurityScheme,
+    Server, ServerBinding, ServerVariable, Tag,
+};
+
+/// An object of a document found by [`AsyncAPI::lookup`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Node<'a> {
+    AsyncAPI(&'a AsyncAPI),
+    Info(&'a Info),
+    Contact(&'a Contact),
+    License(&'a License),
+    Server(&'a Server),
+    ServerVariable(&'a ServerVariable),
+    Channel(&'a Channel),
+    Operation(&'a Operation),
+    OperationTrait(&'a OperationTrait),
+    Message(&'a Message),
+    MessageTrait(&'a MessageTrait),
+    Schema(&'a Schema),
+    Parameter(&'a Parameter),
+    CorrelationId(&'a CorrelationId),
+    SecurityScheme(&'a SecurityScheme),
+    Components(&'a Components),
+    ServerBinding(&'a ServerBinding),
+    ChannelBinding(&'a ChannelBinding),
+    OperationBinding(&'a OperationBinding),
+    MessageBinding(&'a MessageBinding),
+    Tag(&'a Tag),
+    ExternalDocumentation(&'a ExternalDocumentation),
+    /// A [Reference Object][ReferenceOr::Reference], with the reference.
+    /// Lookups don't follow references.
+    Reference(&'a str),
+    /// A value the model keeps as JSON, such as a specification extension or
+    /// a payload in another schema format.
+    Value(&'a serde_json::Value),
+}
+
+impl AsyncAPI {
+    /// The object at the JSON Pointer `pointer`, e.g.
+    /// `/channels/user~1signedup/subscribe/message`, with or without a
+    /// leading `#`.
+    ///
+    /// Returns `None` if nothing is there, if the pointer passes through a
+    /// reference, or if it points to a field that isn't one of the objects of
+    /// [`Node`], like `/info/title`. The fields of bindings, security schemes
+    /// and the like aren't looked into, except for their extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, Node};
+    ///
+    /// let api: AsyncAPI = r#"
+    /// asyncapi: 2.6.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         name: UserSignedUp
+    ///         payload:
+    ///           $ref: '#/components/schemas/User'
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let message = api.lookup("/channels/user~1signedup/subscribe/message");
+    /// assert!(matches!(
+    ///     message,
+    ///     Some(Node::Message(message)) if message.name.as_deref() == Some("UserSignedUp")
+    /// ));
+    /// assert_eq!(
+    ///     api.lookup("#/channels/user~1signedup/subscribe/message/payload"),
+    ///     Some(Node::Reference("#/components/schemas/User"))
+    /// );
+    /// assert_eq!(api.lookup("/channels/user~1deleted"), None);
+    /// ```
+    pub fn lookup(&self, pointer: &str) -> Option<Node<'_>> {
+        let pointer = pointer.strip_prefix('#').unwrap_or(pointer);
+        let mut node = Node::AsyncAPI(self);
+        if pointer.is_empty() {
+            return Some(node);
+        }
+        let mut segments = pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"));
+        while let Some(segment) = segments.next() {
+            node = node.child(&segment, &mut segments)?;
+        }
+        Some(node)
+    }
+}
+
+impl<'a> Node<'a> {
+    /// The node at `segment`, taking further segments from `rest` where the
+    /// model nests objects in maps or lists.
+    fn child(self, segment: &str, rest: &mut impl Iterator<Item = String>) -> Option<Node<'a>> {
+        match self {
+            Node::AsyncAPI(api) => match segment {
+                "info" => Some(Node::Info(&api.info)),
+                "servers" => Some(item(api.servers.get(&rest.next()?)?, Node::Server)),
+                "channels" => Some(Node::Channel(api.channels.get(&rest.next()?)?)),
+                "components" => api.components.as_ref().map(Node::Components),
+                "tags" => index(&api.tags, rest).map(Node::Tag),
+                "externalDocs" => api.external_docs.as_ref().map(Node::ExternalDocumentation),
+                _ => extension(&api.extensions, segment),
+            },
+            Node::Info(info) => match segment {
+                "contact" => info.contact.as_ref().map(Node::Contact),
+                "license" => info.license.as_ref().map(Node::License),
+                _ => extension(&info.extensions, segment),
+            },
+            Node::Server(server) => match segment {
+                "variables" => Some(item(
+                    server.variables.get(&rest.next()?)?,
+                    Node::ServerVariable,
+                )),
+                "tags" => index(&server.tags, rest).map(Node::Tag),
+                "bindings" => Some(item(server.bindings.as_ref()?, Node::ServerBinding)),
+                _ => extension(&server.extensions, segment),
+            },
+            Node::Channel(channel) => match segment {
+                "subscribe" => channel.subscribe.as_ref().map(Node::Operation),
+                "publish" => channel.publish.as_ref().map(Node::Operation),
+                "parameters" => Some(item(
+                    channel.parameters.get(&rest.next()?)?,
+                    Node::Parameter,
+                )),
+                "bindings" => Some(item(channel.bindings.as_ref()?, Node::ChannelBinding)),
+                _ => extension(&channel.extensions, segment),
+            },
+            Node::Operation(operation) => match segment {
+                "message" => match operation.message.as_ref()? {
+                    OperationMessageType::Single(message) => Some(item(message, Node::Message)),
+                    OperationMessageType::OneOf { one_of } => match rest.next()?.as_str() {
+                        "oneOf" => Some(item(index(one_of, rest)?, Node::Message)),
+                        _ => None,
+                    },
+                    OperationMessageType::Map(messages) => {
+                        Some(item(messages.get(&rest.next()?)?, Node::Message))
+                    }
+                },
+                "traits" => Some(item(index(&operation.traits, rest)?, Node::OperationTrait)),
+                "tags" => index(&operation.tags, rest).map(Node::Tag),
+                "externalDocs" => operation
+                    .external_docs
+                    .as_ref()
+                    .map(Node::ExternalDocumentation),
+                "bindings" => Some(item(operation.bindings.as_ref()?, Node::OperationBinding)),
+                _ => extension(&operation.extensions, segment),
+            },
+            Node::OperationTrait(operation_trait) => match segment {
+                "tags" => index(&operation_trait.tags, rest).map(Node::Tag),
+                "externalDocs" => operation_trait
+                    .external_docs
+                    .as_ref()
+                    .map(Node::ExternalDocumentation),
+                "bindings" => Some(item(
+                    operation_trait.bindings.as_ref()?,
+                    Node::OperationBinding,
+                )),
+                _ => extension(&operation_trait.extensions, segment),
+            },
+            Node::Message(message) => match segment {
+                "headers" => Some(item(message.headers.as_ref()?, Node::Schema)),
+                "payload" => match message.payload.as_ref()? {
+                    Payload::Schema(schema) => Some(item(schema, Node::Schema)),
+                    Payload::MultiFormat(schema) => match rest.next()?.as_str() {
+                        "schema" => Some(Node::Value(&schema.schema)),
+                        _ => None,
+                    },
+                    Payload::Any(payload) => Some(Node::Value(payload)),
+                },
+                "correlationId" => {
+                    Some(item(message.correlation_id.as_ref()?, Node::CorrelationId))
+                }
+                "traits" => Some(item(index(&message.traits, rest)?, Node::MessageTrait)),
+                "tags" => index(&message.tags, rest).map(Node::Tag),
+                "externalDocs" => message
+                    .external_docs
+                    .as_ref()
+                    .map(Node::ExternalDocumentation),
+                "bindings" => Some(item(message.bindings.as_ref()?, Node::MessageBinding)),
+                _ => extension(&message.extensions, segment),
+            },
+            Node::MessageTrait(message_trait) => match segment {
+                "headers" => Some(item(message_trait.headers.as_ref()?, Node::Schema)),
+                "correlationId" => Some(item(
+                    message_trait.correlation_id.as_ref()?,
+                    Node::CorrelationId,
+                )),
+                "tags" => index(&message_trait.tags, rest).map(Node::Tag),
+                "externalDocs" => message_trait
+                    .external_docs
+                    .as_ref()
+                    .map(Node::ExternalDocumentation),
+                "bindings" => Some(item(message_trait.bindings.as_ref()?, Node::MessageBinding)),
+                _ => extension(&message_trait.extensions, segment),
+            },
+            Node::Schema(schema) => schema_child(schema, segment, rest),
+            Node::Parameter(parameter) => match segment {
+                "schema" => Some(item(parameter.schema.as_ref()?, Node::Schema)),
+                _ => extension(&parameter.extensions, segment),
+            },
+            Node::Components(components) => {
+                let name = rest.next()?;
+                Some(match ComponentKind::from_field(segment)? {
+                    ComponentKind::Schema => item(components.schemas.get(&name)?, Node::Schema),
+                    ComponentKind::Message => item(components.messages.get(&name)?, Node::Message),
+                    ComponentKind::SecurityScheme => item(
+                        components.security_schemes.get(&name)?,
+                        Node::SecurityScheme,
+                    ),
+                    ComponentKind::Parameter => {
+                        item(components.parameters.get(&name)?, Node::Parameter)
+                    }
+                    ComponentKind::CorrelationId => {
+                        item(components.correlation_ids.get(&name)?, Node::CorrelationId)
+                    }
+                    ComponentKind::OperationTrait => item(
+                        components.operation_traits.get(&name)?,
+                        Node::OperationTrait,
+                    ),
+                    ComponentKind::MessageTrait => {
+                        item(components.message_traits.get(&name)?, Node::MessageTrait)
+                    }
+                    ComponentKind::Server => item(components.servers.get(&name)?, Node::Server),
+                    ComponentKind::ServerVariable => item(
+                        components.server_variables.get(&name)?,
+                        Node::ServerVariable,
+                    ),
+                    ComponentKind::ServerBinding => {
+                        item(components.server_bindings.get(&name)?, Node::ServerBinding)
+                    }
+                    ComponentKind::Channel => Node::Channel(components.channels.get(&name)?),
+                    ComponentKind::ChannelBinding => item(
+                        components.channel_bindings.get(&name)?,
+                        Node::ChannelBinding,
+                    ),
+                    ComponentKind::OperationBinding => item(
+                        components.operation_bindings.get(&name)?,
+                        Node::OperationBinding,
+                    ),
+                    ComponentKind::MessageBinding => item(
+                        components.message_bindings.get(&name)?,
+                        Node::MessageBinding,
+                    ),
+                })
+            }
+            Node::ServerBinding(binding) => extension(&binding.extensions, segment),
+            Node::ChannelBinding(binding) => extension(&binding.extensions, segment),
+            Node::OperationBinding(binding) => extension(&binding.extensions, segment),
+            Node::MessageBinding(binding) => extension(&binding.extensions, segment),
+            Node::Value(value) => match value {
+                serde_json::Value::Object(object) => object.get(segment).map(Node::Value),
+                serde_json::Value::Array(values) => {
+                    values.get(segment.parse::<usize>().ok()?).map(Node::Value)
+                }
+                _ => None,
+            },
+            Node::Contact(_)
+            | Node::License(_)
+            | Node::ServerVariable(_)
+            | Node::CorrelationId(_)
+            | Node::SecurityScheme(_)
+            | Node::Tag(_)
+            | Node::ExternalDocumentation(_)
+            | Node::Reference(_) => None,
+        }
+    }
+}
+
+fn schema_child<'a>(
+    schema: &'a Schema,
+    segment: &str,
+    rest: &mut impl Iterator<Item = String>,
+) -> Option<Node<'a>> {
+    let (properties, additional_properties, items) = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => (
+            Some(&object.properties),
+            object.additional_properties.as_ref(),
+            None,
+        ),
+        SchemaKind::Type(Type::Array(array)) => (None, None, array.items.as_ref()),
+        SchemaKind::Any(any) => (
+            Some(&any.properties),
+            any.additional_properties.as_ref(),
+            any.items.as_ref(),
+        ),
+        SchemaKind::OneOf { one_of: schemas } if segment == "oneOf" => {
+            return Some(item(index(schemas, rest)?, Node::Schema))
+        }
+        SchemaKind::AllOf { all_of: schemas } if segment == "allOf" => {
+            return Some(item(index(schemas, rest)?, Node::Schema))
+        }
+        SchemaKind::AnyOf { any_of: schemas } if segment == "anyOf" => {
+            return Some(item(index(schemas, rest)?, Node::Schema))
+        }
+        _ => return None,
+    };
+    match segment {
+        "properties" => Some(boxed_item(properties?.get(&rest.next()?)?)),
+        "additionalProperties" => match additional_properties? {
+            AdditionalProperties::Schema(schema) => Some(item(schema, Node::Schema)),
+            AdditionalProperties::Any(_) => None,
+        },
+        "items" => Some(boxed_item(items?)),
+        _ => None,
+    }
+}
+
+/// The node of the item, or of the reference.
+fn item<'a, T>(item: &'a ReferenceOr<T>, node: fn(&'a T) -> Node<'a>) -> Node<'a> {
+    match item {
+        ReferenceOr::Reference { reference } => Node::Reference(reference),
+        ReferenceOr::Item(item) => node(item),
+    }
+}
+
+fn boxed_item(schema: &ReferenceOr<Box<Schema>>) -> Node<'_> {
+    match schema {
+        ReferenceOr::Reference { reference } => Node::Reference(reference),
+        ReferenceOr::Item(schema) => Node::Schema(schema),
+    }
+}
+
+/// The element of `items` at the index in the next segment.
+fn index<'a, T>(items: &'a [T], rest: &mut impl Iterator<Item = String>) -> Option<&'a T> {
+    items.get(rest.next()?.parse::<usize>().ok()?)
+}
+
+fn extension<'a>(
+    extensions: &'a IndexMap<String, serde_json::Value>,
+    segment: &str,
+) -> Option<Node<'a>> {
+    extensions.get(segment).map(Node::Value)
+}
+
+#[test]
+fn test_lookup() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+      x-owner:
+        team: accounts
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            oneOf:
+              - $ref: '#/components/messages/UserSignedUp'
+              - name: UserUpdated
+    components:
+      messages:
+        UserSignedUp:
+          payload:
+            type: object
+            properties:
+              tags:
+                type: array
+                items:
+                  type: string
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(api.lookup(""), Some(Node::AsyncAPI(&api)));
+    assert!(matches!(
+        api.lookup("/channels/user~1signedup/subscribe/message/oneOf/1"),
+        Some(Node::Message(message)) if message.name.as_deref() == Some("UserUpdated")
+    ));
+    assert!(matches!(
+        api.lookup("/components/messages/UserSignedUp/payload/properties/tags/items"),
+        Some(Node::Schema(_))
+    ));
+    assert_eq!(
+        api.lookup("/info/x-owner/team"),
+        Some(Node::Value(&serde_json::json!("accounts")))
+    );
+    assert_eq!(
+        api.lookup("/channels/user~1signedup/subscribe/message/oneOf/0/payload"),
+        None
+    );
+    assert_eq!(api.lookup("/info/title"), None);
+    assert_eq!(api.lookup("channels"), None);
+}
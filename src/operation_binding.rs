@@ -1,7 +1,13 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::Schema;
+use crate::{
+    binding_builder::binding_builder,
+    channel_binding::SQSQueue,
+    schema::{IntegerOrSchema, StringOrSchema},
+    validation::{Validate, ValidationError},
+    BindingVersion, Schema,
+};
 
 /// Map describing protocol-specific definitions for an operation.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -54,12 +60,50 @@ pub struct OperationBinding {
     /// Protocol-specific information for a Mercure operation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mercure: Option<MercureOperationBinding>,
+    /// Protocol-specific information for an IBM MQ operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ibmmq: Option<IBMMQOperationBinding>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
+    /// Bindings of other protocols are kept here as well, see
+    /// [custom][OperationBinding::custom].
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl OperationBinding {
+    /// The bindings of protocols this crate doesn't define a binding object
+    /// for, i.e. the [extensions][OperationBinding::extensions] that aren't
+    /// specification extensions.
+    pub fn custom(&self) -> impl Iterator<Item = (&str, &serde_json::Value)> {
+        self.extensions
+            .iter()
+            .filter(|(protocol, _)| !protocol.starts_with("x-"))
+            .map(|(protocol, binding)| (protocol.as_str(), binding))
+    }
+
+    /// The binding for `protocol` among the [custom][OperationBinding::custom] ones.
+    pub fn custom_binding(&self, protocol: &str) -> Option<&serde_json::Value> {
+        self.extensions
+            .get(protocol)
+            .filter(|_| !protocol.starts_with("x-"))
+    }
+}
+
+impl Validate for OperationBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(http) = &self.http {
+            http.validate_at(&format!("{path}/http"), errors);
+        }
+        if let Some(amqp) = &self.amqp {
+            amqp.validate_at(&format!("{path}/amqp"), errors);
+        }
+        if let Some(mqtt) = &self.mqtt {
+            mqtt.validate_at(&format!("{path}/mqtt"), errors);
+        }
+    }
+}
+
 ///
 ///
 /// # Examples
@@ -90,22 +134,83 @@ pub struct HTTPOperationBinding {
     #[serde(rename = "type")]
     pub typ: String,
     /// When `type` is `request`, this is the HTTP method, otherwise it MUST be ignored.
-    /// Its value MUST be one of `GET`, `POST`, `PUT`, `PATCH`, `DELETE`, `HEAD`,
-    /// `OPTIONS`, `CONNECT`, and `TRACE`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub method: Option<String>,
+    pub method: Option<HttpMethod>,
     /// A Schema object containing the definitions for each query parameter.
     /// This schema MUST be of type `object` and have a `properties` key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<Schema>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+impl Validate for HTTPOperationBinding {
+    /// `type` was dropped in version 0.3.0 of the binding, so it is only
+    /// checked for earlier versions.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if !self.binding_version.is_at_least(0, 3, 0)
+            && !matches!(self.typ.as_str(), "request" | "response")
+        {
+            errors.push(ValidationError::new(
+                format!("{path}/type"),
+                format!("`{}` is neither request nor response", self.typ),
+            ));
+        }
+    }
+}
+
+/// The method of an HTTP request, used by the HTTP and WebSockets bindings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+    Connect,
+    Trace,
+    /// A method not defined by the binding.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+impl HttpMethod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Options => "OPTIONS",
+            HttpMethod::Connect => "CONNECT",
+            HttpMethod::Trace => "TRACE",
+            HttpMethod::Other(method) => method,
+        }
+    }
+}
+
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The WebSockets binding doesn't define any fields for operations, as the
+/// channel represents the connection and its handshake. The name is reserved
+/// for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct WebSocketsOperationBinding {}
+pub struct WebSocketsOperationBinding {
+    /// Fields not defined by the binding, e.g. from a newer binding version,
+    /// kept so they survive a round-trip.
+    #[serde(flatten)]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
 
 /// This object contains information about the operation representation in Kafka.
 ///
@@ -128,15 +233,18 @@ pub struct WebSocketsOperationBinding {}
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct KafkaOperationBinding {
-    /// Id of the consumer group.
+    /// Id of the consumer group. The binding defines it as a schema, plain
+    /// strings as written by older documents are accepted as well.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_id: Option<Schema>,
-    /// Id of the consumer inside a consumer group.
+    pub group_id: Option<StringOrSchema>,
+    /// Id of the consumer inside a consumer group. The binding defines it as a
+    /// schema, plain strings as written by older documents are accepted as
+    /// well.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<Schema>,
+    pub client_id: Option<StringOrSchema>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
@@ -168,9 +276,11 @@ pub struct AnyPointMQOperationBinding {}
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AMQPOperationBinding {
-    /// TTL (Time-To-Live) for the message. It MUST be greater than or equal to zero.
+    /// TTL (Time-To-Live) for the message in milliseconds. MUST be greater
+    /// than or equal to zero. Negative values are kept when parsing and
+    /// reported when validating the binding.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<i32>,
+    pub expiration: Option<i64>,
     /// Identifies the user who has sent the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
@@ -180,9 +290,9 @@ pub struct AMQPOperationBinding {
     /// A priority for the message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
-    /// Delivery mode of the message. Its value MUST be either 1 (transient) or 2 (persistent).
+    /// Delivery mode of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_mode: Option<i32>,
+    pub delivery_mode: Option<AMQPOperationBindingDeliveryMode>,
     /// Whether the message is mandatory or not.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mandatory: Option<bool>,
@@ -199,8 +309,64 @@ pub struct AMQPOperationBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ack: Option<bool>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+/// The delivery mode of an AMQP message, written as `1` for transient or `2`
+/// for persistent messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AMQPOperationBindingDeliveryMode {
+    Transient,
+    Persistent,
+    /// A delivery mode not defined by the binding.
+    Other(i64),
+}
+
+impl AMQPOperationBindingDeliveryMode {
+    pub fn as_i64(self) -> i64 {
+        match self {
+            AMQPOperationBindingDeliveryMode::Transient => 1,
+            AMQPOperationBindingDeliveryMode::Persistent => 2,
+            AMQPOperationBindingDeliveryMode::Other(mode) => mode,
+        }
+    }
+}
+
+impl Serialize for AMQPOperationBindingDeliveryMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_i64())
+    }
+}
+
+impl<'de> Deserialize<'de> for AMQPOperationBindingDeliveryMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match i64::deserialize(deserializer)? {
+            1 => Ok(AMQPOperationBindingDeliveryMode::Transient),
+            2 => Ok(AMQPOperationBindingDeliveryMode::Persistent),
+            mode => {
+                crate::parse::check_unknown(&mode.to_string())?;
+                Ok(AMQPOperationBindingDeliveryMode::Other(mode))
+            }
+        }
+    }
+}
+
+impl Validate for AMQPOperationBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(expiration) = self.expiration.filter(|expiration| *expiration < 0) {
+            errors.push(ValidationError::new(
+                format!("{path}/expiration"),
+                format!("`{expiration}` must be greater than or equal to 0"),
+            ));
+        }
+        if let Some(AMQPOperationBindingDeliveryMode::Other(mode)) = self.delivery_mode {
+            errors.push(ValidationError::new(
+                format!("{path}/deliveryMode"),
+                format!("`{mode}` is neither 1 (transient) nor 2 (persistent)"),
+            ));
+        }
+    }
 }
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
@@ -233,13 +399,47 @@ pub struct MQTTOperationBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retain: Option<bool>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+impl Validate for MQTTOperationBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(qos) = self.qos {
+            if !(0..=2).contains(&qos) {
+                errors.push(ValidationError::new(
+                    format!("{path}/qos"),
+                    format!("`{qos}` is neither 0, 1 nor 2"),
+                ));
+            }
+        }
+    }
+}
+
+/// This object contains information about the operation representation in MQTT 5.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user/signup:
+///     publish:
+///       bindings:
+///         mqtt5:
+///           messageExpiryInterval: 60
+///           bindingVersion: 0.2.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct MQTT5OperationBinding {}
+#[serde(rename_all = "camelCase")]
+pub struct MQTT5OperationBinding {
+    /// Lifetime of the message in seconds or a Schema Object containing the
+    /// definition of the lifetime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_expiry_interval: Option<IntegerOrSchema>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -248,17 +448,163 @@ pub struct NATSOperationBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub queue: Option<String>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
 }
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct JMSOperationBinding {}
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains information about the operation representation in SNS.
+/// A publish operation describes the subscriptions, i.e. the consumers, of the
+/// SNS topic.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user-signedup:
+///     publish:
+///       bindings:
+///         sns:
+///           consumers:
+///             - protocol: sqs
+///               endpoint:
+///                 name: myQueue
+///               filterPolicy:
+///                 store:
+///                   - asyncapi_corp
+///               rawMessageDelivery: false
+///               redrivePolicy:
+///                 deadLetterQueue:
+///                   arn: arn:aws:SQS:eu-west-1:0000000:123456789
+///                 maxReceiveCount: 25
+///               deliveryPolicy:
+///                 minDelayTarget: 10
+///                 maxDelayTarget: 100
+///                 numRetries: 5
+///                 backoffFunction: linear
+///           bindingVersion: 0.1.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct SNSOperationBinding {}
+#[serde(rename_all = "camelCase")]
+pub struct SNSOperationBinding {
+    /// The SNS topic, if it isn't the channel name, e.g. to supply its ARN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<SNSIdentifier>,
+    /// The protocols that listen to this topic and their endpoints. Required
+    /// for publish operations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumers: Vec<SNSConsumer>,
+    /// Policy for retries to HTTP. The field is the default for HTTP
+    /// consumers, which can override it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_policy: Option<SNSDeliveryPolicy>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+/// Identifies an AWS resource or endpoint. Which field to use depends on the
+/// protocol of the consumer.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SNSIdentifier {
+    /// The endpoint is a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The endpoint is an email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The endpoint is a phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// The target is an ARN, e.g. of an SQS queue or a Lambda function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// The endpoint is identified by a name, which corresponds to an
+    /// identifying field called `name` of a binding for that protocol on this
+    /// publish operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SNSConsumer {
+    /// The protocol that this endpoint receives messages by, one of `http`,
+    /// `https`, `email`, `email-json`, `sms`, `sqs`, `application`, `lambda`
+    /// or `firehose`.
+    pub protocol: String,
+    /// The endpoint messages are delivered to.
+    pub endpoint: SNSIdentifier,
+    /// Only receive a subset of messages from the channel, determined by this
+    /// policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_policy: Option<serde_json::Value>,
+    /// Whether the filter policy applies to `MessageAttributes` (default) or
+    /// `MessageBody`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_policy_scope: Option<String>,
+    /// If `true`, AWS SNS attributes are removed from the body, and for SQS,
+    /// SNS message attributes are copied to SQS message attributes.
+    pub raw_message_delivery: bool,
+    /// Prevent message loss by sending failed messages to a dead-letter queue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redrive_policy: Option<SNSRedrivePolicy>,
+    /// Policy for retries to HTTP, overriding the one of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_policy: Option<SNSDeliveryPolicy>,
+    /// The display name to use with an SNS subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SNSRedrivePolicy {
+    /// The SQS queue to use as a dead letter queue.
+    pub dead_letter_queue: SNSIdentifier,
+    /// The number of times a message is delivered to the source queue before
+    /// being moved to the dead-letter queue. Defaults to `10`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_receive_count: Option<u32>,
+}
+
+/// The retry policy for HTTP and HTTPS consumers, see the
+/// [AWS documentation](https://docs.aws.amazon.com/sns/latest/dg/sns-message-delivery-retries.html).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SNSDeliveryPolicy {
+    /// The minimum delay for a retry in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_delay_target: Option<u32>,
+    /// The maximum delay for a retry in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay_target: Option<u32>,
+    /// The total number of retries, including immediate, pre-backoff,
+    /// backoff, and post-backoff retries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_retries: Option<u32>,
+    /// The number of immediate retries (with no delay).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_no_delay_retries: Option<u32>,
+    /// The number of immediate retries (with delay).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_min_delay_retries: Option<u32>,
+    /// The number of post-backoff phase retries, with the maximum delay
+    /// between retries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_max_delay_retries: Option<u32>,
+    /// The algorithm for backoff between retries, one of `arithmetic`,
+    /// `exponential`, `geometric` or `linear`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_function: Option<String>,
+    /// The maximum number of deliveries per second, per subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_receives_per_second: Option<u32>,
+}
 
 /// We need the ability to support several bindings for each operation, see the
 /// [Example](https://github.com/asyncapi/bindings/tree/master/solace#example)
@@ -267,8 +613,8 @@ pub struct SNSOperationBinding {}
 #[serde(rename_all = "camelCase")]
 pub struct SolaceOperationBinding {
     /// The current version is 0.2.0
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub destinations: Vec<SolaceDestination>,
 }
@@ -283,35 +629,38 @@ pub struct SolaceDestination {
     /// to the queue, which in turn will subscribe to the topic as represented
     /// by the channel name or to the provided topicSubscriptions.
     #[serde(skip_serializing_if = "Option::is_none")]
-    destination_type: Option<SolaceDestinationType>,
+    pub destination_type: Option<SolaceDestinationType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    delivery_mode: Option<SolaceDestinationDeliveryMode>,
+    pub delivery_mode: Option<SolaceDestinationDeliveryMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    queue: Option<SolaceDestinationQueue>,
+    pub queue: Option<SolaceDestinationQueue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    topic: Option<SolaceDestinationTopic>,
+    pub topic: Option<SolaceDestinationTopic>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum SolaceDestinationType {
     Queue,
     Topic,
+    /// A destination type not defined by the binding.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
 }
 
 /// 'direct' or 'persistent'. This determines the quality of service for
 /// publishing messages as documented
 /// [here](https://docs.solace.com/PubSub-Basics/Core-Concepts-Message-Delivery-Modes.htm).
 /// Default is 'persistent'.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum SolaceDestinationDeliveryMode {
     Direct,
+    #[default]
     Persistent,
-}
-
-impl Default for SolaceDestinationDeliveryMode {
-    fn default() -> Self {
-        SolaceDestinationDeliveryMode::Persistent
-    }
+    /// A delivery mode not defined by the binding.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -319,23 +668,27 @@ impl Default for SolaceDestinationDeliveryMode {
 pub struct SolaceDestinationQueue {
     /// The name of the queue, only applicable when destinationType is 'queue'.
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub name: Option<String>,
     /// A list of topics that the queue subscribes to, only applicable when
     /// destinationType is 'queue'. If none is given, the queue subscribes to
     /// the topic as represented by the channel name.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    topic_subscriptions: Vec<String>,
+    pub topic_subscriptions: Vec<String>,
     /// 'exclusive' or 'nonexclusive'. This is documented
     /// [here](https://docs.solace.com/PubSub-Basics/Endpoints.htm).
     /// Only applicable when destinationType is 'queue'.
     #[serde(skip_serializing_if = "Option::is_none")]
-    access_type: Option<SolaceDestinationQueueAccessType>,
+    pub access_type: Option<SolaceDestinationQueueAccessType>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum SolaceDestinationQueueAccessType {
     Exclusive,
     Nonexclusive,
+    /// An access type not defined by the binding.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -344,12 +697,42 @@ pub struct SolaceDestinationTopic {
     /// A list of topics that the client subscribes to, only applicable when
     /// destinationType is 'topic'. If none is given, the client subscribes to
     /// the topic as represented by the channel name.
-    topic_subscriptions: Vec<String>,
+    pub topic_subscriptions: Vec<String>,
 }
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains information about the operation representation in SQS.
+/// Use it to describe the SQS queues an SNS topic fans out to, when the
+/// channel is the SNS topic.
+///
+/// # Examples
+///
+/// ```yaml
+/// channels:
+///   user-signedup:
+///     publish:
+///       bindings:
+///         sqs:
+///           queues:
+///             - name: user-signedup-queue
+///               fifoQueue: false
+///               redrivePolicy:
+///                 deadLetterQueue:
+///                   name: user-signedup-dlq
+///             - name: user-signedup-dlq
+///               fifoQueue: false
+///               messageRetentionPeriod: 1209600
+///           bindingVersion: 0.2.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct SQSOperationBinding {}
+#[serde(rename_all = "camelCase")]
+pub struct SQSOperationBinding {
+    /// Queue objects that are either the endpoint for an SNS operation binding
+    /// or the dead letter queue of another queue.
+    pub queues: Vec<SQSQueue>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -362,3 +745,207 @@ pub struct RedisOperationBinding {}
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct MercureOperationBinding {}
+
+/// This object MUST NOT contain any properties. Its name is reserved for future use.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct IBMMQOperationBinding {}
+
+binding_builder! {
+    HTTPOperationBinding => HTTPOperationBindingBuilder {
+        typ: String,
+        method: Option<HttpMethod>,
+        query: Option<Schema>,
+        binding_version: BindingVersion,
+    }
+    AMQPOperationBinding => AMQPOperationBindingBuilder {
+        expiration: Option<i64>,
+        user_id: Option<String>,
+        cc: Vec<String>,
+        priority: Option<i32>,
+        delivery_mode: Option<AMQPOperationBindingDeliveryMode>,
+        mandatory: Option<bool>,
+        bcc: Vec<String>,
+        reply_to: Option<String>,
+        timestamp: Option<bool>,
+        ack: Option<bool>,
+        binding_version: BindingVersion,
+    }
+    SNSConsumer => SNSConsumerBuilder {
+        protocol: String,
+        endpoint: SNSIdentifier,
+        filter_policy: Option<serde_json::Value>,
+        filter_policy_scope: Option<String>,
+        raw_message_delivery: bool,
+        redrive_policy: Option<SNSRedrivePolicy>,
+        delivery_policy: Option<SNSDeliveryPolicy>,
+        display_name: Option<String>,
+    }
+    SNSDeliveryPolicy => SNSDeliveryPolicyBuilder {
+        min_delay_target: Option<u32>,
+        max_delay_target: Option<u32>,
+        num_retries: Option<u32>,
+        num_no_delay_retries: Option<u32>,
+        num_min_delay_retries: Option<u32>,
+        num_max_delay_retries: Option<u32>,
+        backoff_function: Option<String>,
+        max_receives_per_second: Option<u32>,
+    }
+}
+
+#[test]
+fn test_websockets_operation_binding_keeps_fields() {
+    let binding: OperationBinding = serde_yaml::from_str("ws:\n  bindingVersion: 0.1.0\n").unwrap();
+    let ws = binding.ws.as_ref().unwrap();
+    assert_eq!(ws.extensions["bindingVersion"], "0.1.0");
+    assert_eq!(
+        serde_yaml::to_string(&binding).unwrap(),
+        "ws:\n  bindingVersion: 0.1.0\n"
+    );
+}
+
+#[test]
+fn test_sns_operation_binding() {
+    let binding: OperationBinding = serde_yaml::from_str(
+        r#"
+        sns:
+          consumers:
+            - protocol: sqs
+              endpoint:
+                name: myQueue
+              filterPolicy:
+                store:
+                  - asyncapi_corp
+              rawMessageDelivery: false
+              redrivePolicy:
+                deadLetterQueue:
+                  arn: arn:aws:SQS:eu-west-1:0000000:123456789
+                maxReceiveCount: 25
+              deliveryPolicy:
+                minDelayTarget: 10
+                maxDelayTarget: 100
+                numRetries: 5
+                backoffFunction: linear
+          bindingVersion: 0.1.0
+        "#,
+    )
+    .unwrap();
+    let consumer = &binding.sns.as_ref().unwrap().consumers[0];
+    assert_eq!(consumer.endpoint.name.as_deref(), Some("myQueue"));
+    assert_eq!(
+        consumer.redrive_policy.as_ref().unwrap().max_receive_count,
+        Some(25)
+    );
+    assert_eq!(
+        consumer
+            .delivery_policy
+            .as_ref()
+            .unwrap()
+            .backoff_function
+            .as_deref(),
+        Some("linear")
+    );
+}
+
+#[test]
+fn test_http_method() {
+    let binding: HTTPOperationBinding =
+        serde_yaml::from_str("type: request\nmethod: POST").unwrap();
+    assert_eq!(binding.method, Some(HttpMethod::Post));
+    let binding: HTTPOperationBinding =
+        serde_yaml::from_str("type: request\nmethod: PROPFIND").unwrap();
+    assert_eq!(
+        binding.method,
+        Some(HttpMethod::Other("PROPFIND".to_string()))
+    );
+    assert_eq!(
+        serde_json::to_value(HttpMethod::Options).unwrap(),
+        "OPTIONS"
+    );
+}
+
+#[test]
+fn test_kafka_client_id() {
+    let binding: KafkaOperationBinding = serde_yaml::from_str(
+        r#"
+        groupId:
+          type: string
+          enum: ['myGroupId']
+        clientId: myClientId
+        "#,
+    )
+    .unwrap();
+    assert!(matches!(binding.group_id, Some(StringOrSchema::Schema(_))));
+    assert_eq!(
+        binding.client_id,
+        Some(StringOrSchema::String("myClientId".to_string()))
+    );
+}
+
+#[test]
+fn test_amqp_operation_binding() {
+    let binding: AMQPOperationBinding =
+        serde_yaml::from_str("expiration: 100000\ndeliveryMode: 2").unwrap();
+    assert_eq!(binding.expiration, Some(100000));
+    assert_eq!(
+        binding.delivery_mode,
+        Some(AMQPOperationBindingDeliveryMode::Persistent)
+    );
+    assert!(binding.validate().is_ok());
+    assert_eq!(serde_json::to_value(&binding).unwrap()["deliveryMode"], 2);
+
+    let binding: AMQPOperationBinding = serde_yaml::from_str("expiration: -1").unwrap();
+    assert_eq!(binding.expiration, Some(-1));
+    assert!(binding.validate().is_err());
+    let binding: AMQPOperationBinding = serde_yaml::from_str("deliveryMode: 3").unwrap();
+    assert!(binding.validate().is_err());
+    assert!(crate::ParseOptions::new()
+        .strict(true)
+        .parse::<AMQPOperationBinding>("deliveryMode: 3")
+        .is_err());
+}
+
+#[test]
+fn test_solace_destination_literal() {
+    let binding = SolaceOperationBinding {
+        binding_version: "0.2.0".into(),
+        destinations: vec![
+            SolaceDestination {
+                destination_type: Some(SolaceDestinationType::Queue),
+                delivery_mode: Some(SolaceDestinationDeliveryMode::Persistent),
+                queue: Some(SolaceDestinationQueue {
+                    name: Some("CreatedHREvents".to_string()),
+                    topic_subscriptions: vec!["person/*/created".to_string()],
+                    access_type: Some(SolaceDestinationQueueAccessType::Exclusive),
+                }),
+                topic: None,
+            },
+            SolaceDestination {
+                destination_type: Some(SolaceDestinationType::Topic),
+                delivery_mode: None,
+                queue: None,
+                topic: Some(SolaceDestinationTopic {
+                    topic_subscriptions: vec!["person/*/updated".to_string()],
+                }),
+            },
+        ],
+    };
+    let parsed: SolaceOperationBinding = serde_yaml::from_str(
+        r#"
+        bindingVersion: 0.2.0
+        destinations:
+          - destinationType: queue
+            deliveryMode: persistent
+            queue:
+              name: CreatedHREvents
+              topicSubscriptions:
+                - person/*/created
+              accessType: exclusive
+          - destinationType: topic
+            topic:
+              topicSubscriptions:
+                - person/*/updated
+        "#,
+    )
+    .unwrap();
+    assert_eq!(parsed, binding);
+}
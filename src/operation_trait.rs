@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::{ExternalDocumentation, OperationBinding, ReferenceOr, Tag};
+use crate::{ExternalDocumentation, OperationBinding, ReferenceOr, SecurityRequirement, Tag};
 
 /// Describes a trait that MAY be applied to an
 /// [Operation Object][crate::Operation].
@@ -46,6 +46,15 @@ pub struct OperationTrait {
     /// can be used for rich text representation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// A declaration of which security mechanisms are associated with this
+    /// operation. Only one of the security requirement objects MUST be
+    /// satisfied to authorize an operation. In cases where
+    /// [Server Security][crate::Server::security] also applies, it MUST also
+    /// be satisfied.
+    ///
+    /// Added in AsyncAPI 2.4.0.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<SecurityRequirement>,
     /// A list of tags for API documentation control.
     /// Tags can be used for logical grouping of operations.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -59,6 +68,6 @@ pub struct OperationTrait {
     pub bindings: Option<ReferenceOr<OperationBinding>>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
@@ -0,0 +1,530 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    thread::LocalKey,
+};
+
+use indexmap::IndexMap;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::{
+    error::FormatError,
+    validation::{self, ValidationError},
+    Error,
+};
+
+thread_local! {
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+    static DENY_UNKNOWN_FIELDS: Cell<bool> = const { Cell::new(false) };
+    static UNKNOWN_FIELD: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Options to control how documents are parsed.
+///
+/// Parsing with the default options is the same as using [`str::parse`] on
+/// an [`AsyncAPI`][crate::AsyncAPI] document.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::{AsyncAPI, ParseOptions};
+///
+/// let input = r#"
+/// asyncapi: 2.3.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels: {}
+/// components:
+///   securitySchemes:
+///     iam:
+///       type: awsIam
+/// "#;
+/// assert!(input.parse::<AsyncAPI>().is_ok());
+/// assert!(ParseOptions::new()
+///     .strict(true)
+///     .parse::<AsyncAPI>(input)
+///     .is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    strict: bool,
+    deny_unknown_fields: bool,
+    env_vars: Vec<String>,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// When enabled, values that are not defined by the specification, like a
+    /// security scheme of an unknown `type`, are rejected instead of being kept
+    /// in an `Other` variant.
+    ///
+    /// Schema `format`s are not affected, JSON Schema allows any format.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// When enabled, fields that are neither defined by the specification nor
+    /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions)
+    /// starting with `x-` are rejected instead of being kept in `extensions`,
+    /// which catches typos like `summmary`.
+    ///
+    /// Binding objects keep accepting unknown fields, they hold the bindings
+    /// of protocols this crate doesn't know.
+    ///
+    /// Objects that may also be a reference, like messages, are parsed by
+    /// trying each form in turn and serde drops the error of every form that
+    /// failed. For an unknown field inside them the error names the last
+    /// unknown field seen while trying, e.g. ``data did not match any variant
+    /// of untagged enum ReferenceOr, possibly because of the unknown field
+    /// `summmary` ``.
+    ///
+    /// The `message` of an operation is read as a single message, rather
+    /// than a map of messages, as soon as one of its keys is a typo of a field
+    /// of a message, so that a typo like `payloadd` is reported as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{Info, ParseOptions};
+    ///
+    /// let input = "{title: Account Service, version: 1.0.0, summmary: Accounts}";
+    /// assert!(ParseOptions::new().parse::<Info>(input).is_ok());
+    /// let error = ParseOptions::new()
+    ///     .deny_unknown_fields(true)
+    ///     .parse::<Info>(input)
+    ///     .unwrap_err();
+    /// assert!(error.to_string().contains("unknown field `summmary`"));
+    /// ```
+    pub fn deny_unknown_fields(mut self, deny: bool) -> Self {
+        self.deny_unknown_fields = deny;
+        self
+    }
+
+    /// Expands `${NAME}` placeholders in the `url` and the variable `default`s
+    /// of the servers of a document with the value of the environment variable
+    /// `NAME`, for every `NAME` in `allowed`. Placeholders of other names are
+    /// kept as they are.
+    ///
+    /// Both the `servers` of the document and the `servers` and
+    /// `serverVariables` of its [Components Object][crate::Components] are
+    /// expanded. Parsing fails if
+    /// an allowed variable is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{AsyncAPI, ParseOptions, ReferenceOr};
+    ///
+    /// std::env::set_var("BROKER_HOST", "broker.staging.example.com");
+    /// let api: AsyncAPI = ParseOptions::new()
+    ///     .substitute_env(["BROKER_HOST"])
+    ///     .parse(
+    ///         r#"
+    ///         asyncapi: 2.3.0
+    ///         info:
+    ///           title: Account Service
+    ///           version: 1.0.0
+    ///         servers:
+    ///           default:
+    ///             url: ${BROKER_HOST}:9092
+    ///             protocol: kafka
+    ///         channels: {}
+    ///         "#,
+    ///     )
+    ///     .unwrap();
+    /// match &api.servers["default"] {
+    ///     ReferenceOr::Item(server) => assert_eq!(server.url, "broker.staging.example.com:9092"),
+    ///     ReferenceOr::Reference { .. } => unreachable!(),
+    /// }
+    /// ```
+    pub fn substitute_env<I, S>(mut self, allowed: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.env_vars = allowed.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Parses a document, or a part of one, from YAML or JSON.
+    pub fn parse<T: DeserializeOwned>(&self, input: &str) -> Result<T, Error> {
+        let _guards = self.guards();
+        if self.env_vars.is_empty() {
+            return serde_yaml::from_str(input)
+                .map_err(|error| Error::Parse(FormatError::Yaml(with_unknown_field(error))));
+        }
+        let value =
+            serde_yaml::from_str(input).map_err(|error| Error::Parse(FormatError::Yaml(error)))?;
+        self.deserialize(value)
+    }
+
+    /// Parses a document, or a part of one, from JSON.
+    pub fn parse_json<T: DeserializeOwned>(&self, input: &str) -> Result<T, Error> {
+        let _guards = self.guards();
+        if self.env_vars.is_empty() {
+            return serde_json::from_str(input)
+                .map_err(|error| Error::Parse(FormatError::Json(with_unknown_field(error))));
+        }
+        let value =
+            serde_json::from_str(input).map_err(|error| Error::Parse(FormatError::Json(error)))?;
+        self.deserialize(value)
+    }
+
+    fn guards(&self) -> [FlagGuard; 2] {
+        UNKNOWN_FIELD.with(RefCell::take);
+        [
+            FlagGuard::set(&STRICT, self.strict),
+            FlagGuard::set(&DENY_UNKNOWN_FIELDS, self.deny_unknown_fields),
+        ]
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, mut value: Value) -> Result<T, Error> {
+        let mut errors = Vec::new();
+        let lookup = |name: &str| std::env::var(name).ok();
+        for path in ["/servers", "/components/servers"] {
+            if let Some(Value::Object(servers)) = value.pointer_mut(path) {
+                for (name, server) in servers {
+                    let path = validation::join_pointer(path, name);
+                    self.substitute_server(&path, server, &lookup, &mut errors);
+                }
+            }
+        }
+        let path = "/components/serverVariables";
+        if let Some(Value::Object(variables)) = value.pointer_mut(path) {
+            for (name, variable) in variables {
+                let path = validation::join_pointer(path, name);
+                self.substitute_variable(&path, variable, &lookup, &mut errors);
+            }
+        }
+        if !errors.is_empty() {
+            return Err(Error::Validate(errors));
+        }
+        T::deserialize(value)
+            .map_err(|error| Error::Parse(FormatError::Json(with_unknown_field(error))))
+    }
+
+    fn substitute_server(
+        &self,
+        path: &str,
+        server: &mut Value,
+        lookup: &impl Fn(&str) -> Option<String>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(Value::String(url)) = server.get_mut("url") {
+            self.substitute(&format!("{path}/url"), url, lookup, errors);
+        }
+        if let Some(Value::Object(variables)) = server.get_mut("variables") {
+            for (name, variable) in variables {
+                let path = validation::join_pointer(&format!("{path}/variables"), name);
+                self.substitute_variable(&path, variable, lookup, errors);
+            }
+        }
+    }
+
+    fn substitute_variable(
+        &self,
+        path: &str,
+        variable: &mut Value,
+        lookup: &impl Fn(&str) -> Option<String>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if let Some(Value::String(default)) = variable.get_mut("default") {
+            self.substitute(&format!("{path}/default"), default, lookup, errors);
+        }
+    }
+
+    fn substitute(
+        &self,
+        path: &str,
+        text: &mut String,
+        lookup: &impl Fn(&str) -> Option<String>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find("${") {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+            let name = &rest[start + 2..start + end];
+            let placeholder = &rest[start..start + end + 1];
+            result.push_str(&rest[..start]);
+            if !self.env_vars.iter().any(|allowed| allowed == name) {
+                result.push_str(placeholder);
+            } else if let Some(value) = lookup(name) {
+                result.push_str(&value);
+            } else {
+                errors.push(ValidationError::new(
+                    path,
+                    format!("environment variable `{name}` is not set"),
+                ));
+                result.push_str(placeholder);
+            }
+            rest = &rest[start + end + 1..];
+        }
+        result.push_str(rest);
+        *text = result;
+    }
+}
+
+/// Restores the previous value of a flag when dropped, even if
+/// deserialization panics.
+struct FlagGuard {
+    flag: &'static LocalKey<Cell<bool>>,
+    previous: bool,
+}
+
+impl FlagGuard {
+    fn set(flag: &'static LocalKey<Cell<bool>>, value: bool) -> Self {
+        FlagGuard {
+            flag,
+            previous: flag.with(|cell| cell.replace(value)),
+        }
+    }
+}
+
+impl Drop for FlagGuard {
+    fn drop(&mut self) {
+        self.flag.with(|cell| cell.set(self.previous));
+    }
+}
+
+/// Adds the last unknown field seen to the error of an untagged enum, which
+/// doesn't tell why none of its variants matched.
+fn with_unknown_field<E: serde::de::Error + fmt::Display>(error: E) -> E {
+    match UNKNOWN_FIELD.with(RefCell::take) {
+        Some(field) if error.to_string().contains("did not match any variant") => E::custom(
+            format!("{error}, possibly because of the unknown field `{field}`"),
+        ),
+        _ => error,
+    }
+}
+
+/// Fails when an unknown value is encountered in [strict][ParseOptions::strict] mode.
+pub(crate) fn check_unknown<E: serde::de::Error>(value: &str) -> Result<(), E> {
+    if STRICT.with(Cell::get) {
+        Err(E::custom(format!("unknown value `{value}`")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether `key` may be a misspelling of one of `fields`, i.e. is one of them
+/// or is a typo away from one when
+/// [unknown fields are denied][ParseOptions::deny_unknown_fields].
+pub(crate) fn is_field_or_typo(key: &str, fields: &[&str]) -> bool {
+    let deny = DENY_UNKNOWN_FIELDS.with(Cell::get);
+    fields
+        .iter()
+        .any(|field| *field == key || deny && edit_distance(key, field) <= 2)
+}
+
+/// The number of characters to insert, delete or substitute to turn `a`
+/// into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Deserializes the string of an `Other` variant, see [`check_unknown`].
+pub(crate) fn deserialize_unknown<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    check_unknown(&value)?;
+    Ok(value)
+}
+
+/// Deserializes the flattened `extensions` of an object, failing on fields
+/// that aren't specification extensions when
+/// [unknown fields are denied][ParseOptions::deny_unknown_fields].
+pub(crate) fn deserialize_extensions<'de, D>(
+    deserializer: D,
+) -> Result<IndexMap<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let extensions = IndexMap::<String, Value>::deserialize(deserializer)?;
+    if DENY_UNKNOWN_FIELDS.with(Cell::get) {
+        if let Some(field) = extensions.keys().find(|field| !field.starts_with("x-")) {
+            UNKNOWN_FIELD.with(|unknown| unknown.replace(Some(field.clone())));
+            return Err(serde::de::Error::custom(format!("unknown field `{field}`")));
+        }
+    }
+    Ok(extensions)
+}
+
+#[test]
+fn test_strict_rejects_unknown_values() {
+    use crate::{HttpApiKeyLocation, SecurityScheme};
+
+    let input = "type: httpApiKey\nname: api_key\nin: path";
+    let lenient: SecurityScheme = ParseOptions::new().parse(input).unwrap();
+    assert!(matches!(
+        lenient,
+        SecurityScheme::HttpApiKey {
+            location: HttpApiKeyLocation::Other(_),
+            ..
+        }
+    ));
+    assert!(ParseOptions::new()
+        .strict(true)
+        .parse::<SecurityScheme>(input)
+        .is_err());
+
+    // Strictness does not leak into later parses.
+    assert!(serde_yaml::from_str::<SecurityScheme>(input).is_ok());
+}
+
+#[test]
+fn test_substitute_env() {
+    let options = ParseOptions::new().substitute_env(["HOST", "UNSET"]);
+    let lookup = |name: &str| match name {
+        "HOST" | "SECRET" => Some(format!("<{name}>")),
+        _ => None,
+    };
+
+    let mut errors = Vec::new();
+    let mut text = "${HOST}:${SECRET}/${HOST".to_string();
+    options.substitute("/servers/a/url", &mut text, &lookup, &mut errors);
+    assert_eq!(text, "<HOST>:${SECRET}/${HOST");
+    assert!(errors.is_empty());
+
+    let mut text = "${UNSET}".to_string();
+    options.substitute("/servers/a/url", &mut text, &lookup, &mut errors);
+    assert_eq!(
+        errors,
+        [ValidationError::new(
+            "/servers/a/url",
+            "environment variable `UNSET` is not set"
+        )]
+    );
+}
+
+#[test]
+fn test_substitute_env_in_component_server_variables() {
+    std::env::set_var("ASYNCAPI_TEST_BROKER_PORT", "9093");
+    let input = r#"
+    asyncapi: 2.4.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels: {}
+    components:
+      serverVariables:
+        port:
+          default: ${ASYNCAPI_TEST_BROKER_PORT}
+    "#;
+    let api: crate::AsyncAPI = ParseOptions::new()
+        .substitute_env(["ASYNCAPI_TEST_BROKER_PORT"])
+        .parse(input)
+        .unwrap();
+    let variables = &api.components.unwrap().server_variables;
+    match &variables["port"] {
+        crate::ReferenceOr::Item(variable) => {
+            assert_eq!(variable.default.as_deref(), Some("9093"))
+        }
+        crate::ReferenceOr::Reference { .. } => unreachable!(),
+    }
+
+    let error = ParseOptions::new()
+        .substitute_env(["ASYNCAPI_TEST_UNSET_PORT"])
+        .parse::<crate::AsyncAPI>(&input.replace("BROKER", "UNSET"))
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Validate(errors) if errors[0].path == "/components/serverVariables/port/default"
+    ));
+}
+
+#[test]
+fn test_deny_unknown_fields() {
+    use crate::AsyncAPI;
+
+    let input = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+      x-owner: accounts
+    channels:
+      user/signedup:
+        subscribe:
+          message:
+            summary: A user signed up.
+            bindings:
+              pulsar: {}
+    "#;
+    let options = ParseOptions::new().deny_unknown_fields(true);
+    assert!(options.parse::<AsyncAPI>(input).is_ok());
+    let typo = input.replace("summary", "summmary");
+    assert!(typo.parse::<AsyncAPI>().is_ok());
+    assert!(options.parse::<AsyncAPI>(&typo).is_err());
+    let error = AsyncAPI::from_str_strict(&typo).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("possibly because of the unknown field `summmary`"));
+}
+
+#[test]
+fn test_deny_unknown_fields_of_single_messages() {
+    use crate::{channel::OperationMessageType, Operation};
+
+    let input = "message: {payloadd: {type: string}}";
+    let lenient: Operation = ParseOptions::new().parse(input).unwrap();
+    assert!(matches!(
+        lenient.message,
+        Some(OperationMessageType::Map(_))
+    ));
+    let error = ParseOptions::new()
+        .deny_unknown_fields(true)
+        .parse::<Operation>(input)
+        .unwrap_err();
+    assert!(error.to_string().contains("unknown field `payloadd`"));
+
+    let map = "message: {UserSignedUp: {name: UserSignedUp}}";
+    let strict: Operation = ParseOptions::new()
+        .deny_unknown_fields(true)
+        .parse(map)
+        .unwrap();
+    assert!(matches!(strict.message, Some(OperationMessageType::Map(_))));
+}
+
+#[test]
+fn test_from_str_strict_rejects_unknown_values() {
+    let input = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels: {}
+    components:
+      securitySchemes:
+        iam:
+          type: awsIam
+    "#;
+    assert!(input.parse::<crate::AsyncAPI>().is_ok());
+    assert!(crate::AsyncAPI::from_str_strict(input).is_err());
+}
+
+#[test]
+fn test_fixture_has_no_unknown_fields() {
+    let input = include_str!("fixtures/streetlights_kafka.yml");
+    crate::AsyncAPI::from_str_strict(input).unwrap();
+}
@@ -1,5 +1,8 @@
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
+use crate::{Component, ComponentKind, Components, ResolveError};
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(untagged)]
 pub enum ReferenceOr<T> {
@@ -33,6 +36,79 @@ impl<T> ReferenceOr<T> {
     pub fn boxed_item(item: T) -> ReferenceOr<Box<T>> {
         ReferenceOr::Item(Box::new(item))
     }
+
+    /// A reference to the component of `kind` called `name`. Constructors for
+    /// each kind, like `ReferenceOr::<Schema>::schema`, check that the kind
+    /// fits the referenced type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{ComponentKind, Message, ReferenceOr, Schema};
+    ///
+    /// let user = ReferenceOr::<Schema>::schema("User");
+    /// assert_eq!(user, ReferenceOr::ref_("#/components/schemas/User"));
+    /// assert_eq!(user.component_kind(), Some(ComponentKind::Schema));
+    ///
+    /// let message = ReferenceOr::<Message>::component(ComponentKind::Message, "UserSignedUp");
+    /// assert_eq!(message.component_name().as_deref(), Some("UserSignedUp"));
+    /// ```
+    pub fn component(kind: ComponentKind, name: &str) -> Self {
+        ReferenceOr::ref_(&kind.pointer(name))
+    }
+
+    /// The kind of the component this points to, if it is a reference to a
+    /// component of the same document.
+    pub fn component_kind(&self) -> Option<ComponentKind> {
+        Some(self.parse_component()?.0)
+    }
+
+    /// The name of the component this points to, if it is a reference to a
+    /// component of the same document.
+    pub fn component_name(&self) -> Option<String> {
+        Some(self.parse_component()?.1)
+    }
+
+    fn parse_component(&self) -> Option<(ComponentKind, String)> {
+        match self {
+            ReferenceOr::Reference { reference } => ComponentKind::parse(reference),
+            ReferenceOr::Item(_) => None,
+        }
+    }
+}
+
+impl<T: Component> ReferenceOr<T> {
+    /// The item itself, or the component of `components` the reference
+    /// points to, following references between components.
+    ///
+    /// Fails if the reference points to another document, to another kind of
+    /// component or to a component that doesn't exist, and if references
+    /// between components form a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{Components, Message, ReferenceOr, ResolveError};
+    ///
+    /// let mut components = Components::default();
+    /// components.add_message("UserSignedUp", Message::builder().name("UserSignedUp").build());
+    ///
+    /// let message = ReferenceOr::<Message>::message("UserSignedUp");
+    /// assert_eq!(
+    ///     message.resolve(&components).unwrap().name.as_deref(),
+    ///     Some("UserSignedUp")
+    /// );
+    ///
+    /// let missing = ReferenceOr::<Message>::message("UserDeleted");
+    /// assert_eq!(
+    ///     missing.resolve(&components),
+    ///     Err(ResolveError::NotFound("#/components/messages/UserDeleted".to_string()))
+    /// );
+    /// ```
+    pub fn resolve<'a>(&'a self, components: &'a Components) -> Result<&'a T, ResolveError> {
+        let prefix = format!("#/components/{}/", T::KIND);
+        resolve_local(Some(T::components(components)), &prefix, self)
+    }
 }
 
 impl<T> ReferenceOr<Box<T>> {
@@ -43,3 +119,78 @@ impl<T> ReferenceOr<Box<T>> {
         }
     }
 }
+
+/// The last segment of a reference, e.g. `User` for `#/components/schemas/User`.
+pub(crate) fn reference_name(reference: &str) -> String {
+    reference
+        .rsplit(['/', '#'])
+        .find(|segment| !segment.is_empty())
+        .unwrap_or(reference)
+        .replace("~1", "/")
+        .replace("~0", "~")
+}
+
+/// What the keys of an object of a serialized document are, for walks that
+/// look for Reference Objects in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObjectKeys {
+    /// The keys are the fields of an object, e.g. a message.
+    Fields,
+    /// The keys are the fields of the components.
+    Components,
+    /// The keys name objects, e.g. the properties of a schema, and may well
+    /// be called like a field, e.g. `default`.
+    Names,
+}
+
+impl ObjectKeys {
+    /// What the keys of the object in the field `key` of an object with these
+    /// keys are. Returns `None` if the field holds literal data, i.e. an
+    /// example, a default, an enum, a constant or an extension, in which a
+    /// `$ref` is data rather than a reference.
+    pub(crate) fn child(self, key: &str) -> Option<ObjectKeys> {
+        match self {
+            ObjectKeys::Names => Some(ObjectKeys::Fields),
+            ObjectKeys::Components => Some(ObjectKeys::Names),
+            ObjectKeys::Fields => match key {
+                "example" | "examples" | "default" | "enum" | "const" => None,
+                key if key.starts_with("x-") => None,
+                "components" => Some(ObjectKeys::Components),
+                "properties" | "patternProperties" | "definitions" | "$defs"
+                | "dependentSchemas" | "channels" | "servers" | "operations" | "messages"
+                | "parameters" | "variables" => Some(ObjectKeys::Names),
+                _ => Some(ObjectKeys::Fields),
+            },
+        }
+    }
+}
+
+/// Follows local references to `{prefix}{name}` through `components` until an
+/// item is found.
+pub(crate) fn resolve_local<'a, T>(
+    components: Option<&'a IndexMap<String, ReferenceOr<T>>>,
+    prefix: &str,
+    mut item: &'a ReferenceOr<T>,
+) -> Result<&'a T, ResolveError> {
+    let mut seen = Vec::new();
+    loop {
+        let reference = match item {
+            ReferenceOr::Item(item) => return Ok(item),
+            ReferenceOr::Reference { reference } => reference,
+        };
+        if seen.contains(&reference) {
+            return Err(ResolveError::Cycle(reference.clone()));
+        }
+        seen.push(reference);
+        let name = match reference.strip_prefix(prefix) {
+            Some(name) => name.replace("~1", "/").replace("~0", "~"),
+            None if reference.starts_with('#') => {
+                return Err(ResolveError::NotFound(reference.clone()))
+            }
+            None => return Err(ResolveError::External(reference.clone())),
+        };
+        item = components
+            .and_then(|components| components.get(&name))
+            .ok_or_else(|| ResolveError::NotFound(reference.clone()))?;
+    }
+}
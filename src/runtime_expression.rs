@@ -0,0 +1,137 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{Validate, ValidationError};
+
+/// An expression pointing to a value in a message at runtime, e.g.
+/// `$message.header#/replyTo`.
+///
+/// The expression starts with the part of the message, `$message.header` or
+/// `$message.payload`, followed by a `#` and a
+/// [JSON Pointer](https://tools.ietf.org/html/rfc6901) into that part.
+///
+/// Any string is accepted when parsing a document, use
+/// [`validate`][Validate::validate] to check its format.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::{MessagePart, RuntimeExpression};
+/// use serde_json::json;
+///
+/// let location = RuntimeExpression::new("$message.header#/replyTo");
+/// assert_eq!(location.part(), Some(MessagePart::Header));
+/// assert_eq!(location.pointer(), Some("/replyTo"));
+///
+/// let headers = json!({ "replyTo": "users.inbox" });
+/// assert_eq!(
+///     location.evaluate(&headers, &json!({})),
+///     Some(&json!("users.inbox"))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RuntimeExpression(String);
+
+/// The part of a message a [RuntimeExpression] points into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessagePart {
+    Header,
+    Payload,
+}
+
+impl RuntimeExpression {
+    pub fn new(expression: impl Into<String>) -> Self {
+        RuntimeExpression(expression.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The part of the message the expression points into.
+    pub fn part(&self) -> Option<MessagePart> {
+        match self
+            .0
+            .split_once('#')
+            .map_or(self.0.as_str(), |(part, _)| part)
+        {
+            "$message.header" => Some(MessagePart::Header),
+            "$message.payload" => Some(MessagePart::Payload),
+            _ => None,
+        }
+    }
+
+    /// The JSON Pointer into the [part][RuntimeExpression::part] of the
+    /// message, e.g. `/replyTo` for `$message.header#/replyTo`.
+    pub fn pointer(&self) -> Option<&str> {
+        self.0.split_once('#').map(|(_, pointer)| pointer)
+    }
+
+    /// Looks up the value the expression points to in a message with the
+    /// given `headers` and `payload`.
+    pub fn evaluate<'a>(
+        &self,
+        headers: &'a serde_json::Value,
+        payload: &'a serde_json::Value,
+    ) -> Option<&'a serde_json::Value> {
+        let value = match self.part()? {
+            MessagePart::Header => headers,
+            MessagePart::Payload => payload,
+        };
+        value.pointer(self.pointer().unwrap_or_default())
+    }
+}
+
+impl Validate for RuntimeExpression {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let valid_pointer = self
+            .pointer()
+            .is_none_or(|pointer| pointer.is_empty() || pointer.starts_with('/'));
+        if self.part().is_none() || !valid_pointer {
+            errors.push(ValidationError::new(
+                path,
+                format!(
+                    "`{}` is not a runtime expression of the form `$message.header#/path` or `$message.payload#/path`",
+                    self.0
+                ),
+            ));
+        }
+    }
+}
+
+impl fmt::Display for RuntimeExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for RuntimeExpression {
+    fn from(expression: String) -> Self {
+        RuntimeExpression(expression)
+    }
+}
+
+impl From<&str> for RuntimeExpression {
+    fn from(expression: &str) -> Self {
+        RuntimeExpression(expression.to_string())
+    }
+}
+
+#[test]
+fn test_validate_runtime_expression() {
+    for valid in [
+        "$message.header#/replyTo",
+        "$message.payload#/user/id",
+        "$message.payload",
+    ] {
+        assert!(RuntimeExpression::new(valid).validate().is_ok(), "{valid}");
+    }
+    for invalid in ["$message.body#/id", "replyTo", "$message.header#replyTo"] {
+        assert!(
+            RuntimeExpression::new(invalid).validate().is_err(),
+            "{invalid}"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use indexmap::IndexMap;
+
+use crate::channel::OperationMessageType;
+use crate::{
+    message_trait::MessageExample, AsyncAPI, Channel, ChannelBinding, Components, Contact,
+    CorrelationId, Example, ExternalDocumentation, Info, License, Message, MessageBinding,
+    MessageTrait, OAuthFlowAuthorizationCode, OAuthFlowClientCredentials, OAuthFlowImplicit,
+    OAuthFlowPassword, OAuthFlows, Operation, OperationBinding, OperationTrait, Parameter,
+    ReferenceOr, SecurityScheme, Server, ServerBinding, ServerVariable, Tag,
+};
+
+/// Selects what [`AsyncAPI::sanitize`] removes from a document.
+///
+/// By default all specification extensions are removed, including any
+/// additional fields of security schemes, but no channels.
+#[derive(Debug, Clone)]
+pub struct SanitizeOptions {
+    /// Remove all [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions),
+    /// i.e. fields starting with `x-`.
+    pub strip_extensions: bool,
+    /// Remove every field of a security scheme that is not defined by the
+    /// specification. These fields often point to vaults or hold credentials
+    /// for internal tooling.
+    pub strip_security_scheme_extensions: bool,
+    /// Channels with an operation tagged with one of these tags are removed,
+    /// together with the tags themselves.
+    pub internal_tags: Vec<String>,
+}
+
+impl Default for SanitizeOptions {
+    fn default() -> Self {
+        SanitizeOptions {
+            strip_extensions: true,
+            strip_security_scheme_extensions: true,
+            internal_tags: Vec::new(),
+        }
+    }
+}
+
+impl SanitizeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes channels with an operation tagged `tag`.
+    pub fn internal_tag(mut self, tag: impl Into<String>) -> Self {
+        self.internal_tags.push(tag.into());
+        self
+    }
+
+    pub fn strip_extensions(mut self, strip: bool) -> Self {
+        self.strip_extensions = strip;
+        self
+    }
+
+    pub fn strip_security_scheme_extensions(mut self, strip: bool) -> Self {
+        self.strip_security_scheme_extensions = strip;
+        self
+    }
+}
+
+impl AsyncAPI {
+    /// Returns a copy of the document that is fit for publishing outside of
+    /// the organization maintaining it, see [`SanitizeOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{sanitize::SanitizeOptions, AsyncAPI};
+    ///
+    /// let api: AsyncAPI = r#"
+    /// asyncapi: 2.3.0
+    /// info:
+    ///   title: Account Service
+    ///   version: 1.0.0
+    ///   x-owner: team-accounts
+    /// channels:
+    ///   user/signedup:
+    ///     subscribe:
+    ///       message:
+    ///         name: userSignedUp
+    ///   admin/audit:
+    ///     subscribe:
+    ///       tags:
+    ///         - name: internal
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let public = api.sanitize(&SanitizeOptions::new().internal_tag("internal"));
+    /// assert!(public.info.extensions.is_empty());
+    /// assert_eq!(public.channels.keys().collect::<Vec<_>>(), ["user/signedup"]);
+    /// ```
+    pub fn sanitize(&self, options: &SanitizeOptions) -> AsyncAPI {
+        let mut api = self.clone();
+        if !options.internal_tags.is_empty() {
+            let is_public = |_: &String, channel: &mut Channel| {
+                !channel
+                    .operations()
+                    .flat_map(|(_, operation)| &operation.tags)
+                    .any(|tag| options.internal_tags.contains(&tag.name))
+            };
+            api.channels.retain(is_public);
+            if let Some(components) = &mut api.components {
+                components.channels.retain(is_public);
+            }
+            api.tags
+                .retain(|tag| !options.internal_tags.contains(&tag.name));
+        }
+        if options.strip_security_scheme_extensions {
+            let schemes = api
+                .components
+                .iter_mut()
+                .flat_map(|c| c.security_schemes.values_mut());
+            for scheme in schemes {
+                if let ReferenceOr::Item(scheme) = scheme {
+                    let other = matches!(scheme, SecurityScheme::Other { .. });
+                    // The fields of unknown schemes can't be told apart from extensions.
+                    scheme
+                        .extensions_mut()
+                        .retain(|name, _| other && !name.starts_with("x-"));
+                }
+            }
+        }
+        if options.strip_extensions {
+            api.strip_extensions();
+        }
+        api
+    }
+}
+
+/// Removes the specification extensions of a value and all values it contains.
+trait StripExtensions {
+    fn strip_extensions(&mut self);
+}
+
+fn strip(extensions: &mut IndexMap<String, serde_json::Value>) {
+    extensions.retain(|name, _| !name.starts_with("x-"));
+}
+
+impl<T: StripExtensions> StripExtensions for ReferenceOr<T> {
+    fn strip_extensions(&mut self) {
+        if let ReferenceOr::Item(item) = self {
+            item.strip_extensions();
+        }
+    }
+}
+
+impl<T: StripExtensions> StripExtensions for Option<T> {
+    fn strip_extensions(&mut self) {
+        if let Some(item) = self {
+            item.strip_extensions();
+        }
+    }
+}
+
+impl<T: StripExtensions> StripExtensions for Vec<T> {
+    fn strip_extensions(&mut self) {
+        self.iter_mut().for_each(T::strip_extensions);
+    }
+}
+
+impl<T: StripExtensions> StripExtensions for IndexMap<String, T> {
+    fn strip_extensions(&mut self) {
+        self.values_mut().for_each(T::strip_extensions);
+    }
+}
+
+/// Implements [`StripExtensions`] for a struct with an `extensions` field and
+/// the listed fields containing other values with extensions.
+macro_rules! strip_extensions {
+    ($($ty:ty { $($field:ident),* })*) => {
+        $(
+            impl StripExtensions for $ty {
+                fn strip_extensions(&mut self) {
+                    strip(&mut self.extensions);
+                    $(self.$field.strip_extensions();)*
+                }
+            }
+        )*
+    };
+}
+
+strip_extensions! {
+    AsyncAPI { info, servers, channels, components, tags, external_docs }
+    Info { contact, license }
+    Contact {}
+    License {}
+    Server { variables, tags, bindings }
+    ServerVariable {}
+    Channel { subscribe, publish, parameters, bindings }
+    Operation { tags, external_docs, bindings, traits, message }
+    OperationTrait { tags, external_docs, bindings }
+    Message { correlation_id, tags, external_docs, bindings, examples }
+    MessageTrait { correlation_id, tags, external_docs, bindings, examples }
+    MessageExample {}
+    Example {}
+    Parameter {}
+    CorrelationId {}
+    Tag { external_docs }
+    ExternalDocumentation {}
+    OAuthFlows { implicit, password, client_credentials, authorization_code }
+    OAuthFlowImplicit {}
+    OAuthFlowPassword {}
+    OAuthFlowClientCredentials {}
+    OAuthFlowAuthorizationCode {}
+    Components {
+        messages, security_schemes, parameters, correlation_ids, operation_traits,
+        message_traits, servers, server_variables, server_bindings, channels, channel_bindings,
+        operation_bindings, message_bindings
+    }
+    ServerBinding {}
+    ChannelBinding {}
+    OperationBinding {}
+    MessageBinding {}
+}
+
+impl StripExtensions for OperationMessageType {
+    fn strip_extensions(&mut self) {
+        match self {
+            OperationMessageType::OneOf { one_of } => one_of.strip_extensions(),
+            OperationMessageType::Map(messages) => messages.strip_extensions(),
+            OperationMessageType::Single(message) => message.strip_extensions(),
+        }
+    }
+}
+
+impl StripExtensions for SecurityScheme {
+    fn strip_extensions(&mut self) {
+        strip(self.extensions_mut());
+        if let SecurityScheme::OAuth2 { flows, .. } = self {
+            flows.strip_extensions();
+        }
+    }
+}
+
+#[test]
+fn test_sanitize_strips_nested_extensions() {
+    let api: AsyncAPI = r#"
+    asyncapi: 2.3.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels:
+      user/signedup:
+        x-internal-topic: accounts.user.signedup.v1
+        subscribe:
+          message:
+            x-schema-registry: https://registry.internal
+            headers:
+              type: object
+              properties:
+                x-request-id:
+                  type: string
+    components:
+      securitySchemes:
+        sasl:
+          type: scramSha256
+          vaultPath: secret/kafka/accounts
+    "#
+    .parse()
+    .unwrap();
+
+    let value = serde_json::to_value(api.sanitize(&SanitizeOptions::new())).unwrap();
+    let channel = &value["channels"]["user/signedup"];
+    assert!(channel.get("x-internal-topic").is_none());
+    assert!(channel["subscribe"]["message"]
+        .get("x-schema-registry")
+        .is_none());
+    // Schema properties are not extensions.
+    assert!(channel["subscribe"]["message"]["headers"]["properties"]
+        .get("x-request-id")
+        .is_some());
+    assert_eq!(
+        value["components"]["securitySchemes"]["sasl"],
+        serde_json::json!({ "type": "scramSha256" })
+    );
+}
@@ -83,6 +83,24 @@ pub enum AdditionalProperties {
     Schema(Box<ReferenceOr<Schema>>),
 }
 
+/// A binding value that is either given as an integer or described by a
+/// schema, such as the expiry intervals of the MQTT 5 bindings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum IntegerOrSchema {
+    Integer(i64),
+    Schema(Box<ReferenceOr<Schema>>),
+}
+
+/// A binding value that is either given as a string or described by a schema,
+/// such as the response topic of the MQTT message binding.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum StringOrSchema {
+    String(String),
+    Schema(Box<ReferenceOr<Schema>>),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AnySchema {
@@ -1,6 +1,8 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
+use crate::validation::{self, Validate, ValidationError};
+
 /// Defines a security scheme that can be used by the operations. Supported schemes are:
 ///
 /// * User/Password.
@@ -142,6 +144,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum SecurityScheme {
     #[serde(rename = "userPassword")]
     UserPassword {
@@ -152,7 +155,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "apiKey")]
@@ -165,10 +168,10 @@ pub enum SecurityScheme {
         /// REQUIRED. The location of the API key.
         /// Valid values are `"user"` and `"password"`.
         #[serde(rename = "in")]
-        location: String,
+        location: ApiKeyLocation,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     X509 {
@@ -179,7 +182,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "symmetricEncryption")]
@@ -191,7 +194,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "asymmetricEncryption")]
@@ -203,7 +206,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "httpApiKey")]
@@ -219,10 +222,10 @@ pub enum SecurityScheme {
         /// REQUIRED. The location of the API key.
         /// Valid values are `"query"`, `"header"` or `"cookie"`.
         #[serde(rename = "in")]
-        location: String,
+        location: HttpApiKeyLocation,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "http", rename_all = "camelCase")]
@@ -243,7 +246,7 @@ pub enum SecurityScheme {
         bearer_format: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     /// # Examples
@@ -297,7 +300,7 @@ pub enum SecurityScheme {
         flows: OAuthFlows,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "openIdConnect", rename_all = "camelCase")]
@@ -312,7 +315,7 @@ pub enum SecurityScheme {
         open_id_connect_url: String,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "plain")]
@@ -324,7 +327,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "scramSha256")]
@@ -336,7 +339,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "scramSha512")]
@@ -348,7 +351,7 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
     #[serde(rename = "gssapi")]
@@ -360,9 +363,77 @@ pub enum SecurityScheme {
         description: Option<String>,
         /// This object MAY be extended with
         /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
         extensions: IndexMap<String, serde_json::Value>,
     },
+    /// A type of security scheme this crate doesn't know about, e.g. one added
+    /// by a later version of the specification. All fields are kept as they are.
+    #[serde(untagged)]
+    Other {
+        /// The `type` of the security scheme. For the types known to this crate
+        /// the dedicated variant is used instead.
+        #[serde(rename = "type", deserialize_with = "deserialize_unknown_type")]
+        typ: String,
+        /// All other fields of the security scheme.
+        #[serde(flatten)]
+        fields: IndexMap<String, serde_json::Value>,
+    },
+}
+
+/// The values of `type` that have a dedicated [`SecurityScheme`] variant.
+const KNOWN_TYPES: &[&str] = &[
+    "userPassword",
+    "apiKey",
+    "X509",
+    "symmetricEncryption",
+    "asymmetricEncryption",
+    "httpApiKey",
+    "http",
+    "oauth2",
+    "openIdConnect",
+    "plain",
+    "scramSha256",
+    "scramSha512",
+    "gssapi",
+];
+
+/// Keeps a malformed scheme of a known type from being accepted as
+/// [`SecurityScheme::Other`], and rejects unknown types in strict mode.
+pub(crate) fn deserialize_unknown_type<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let typ = String::deserialize(deserializer)?;
+    if KNOWN_TYPES.contains(&typ.as_str()) {
+        return Err(serde::de::Error::custom(format!(
+            "invalid security scheme of type `{typ}`"
+        )));
+    }
+    crate::parse::check_unknown(&typ)?;
+    Ok(typ)
+}
+
+/// The location of the key of an [`ApiKey`][SecurityScheme::ApiKey] security scheme.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiKeyLocation {
+    User,
+    Password,
+    /// A location not defined by the specification.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
+}
+
+/// The location of the key of an [`HttpApiKey`][SecurityScheme::HttpApiKey] security scheme.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HttpApiKeyLocation {
+    Query,
+    Header,
+    Cookie,
+    /// A location not defined by the specification.
+    #[serde(untagged, deserialize_with = "crate::parse::deserialize_unknown")]
+    Other(String),
 }
 
 /// Allows configuration of the supported OAuth Flows.
@@ -383,7 +454,7 @@ pub struct OAuthFlows {
     pub authorization_code: Option<OAuthFlowAuthorizationCode>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -403,7 +474,7 @@ pub struct OAuthFlowImplicit {
     pub scopes: IndexMap<String, String>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -423,7 +494,7 @@ pub struct OAuthFlowPassword {
     pub scopes: IndexMap<String, String>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -443,7 +514,7 @@ pub struct OAuthFlowClientCredentials {
     pub scopes: IndexMap<String, String>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -466,10 +537,194 @@ pub struct OAuthFlowAuthorizationCode {
     pub scopes: IndexMap<String, String>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl Validate for SecurityScheme {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            SecurityScheme::OAuth2 { flows, .. } => {
+                flows.validate_at(&format!("{path}/flows"), errors)
+            }
+            SecurityScheme::OpenIdConnect {
+                open_id_connect_url,
+                ..
+            } => validation::check_url(
+                format!("{path}/openIdConnectUrl"),
+                open_id_connect_url,
+                errors,
+            ),
+            SecurityScheme::ApiKey {
+                location: ApiKeyLocation::Other(location),
+                ..
+            } => errors.push(ValidationError::new(
+                format!("{path}/in"),
+                format!("`{location}` is neither user nor password"),
+            )),
+            SecurityScheme::HttpApiKey { name, location, .. } => {
+                if name.is_empty() {
+                    errors.push(ValidationError::new(
+                        format!("{path}/name"),
+                        "the name of the parameter must not be empty",
+                    ));
+                }
+                if let HttpApiKeyLocation::Other(location) = location {
+                    errors.push(ValidationError::new(
+                        format!("{path}/in"),
+                        format!("`{location}` is neither query, header nor cookie"),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl SecurityScheme {
+    /// The fields of the scheme that are not defined by the specification.
+    pub(crate) fn extensions_mut(&mut self) -> &mut IndexMap<String, serde_json::Value> {
+        match self {
+            SecurityScheme::UserPassword { extensions, .. }
+            | SecurityScheme::ApiKey { extensions, .. }
+            | SecurityScheme::X509 { extensions, .. }
+            | SecurityScheme::SymmetricEncryption { extensions, .. }
+            | SecurityScheme::AsymmetricEncryption { extensions, .. }
+            | SecurityScheme::HttpApiKey { extensions, .. }
+            | SecurityScheme::Http { extensions, .. }
+            | SecurityScheme::OAuth2 { extensions, .. }
+            | SecurityScheme::OpenIdConnect { extensions, .. }
+            | SecurityScheme::Plain { extensions, .. }
+            | SecurityScheme::ScramSha256 { extensions, .. }
+            | SecurityScheme::ScramSha512 { extensions, .. }
+            | SecurityScheme::Gssapi { extensions, .. }
+            | SecurityScheme::Other {
+                fields: extensions, ..
+            } => extensions,
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+impl SecurityScheme {
+    /// Parses the `openIdConnectUrl` of an
+    /// [`OpenIdConnect`][SecurityScheme::OpenIdConnect] scheme. Returns `None`
+    /// for every other type of scheme.
+    ///
+    /// This method is only available with the `url` feature enabled.
+    pub fn open_id_connect_url(&self) -> Option<Result<url::Url, url::ParseError>> {
+        match self {
+            SecurityScheme::OpenIdConnect {
+                open_id_connect_url,
+                ..
+            } => Some(url::Url::parse(open_id_connect_url)),
+            _ => None,
+        }
+    }
+}
+
+impl OAuthFlows {
+    /// Returns the scopes of all configured flows. When several flows define
+    /// the same scope, the description of the first one wins.
+    pub fn scopes(&self) -> IndexMap<&str, &str> {
+        let mut scopes = IndexMap::new();
+        let flows = [
+            self.implicit.as_ref().map(|flow| &flow.scopes),
+            self.password.as_ref().map(|flow| &flow.scopes),
+            self.client_credentials.as_ref().map(|flow| &flow.scopes),
+            self.authorization_code.as_ref().map(|flow| &flow.scopes),
+        ];
+        for (name, description) in flows.into_iter().flatten().flatten() {
+            scopes.entry(name.as_str()).or_insert(description.as_str());
+        }
+        scopes
+    }
+
+    /// Returns whether any of the configured flows defines `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().contains_key(scope)
+    }
+
+    /// Returns the `requested` scopes that none of the configured flows define,
+    /// e.g. to check the scopes listed by a
+    /// [Security Requirement][crate::SecurityRequirement].
+    pub fn undefined_scopes<'a>(&self, requested: &'a [String]) -> Vec<&'a str> {
+        let scopes = self.scopes();
+        requested
+            .iter()
+            .map(String::as_str)
+            .filter(|scope| !scopes.contains_key(scope))
+            .collect()
+    }
+}
+
+impl Validate for OAuthFlows {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.implicit.is_none()
+            && self.password.is_none()
+            && self.client_credentials.is_none()
+            && self.authorization_code.is_none()
+        {
+            errors.push(ValidationError::new(path, "at least one flow is required"));
+        }
+        if let Some(flow) = &self.implicit {
+            flow.validate_at(&format!("{path}/implicit"), errors);
+        }
+        if let Some(flow) = &self.password {
+            flow.validate_at(&format!("{path}/password"), errors);
+        }
+        if let Some(flow) = &self.client_credentials {
+            flow.validate_at(&format!("{path}/clientCredentials"), errors);
+        }
+        if let Some(flow) = &self.authorization_code {
+            flow.validate_at(&format!("{path}/authorizationCode"), errors);
+        }
+    }
+}
+
+impl Validate for OAuthFlowImplicit {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        validation::check_url(
+            format!("{path}/authorizationUrl"),
+            &self.authorization_url,
+            errors,
+        );
+        check_refresh_url(path, &self.refresh_url, errors);
+    }
+}
+
+impl Validate for OAuthFlowPassword {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        validation::check_url(format!("{path}/tokenUrl"), &self.token_url, errors);
+        check_refresh_url(path, &self.refresh_url, errors);
+    }
+}
+
+impl Validate for OAuthFlowClientCredentials {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        validation::check_url(format!("{path}/tokenUrl"), &self.token_url, errors);
+        check_refresh_url(path, &self.refresh_url, errors);
+    }
+}
+
+impl Validate for OAuthFlowAuthorizationCode {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        validation::check_url(
+            format!("{path}/authorizationUrl"),
+            &self.authorization_url,
+            errors,
+        );
+        validation::check_url(format!("{path}/tokenUrl"), &self.token_url, errors);
+        check_refresh_url(path, &self.refresh_url, errors);
+    }
+}
+
+fn check_refresh_url(path: &str, refresh_url: &Option<String>, errors: &mut Vec<ValidationError>) {
+    if let Some(refresh_url) = refresh_url {
+        validation::check_url(format!("{path}/refreshUrl"), refresh_url, errors);
+    }
+}
+
 #[test]
 fn test_deserialize_security_scheme() {
     use crate::ReferenceOr;
@@ -479,11 +734,11 @@ fn test_deserialize_security_scheme() {
     in: user
     description: Provide your API key as the user and leave the password empty.
     "#;
-    let asyncapi: ReferenceOr<SecurityScheme> = serde_yaml::from_str(example)
-        .expect(&format!("Could not deserialize api key security scheme"));
+    let asyncapi: ReferenceOr<SecurityScheme> =
+        serde_yaml::from_str(example).expect("Could not deserialize api key security scheme");
     assert_eq!(
         ReferenceOr::Item(SecurityScheme::ApiKey {
-            location: "user".to_string(),
+            location: ApiKeyLocation::User,
             description: Some(
                 "Provide your API key as the user and leave the password empty.".to_string(),
             ),
@@ -492,3 +747,238 @@ fn test_deserialize_security_scheme() {
         asyncapi
     );
 }
+
+#[test]
+fn test_deserialize_sasl_and_certificate_security_schemes() {
+    let schemes: IndexMap<String, SecurityScheme> = serde_yaml::from_str(
+        r#"
+        plain:
+          type: plain
+        scramSha256:
+          type: scramSha256
+          description: SASL/SCRAM with SHA-256
+        scramSha512:
+          type: scramSha512
+        gssapi:
+          type: gssapi
+          x-realm: EXAMPLE.COM
+        X509:
+          type: X509
+        symmetricEncryption:
+          type: symmetricEncryption
+        asymmetricEncryption:
+          type: asymmetricEncryption
+        "#,
+    )
+    .expect("Could not deserialize SASL and certificate security schemes");
+
+    assert!(matches!(schemes["plain"], SecurityScheme::Plain { .. }));
+    assert_eq!(
+        schemes["scramSha256"],
+        SecurityScheme::ScramSha256 {
+            description: Some("SASL/SCRAM with SHA-256".to_string()),
+            extensions: Default::default(),
+        }
+    );
+    assert!(matches!(
+        schemes["scramSha512"],
+        SecurityScheme::ScramSha512 { .. }
+    ));
+    match &schemes["gssapi"] {
+        SecurityScheme::Gssapi { extensions, .. } => {
+            assert_eq!(extensions["x-realm"], "EXAMPLE.COM")
+        }
+        scheme => panic!("expected a gssapi scheme, got {scheme:?}"),
+    }
+    assert!(matches!(schemes["X509"], SecurityScheme::X509 { .. }));
+    assert!(matches!(
+        schemes["symmetricEncryption"],
+        SecurityScheme::SymmetricEncryption { .. }
+    ));
+    assert!(matches!(
+        schemes["asymmetricEncryption"],
+        SecurityScheme::AsymmetricEncryption { .. }
+    ));
+
+    for (typ, scheme) in &schemes {
+        let value = serde_json::to_value(scheme).unwrap();
+        assert_eq!(value["type"], typ.as_str());
+        assert_eq!(
+            &serde_json::from_value::<SecurityScheme>(value).unwrap(),
+            scheme
+        );
+    }
+}
+
+#[test]
+fn test_oauth_flows() {
+    let scheme: SecurityScheme = serde_yaml::from_str(
+        r#"
+        type: oauth2
+        flows:
+          implicit:
+            authorizationUrl: https://example.com/api/oauth/dialog
+            scopes:
+              write:pets: modify pets in your account
+              read:pets: read your pets
+          authorizationCode:
+            authorizationUrl: https://example.com/api/oauth/dialog
+            tokenUrl: /api/oauth/token
+            scopes:
+              read:pets: read pets
+              admin: administer pets
+        "#,
+    )
+    .expect("Could not deserialize oauth2 security scheme");
+    let flows = match &scheme {
+        SecurityScheme::OAuth2 { flows, .. } => flows,
+        scheme => panic!("expected an oauth2 scheme, got {scheme:?}"),
+    };
+
+    assert_eq!(
+        flows.scopes().into_iter().collect::<Vec<_>>(),
+        [
+            ("write:pets", "modify pets in your account"),
+            ("read:pets", "read your pets"),
+            ("admin", "administer pets"),
+        ]
+    );
+    assert!(flows.has_scope("admin"));
+    assert_eq!(
+        flows.undefined_scopes(&["read:pets".to_string(), "delete:pets".to_string()]),
+        ["delete:pets"]
+    );
+
+    match scheme.validate() {
+        Err(crate::Error::Validate(errors)) => assert_eq!(
+            errors,
+            [ValidationError::new(
+                "/flows/authorizationCode/tokenUrl",
+                "`/api/oauth/token` is not an absolute URL"
+            )]
+        ),
+        result => panic!("expected validation errors, got {result:?}"),
+    }
+    assert!(OAuthFlows::default().validate().is_err());
+}
+
+#[test]
+fn test_http_api_key_location() {
+    let scheme: SecurityScheme = serde_yaml::from_str(
+        r#"
+        type: httpApiKey
+        name: api_key
+        in: cookie
+        "#,
+    )
+    .expect("Could not deserialize http api key security scheme");
+    assert!(matches!(
+        scheme,
+        SecurityScheme::HttpApiKey {
+            location: HttpApiKeyLocation::Cookie,
+            ..
+        }
+    ));
+
+    let location: HttpApiKeyLocation = serde_json::from_str(r#""path""#).unwrap();
+    assert_eq!(location, HttpApiKeyLocation::Other("path".to_string()));
+    assert_eq!(serde_json::to_string(&location).unwrap(), r#""path""#);
+}
+
+#[test]
+fn test_validate_open_id_connect_url() {
+    let scheme = |url: &str| SecurityScheme::OpenIdConnect {
+        description: None,
+        open_id_connect_url: url.to_string(),
+        extensions: Default::default(),
+    };
+    assert!(
+        scheme("https://example.com/.well-known/openid-configuration")
+            .validate()
+            .is_ok()
+    );
+    for url in [
+        "/.well-known/openid-configuration",
+        "https://{tenant}.example.com/.well-known/openid-configuration",
+        "",
+    ] {
+        match scheme(url).validate() {
+            Err(crate::Error::Validate(errors)) => {
+                assert_eq!(errors[0].path, "/openIdConnectUrl")
+            }
+            result => panic!("expected `{url}` to be rejected, got {result:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_validate_security_schemes() {
+    let api: crate::AsyncAPI = r#"
+    asyncapi: 2.6.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    channels: {}
+    components:
+      securitySchemes:
+        userKey:
+          type: apiKey
+          in: header
+        queryKey:
+          type: httpApiKey
+          name: ''
+          in: path
+        oauth:
+          type: oauth2
+          flows:
+            implicit:
+              authorizationUrl: /oauth/dialog
+              scopes: {}
+            clientCredentials:
+              tokenUrl: https://example.com/oauth/token
+              scopes: {}
+    "#
+    .parse()
+    .unwrap();
+    let errors = match api.validate() {
+        Err(crate::Error::Validate(errors)) => errors,
+        result => panic!("expected the schemes to be rejected, got {result:?}"),
+    };
+    let paths: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        [
+            "/components/securitySchemes/userKey/in",
+            "/components/securitySchemes/queryKey/name",
+            "/components/securitySchemes/queryKey/in",
+            "/components/securitySchemes/oauth/flows/implicit/authorizationUrl",
+        ]
+    );
+}
+
+#[test]
+fn test_deserialize_unknown_security_scheme() {
+    let example = r#"
+    type: awsIam
+    description: Sign requests with AWS Signature Version 4.
+    region: eu-central-1
+    "#;
+    let scheme: SecurityScheme =
+        serde_yaml::from_str(example).expect("Could not deserialize unknown security scheme");
+    match &scheme {
+        SecurityScheme::Other { typ, fields } => {
+            assert_eq!(typ, "awsIam");
+            assert_eq!(fields["region"], "eu-central-1");
+        }
+        scheme => panic!("expected an unknown scheme, got {scheme:?}"),
+    }
+    let value = serde_json::to_value(&scheme).unwrap();
+    assert_eq!(value["type"], "awsIam");
+    assert_eq!(
+        value["description"],
+        "Sign requests with AWS Signature Version 4."
+    );
+
+    // A known type missing required fields is still an error.
+    assert!(serde_yaml::from_str::<SecurityScheme>("type: httpApiKey\nin: header").is_err());
+}
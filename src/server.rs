@@ -1,7 +1,10 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::{ReferenceOr, ServerBinding};
+use crate::{
+    validation::{self, Validate, ValidationError},
+    ReferenceOr, SecurityScheme, ServerBinding, Tag,
+};
 
 /// An object representing a message broker, a server or any other kind of
 /// computer program capable of sending and/or receiving data. This object is
@@ -152,23 +155,183 @@ pub struct Server {
     /// A map between a variable name and its value. The value is used
     /// for substitution in the server's URL template.
     #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
-    pub variables: IndexMap<String, ServerVariable>,
+    pub variables: IndexMap<String, ReferenceOr<ServerVariable>>,
     /// A declaration of which security mechanisms can be used with this
     /// server. The list of values includes alternative security requirement
     /// objects that can be used. Only one of the security requirement objects
     /// need to be satisfied to authorize a connection or operation.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub security: Vec<SecurityRequirement>,
+    /// A list of tags for logical grouping and categorization of servers.
+    ///
+    /// Added in AsyncAPI 2.5.0.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
     /// A map where the keys describe the name of the protocol and the values
     /// describe protocol-specific definitions for the server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bindings: Option<ReferenceOr<ServerBinding>>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl Server {
+    /// The protocols named by the specification and its bindings. Servers
+    /// may use others, see [`Server::has_known_protocol`].
+    pub const KNOWN_PROTOCOLS: &'static [&'static str] = &[
+        "amqp",
+        "amqps",
+        "anypointmq",
+        "googlepubsub",
+        "http",
+        "https",
+        "ibmmq",
+        "jms",
+        "kafka",
+        "kafka-secure",
+        "mercure",
+        "mqtt",
+        "mqtt5",
+        "nats",
+        "pulsar",
+        "redis",
+        "secure-mqtt",
+        "sns",
+        "solace",
+        "sqs",
+        "stomp",
+        "stomps",
+        "ws",
+        "wss",
+    ];
+
+    /// Returns whether the [protocol][Server::protocol] is one of the
+    /// [known protocols][Server::KNOWN_PROTOCOLS]. Other protocols are
+    /// allowed, but are often a typo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::Server;
+    ///
+    /// assert!(Server::new("broker.example.com:9092", "kafka").has_known_protocol());
+    /// assert!(!Server::new("broker.example.com:9092", "kafak").has_known_protocol());
+    /// ```
+    pub fn has_known_protocol(&self) -> bool {
+        Self::KNOWN_PROTOCOLS.contains(&self.protocol.as_str())
+    }
+
+    /// Creates a server at `url` speaking `protocol`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::Server;
+    ///
+    /// let server = Server::new("broker.example.com:9092", "kafka")
+    ///     .with_protocol_version("3.5")
+    ///     .with_description("Production broker");
+    /// assert_eq!(server.protocol, "kafka");
+    /// ```
+    pub fn new(url: impl Into<String>, protocol: impl Into<String>) -> Self {
+        Server {
+            url: url.into(),
+            protocol: protocol.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the [protocol version][Server::protocol_version].
+    pub fn with_protocol_version(mut self, protocol_version: impl Into<String>) -> Self {
+        self.protocol_version = Some(protocol_version.into());
+        self
+    }
+
+    /// Sets the [description][Server::description].
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a [variable][Server::variables], replacing one of the same name.
+    pub fn with_variable(mut self, name: impl Into<String>, variable: ServerVariable) -> Self {
+        self.variables
+            .insert(name.into(), ReferenceOr::Item(variable));
+        self
+    }
+
+    /// Adds an alternative [security requirement][Server::security].
+    pub fn with_security(mut self, requirement: SecurityRequirement) -> Self {
+        self.security.push(requirement);
+        self
+    }
+
+    /// Adds a [tag][Server::tags].
+    pub fn with_tag(mut self, tag: Tag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Sets the [bindings][Server::bindings].
+    pub fn with_bindings(mut self, bindings: ServerBinding) -> Self {
+        self.bindings = Some(ReferenceOr::Item(bindings));
+        self
+    }
+}
+
+impl Validate for Server {
+    /// The URL may be relative and doesn't need a scheme, e.g. a Kafka
+    /// bootstrap server, so only its shape as a URL template is checked.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let url_path = format!("{path}/url");
+        if self.url.is_empty() {
+            errors.push(ValidationError::new(&url_path, "must not be empty"));
+        } else if self.url.contains(char::is_whitespace) || !is_template(&self.url) {
+            errors.push(ValidationError::new(
+                &url_path,
+                format!("`{}` is not a well-formed URL template", self.url),
+            ));
+        } else {
+            for variable in validation::placeholders(&self.url) {
+                if !self.variables.contains_key(variable) {
+                    errors.push(ValidationError::new(
+                        &url_path,
+                        format!("variable `{variable}` is not declared in variables"),
+                    ));
+                }
+            }
+        }
+        if self.protocol.is_empty() {
+            errors.push(ValidationError::new(
+                format!("{path}/protocol"),
+                "must not be empty",
+            ));
+        }
+    }
+}
+
+/// Returns whether the braces of `template` enclose non-empty variable
+/// names and are neither nested nor unbalanced.
+fn is_template(template: &str) -> bool {
+    let mut in_variable = false;
+    let mut empty = true;
+    for c in template.chars() {
+        match (c, in_variable) {
+            ('{', false) => {
+                in_variable = true;
+                empty = true;
+            }
+            ('}', true) if !empty => in_variable = false,
+            ('{' | '}', _) => return false,
+            (_, true) => empty = false,
+            (_, false) => {}
+        }
+    }
+    !in_variable
+}
+
 /// An object representing a Server Variable for server URL
 /// template substitution.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -192,7 +355,7 @@ pub struct ServerVariable {
     pub examples: Option<Vec<String>>,
     /// This object MAY be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
@@ -259,3 +422,186 @@ pub struct SecurityRequirement {
     #[serde(flatten)]
     pub values: IndexMap<String, Vec<String>>,
 }
+
+/// A security scheme of a [Security Requirement][SecurityRequirement],
+/// resolved by [`AsyncAPI::server_security`][crate::AsyncAPI::server_security].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecuritySchemeRequirement<'a> {
+    /// The name of the scheme in the
+    /// [Components Object][crate::Components].
+    pub name: &'a str,
+    pub scheme: &'a SecurityScheme,
+    /// The scopes required for `"oauth2"` and `"openIdConnect"` schemes.
+    pub scopes: &'a [String],
+}
+
+#[test]
+fn test_server_variable_reference() {
+    let example = r#"
+    asyncapi: 2.4.0
+    info:
+      title: Account Service
+      version: 1.0.0
+    servers:
+      production:
+        url: '{username}.gigantic-server.com:{port}'
+        protocol: secure-mqtt
+        variables:
+          username:
+            $ref: '#/components/serverVariables/username'
+          port:
+            default: '8883'
+    channels: {}
+    components:
+      serverVariables:
+        username:
+          default: demo
+    "#;
+    let api: crate::AsyncAPI = serde_yaml::from_str(example).unwrap();
+    let ReferenceOr::Item(server) = &api.servers["production"] else {
+        panic!("expected an inline server");
+    };
+    assert_eq!(
+        server.variables["username"],
+        ReferenceOr::ref_("#/components/serverVariables/username")
+    );
+    assert!(matches!(
+        &server.variables["port"],
+        ReferenceOr::Item(variable) if variable.default.as_deref() == Some("8883")
+    ));
+    let components = api.components.as_ref().unwrap();
+    assert!(components.server_variables.contains_key("username"));
+
+    let value = serde_json::to_value(&api).unwrap();
+    assert_eq!(
+        value["servers"]["production"]["variables"]["username"]["$ref"],
+        "#/components/serverVariables/username"
+    );
+    assert_eq!(
+        value["components"]["serverVariables"]["username"]["default"],
+        "demo"
+    );
+}
+
+#[test]
+fn test_server_tags() {
+    let example = r#"
+    url: rabbitmq.in.mycompany.com:5672
+    protocol: amqp
+    tags:
+      - name: env:production
+        description: This environment is the live environment available for final users.
+      - name: visibility:public
+    "#;
+    let server: Server = serde_yaml::from_str(example).unwrap();
+    assert_eq!(
+        server
+            .tags
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect::<Vec<_>>(),
+        ["env:production", "visibility:public"]
+    );
+    assert!(server.extensions.is_empty());
+    assert_eq!(
+        serde_json::to_value(&server).unwrap()["tags"][1]["name"],
+        "visibility:public"
+    );
+}
+
+#[test]
+fn test_server_variables_2_5_0_example() {
+    let example = r#"
+    asyncapi: 2.5.0
+    info:
+      title: Gigantic Server
+      version: 1.0.0
+    servers:
+      production:
+        url: '{username}.gigantic-server.com:{port}/{basePath}'
+        description: The production API server
+        protocol: secure-mqtt
+        variables:
+          username:
+            $ref: '#/components/serverVariables/username'
+          port:
+            enum:
+              - '8883'
+              - '8884'
+            default: '8883'
+          basePath:
+            $ref: '#/components/serverVariables/basePath'
+    channels: {}
+    components:
+      serverVariables:
+        username:
+          # note! no enum here means it is an open value
+          default: demo
+          description: This value is assigned by the service provider, in this example `gigantic-server.com`
+        basePath:
+          $ref: '#/components/serverVariables/version'
+        version:
+          default: v2
+    "#;
+    let api: crate::AsyncAPI = serde_yaml::from_str(example).unwrap();
+    let ReferenceOr::Item(server) = &api.servers["production"] else {
+        panic!("expected an inline server");
+    };
+    let components = api.components.as_ref().map(|c| &c.server_variables);
+    let defaults = server
+        .variables
+        .values()
+        .map(|variable| {
+            crate::reference::resolve_local(components, "#/components/serverVariables/", variable)
+                .map(|variable| variable.default.as_deref())
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(defaults, [Some("demo"), Some("8883"), Some("v2")]);
+
+    let value = serde_json::to_value(&api).unwrap();
+    assert_eq!(
+        serde_json::from_value::<crate::AsyncAPI>(value).unwrap(),
+        api
+    );
+}
+
+#[test]
+fn test_validate_server() {
+    let server = Server::new("{username}.example.com:{port}", "mqtt").with_variable(
+        "username",
+        ServerVariable {
+            default: Some("demo".to_string()),
+            ..Default::default()
+        },
+    );
+    let Err(crate::Error::Validate(errors)) = server.validate() else {
+        panic!("the server is invalid");
+    };
+    assert_eq!(
+        errors,
+        [ValidationError::new(
+            "/url",
+            "variable `port` is not declared in variables"
+        )]
+    );
+
+    for url in [
+        "example.com:{port",
+        "example.com:{}",
+        "example.com/{a{b}}",
+        "a b",
+    ] {
+        let Err(crate::Error::Validate(errors)) = Server::new(url, "ws").validate() else {
+            panic!("expected `{url}` to be rejected");
+        };
+        assert_eq!(
+            errors[0].message,
+            format!("`{url}` is not a well-formed URL template")
+        );
+    }
+    let Err(crate::Error::Validate(errors)) = Server::new("", "").validate() else {
+        panic!("the server is invalid");
+    };
+    assert_eq!(errors.len(), 2);
+}
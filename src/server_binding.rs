@@ -1,6 +1,13 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    binding_builder::binding_builder,
+    schema::IntegerOrSchema,
+    validation::{Validate, ValidationError},
+    BindingVersion,
+};
+
 /// Map describing protocol-specific definitions for a server.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -23,7 +30,7 @@ pub struct ServerBinding {
     /// Protocol-specific information for an AMQP 1.0 server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ampq1: Option<AMPQ1ServerBinding>,
-    ///	Protocol-specific information for an MQTT server.
+    /// Protocol-specific information for an MQTT server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mqtt: Option<MQTTServerBinding>,
     /// Protocol-specific information for an MQTT 5 server.
@@ -50,18 +57,53 @@ pub struct ServerBinding {
     /// Protocol-specific information for a Redis server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redis: Option<RedisServerBinding>,
-    ///	Protocol-specific information for a Mercure server.
+    /// Protocol-specific information for a Mercure server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mercure: Option<MercureServerBinding>,
     /// Protocol-specific information for an IBM MQ server.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ibmmq: Option<IBMMQServerBinding>,
+    /// Protocol-specific information for a Pulsar server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pulsar: Option<PulsarServerBinding>,
     /// This object can be extended with
     /// [Specification Extensions](https://www.asyncapi.com/docs/specifications/v2.3.0#specificationExtensions).
+    /// Bindings of other protocols are kept here as well, see
+    /// [custom][ServerBinding::custom].
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
 }
 
+impl ServerBinding {
+    /// The bindings of protocols this crate doesn't define a binding object
+    /// for, i.e. the [extensions][ServerBinding::extensions] that aren't
+    /// specification extensions.
+    pub fn custom(&self) -> impl Iterator<Item = (&str, &serde_json::Value)> {
+        self.extensions
+            .iter()
+            .filter(|(protocol, _)| !protocol.starts_with("x-"))
+            .map(|(protocol, binding)| (protocol.as_str(), binding))
+    }
+
+    /// The binding for `protocol` among the [custom][ServerBinding::custom] ones.
+    pub fn custom_binding(&self, protocol: &str) -> Option<&serde_json::Value> {
+        self.extensions
+            .get(protocol)
+            .filter(|_| !protocol.starts_with("x-"))
+    }
+}
+
+impl Validate for ServerBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(mqtt) = &self.mqtt {
+            mqtt.validate_at(&format!("{path}/mqtt"), errors);
+        }
+        if let Some(ibmmq) = &self.ibmmq {
+            ibmmq.validate_at(&format!("{path}/ibmmq"), errors);
+        }
+    }
+}
+
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct HTTPServerBinding {}
@@ -70,9 +112,32 @@ pub struct HTTPServerBinding {}
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct WebsocketsServerBinding {}
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains information about the server representation in Kafka.
+///
+/// # Examples
+///
+/// ```yaml
+/// servers:
+///   production:
+///     bindings:
+///       kafka:
+///         schemaRegistryUrl: 'https://my-schema-registry.com'
+///         schemaRegistryVendor: 'confluent'
+///         bindingVersion: '0.4.0'
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct KafkaServerBinding {}
+#[serde(rename_all = "camelCase")]
+pub struct KafkaServerBinding {
+    /// API URL for the Schema Registry used when producing Kafka messages (if a Schema Registry was used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry_url: Option<String>,
+    /// The vendor of Schema Registry and Kafka serdes library that should be used (e.g. `apicurio`, `confluent`, `ibm`, or `karapace`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry_vendor: Option<String>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -122,8 +187,21 @@ pub struct MQTTServerBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub keep_alive: Option<i32>,
     /// The version of this binding. If omitted, "latest" MUST be assumed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+impl Validate for MQTTServerBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(qos) = self.last_will.as_ref().and_then(|last_will| last_will.qos) {
+            if !(0..=2).contains(&qos) {
+                errors.push(ValidationError::new(
+                    format!("{path}/lastWill/qos"),
+                    format!("`{qos}` is neither 0, 1 nor 2"),
+                ));
+            }
+        }
+    }
 }
 
 /// Last Will and Testament configuration.
@@ -147,17 +225,84 @@ pub struct MQTTServerBindingLasWill {
     pub retain: Option<bool>,
 }
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains information about the server representation in MQTT 5.
+///
+/// # Examples
+///
+/// ```yaml
+/// servers:
+///   production:
+///     bindings:
+///       mqtt5:
+///         sessionExpiryInterval: 60
+///         bindingVersion: 0.2.0
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct MQTT5ServerBinding {}
+#[serde(rename_all = "camelCase")]
+pub struct MQTT5ServerBinding {
+    /// Session Expiry Interval in seconds or a Schema Object containing the
+    /// definition of the interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_expiry_interval: Option<IntegerOrSchema>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct NATSServerBinding {}
 
-/// This object MUST NOT contain any properties. Its name is reserved for future use.
+/// This object contains configuration for describing a JMS broker as an
+/// AsyncAPI server.
+///
+/// # Examples
+///
+/// ```yaml
+/// servers:
+///   production:
+///     url: jms://my-activemq-broker:61616
+///     protocol: jms
+///     protocolVersion: '1.1'
+///     bindings:
+///       jms:
+///         jmsConnectionFactory: org.apache.activemq.ActiveMQConnectionFactory
+///         properties:
+///           - name: disableTimeStampsByDefault
+///             value: false
+///         clientID: my-application-1
+///         bindingVersion: 0.0.1
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JMSServerBinding {
+    /// The classname of the ConnectionFactory implementation for the JMS
+    /// Provider.
+    pub jms_connection_factory: String,
+    /// Additional properties to set on the JMS ConnectionFactory
+    /// implementation for the JMS Provider.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<JMSServerBindingProperty>,
+    /// A client identifier for applications that use this JMS connection
+    /// factory. If the client ID policy is set to `Restricted` (the default),
+    /// then configuring a client ID on the connection factory prevents more
+    /// than one JMS client from using a connection from this factory.
+    #[serde(rename = "clientID", skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+/// A property to set on the JMS ConnectionFactory.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct JMSServerBinding {}
+#[serde(rename_all = "camelCase")]
+pub struct JMSServerBindingProperty {
+    /// The name of a property.
+    pub name: String,
+    /// The value of a property, a string, boolean, number or `null`.
+    pub value: serde_json::Value,
+}
 
 /// This object MUST NOT contain any properties. Its name is reserved for future use.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -167,8 +312,8 @@ pub struct SNSServerBinding {}
 #[serde(rename_all = "camelCase")]
 pub struct SolaceServerBinding {
     /// The current version is 0.2.0
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
     /// The Virtual Private Network name on the Solace broker.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub msg_vpn: Option<String>,
@@ -283,6 +428,131 @@ pub struct IBMMQServerBinding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heart_beat_interval: Option<i32>,
     /// The version of this binding.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+impl Validate for IBMMQServerBinding {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(interval) = self.heart_beat_interval {
+            if !(0..=999_999).contains(&interval) {
+                errors.push(ValidationError::new(
+                    format!("{path}/heartBeatInterval"),
+                    format!("`{interval}` is not between 0 and 999999"),
+                ));
+            }
+        }
+    }
+}
+
+/// This object contains information about the server representation in Pulsar.
+///
+/// # Examples
+///
+/// ```yaml
+/// servers:
+///   production:
+///     url: '{username}.in.mycompany.com:{port}'
+///     protocol: pulsar+ssl
+///     bindings:
+///       pulsar:
+///         tenant: contoso
+///         bindingVersion: '0.1.0'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PulsarServerBinding {
+    /// The pulsar tenant. If omitted, "public" MUST be assumed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub binding_version: Option<String>,
+    pub tenant: Option<String>,
+    /// The version of this binding. If omitted, "latest" MUST be assumed.
+    #[serde(default, skip_serializing_if = "BindingVersion::is_latest")]
+    pub binding_version: BindingVersion,
+}
+
+binding_builder! {
+    MQTTServerBinding => MQTTServerBindingBuilder {
+        client_id: Option<String>,
+        clean_session: Option<bool>,
+        last_will: Option<MQTTServerBindingLasWill>,
+        keep_alive: Option<i32>,
+        binding_version: BindingVersion,
+    }
+    IBMMQServerBinding => IBMMQServerBindingBuilder {
+        group_id: Option<String>,
+        ccdt_queue_manager_name: Option<String>,
+        cipher_spec: Option<String>,
+        multi_endpoint_server: Option<bool>,
+        heart_beat_interval: Option<i32>,
+        binding_version: BindingVersion,
+    }
+}
+
+#[test]
+fn test_pulsar_server_binding() {
+    let binding: ServerBinding = serde_yaml::from_str(
+        r#"
+        pulsar:
+          tenant: contoso
+          bindingVersion: '0.1.0'
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        binding.pulsar,
+        Some(PulsarServerBinding {
+            tenant: Some("contoso".to_string()),
+            binding_version: BindingVersion::new("0.1.0"),
+        })
+    );
+    assert!(binding.extensions.is_empty());
+}
+
+#[test]
+fn test_mqtt5_server_binding() {
+    let binding: ServerBinding = serde_yaml::from_str(
+        r#"
+        mqtt5:
+          sessionExpiryInterval:
+            type: integer
+            minimum: 30
+            maximum: 1200
+          bindingVersion: 0.2.0
+        "#,
+    )
+    .unwrap();
+    let mqtt5 = binding.mqtt5.unwrap();
+    assert!(matches!(
+        mqtt5.session_expiry_interval,
+        Some(IntegerOrSchema::Schema(_))
+    ));
+
+    let binding: MQTT5ServerBinding = serde_yaml::from_str("sessionExpiryInterval: 60").unwrap();
+    assert_eq!(
+        binding.session_expiry_interval,
+        Some(IntegerOrSchema::Integer(60))
+    );
+}
+
+#[test]
+fn test_jms_server_binding() {
+    let binding: ServerBinding = serde_yaml::from_str(
+        r#"
+        jms:
+          jmsConnectionFactory: org.apache.activemq.ActiveMQConnectionFactory
+          properties:
+            - name: disableTimeStampsByDefault
+              value: false
+          clientID: my-application-1
+          bindingVersion: 0.0.1
+        "#,
+    )
+    .unwrap();
+    let jms = binding.jms.unwrap();
+    assert_eq!(jms.client_id.as_deref(), Some("my-application-1"));
+    assert_eq!(jms.properties[0].value, false);
+    assert_eq!(
+        serde_json::to_value(&jms).unwrap()["clientID"],
+        "my-application-1"
+    );
 }
@@ -0,0 +1,170 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The version of the AsyncAPI Specification a document complies to, the
+/// value of its `asyncapi` field.
+///
+/// Versions this crate doesn't know about are kept as
+/// [`Unknown`][SpecVersion::Unknown]. The `supports_*` helpers look at the
+/// `major`.`minor` part only, so they work for those as well.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::SpecVersion;
+///
+/// let version: SpecVersion = "2.4.0".parse().unwrap();
+/// assert_eq!(version, SpecVersion::V2_4_0);
+/// assert!(version.supports_message_id());
+/// assert!(!SpecVersion::V2_3_0.supports_message_id());
+///
+/// let patched: SpecVersion = "2.6.1".parse().unwrap();
+/// assert_eq!(patched, SpecVersion::Unknown("2.6.1".to_string()));
+/// assert!(patched.supports_server_tags());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpecVersion {
+    V2_0_0,
+    V2_1_0,
+    V2_2_0,
+    V2_3_0,
+    V2_4_0,
+    V2_5_0,
+    V2_6_0,
+    V3_0_0,
+    Unknown(String),
+}
+
+impl SpecVersion {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SpecVersion::V2_0_0 => "2.0.0",
+            SpecVersion::V2_1_0 => "2.1.0",
+            SpecVersion::V2_2_0 => "2.2.0",
+            SpecVersion::V2_3_0 => "2.3.0",
+            SpecVersion::V2_4_0 => "2.4.0",
+            SpecVersion::V2_5_0 => "2.5.0",
+            SpecVersion::V2_6_0 => "2.6.0",
+            SpecVersion::V3_0_0 => "3.0.0",
+            SpecVersion::Unknown(version) => version,
+        }
+    }
+
+    /// The `major` and `minor` part of the version, if it has the
+    /// `major`.`minor`.`patch` form.
+    pub fn major_minor(&self) -> Option<(u32, u32)> {
+        let mut parts = self.as_str().splitn(3, '.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        parts.next()?;
+        Some((major, minor))
+    }
+
+    /// Whether the version is `major`.`minor` or a later one.
+    pub fn is_at_least(&self, major: u32, minor: u32) -> bool {
+        self.major_minor()
+            .is_some_and(|version| version >= (major, minor))
+    }
+
+    /// Whether messages have a `messageId`, added in 2.4.0 and removed in
+    /// 3.0.0, where the key of a message in its map identifies it.
+    pub fn supports_message_id(&self) -> bool {
+        self.is_at_least(2, 4) && self.major_minor().is_some_and(|(major, _)| major == 2)
+    }
+
+    /// Whether server variables can be references to
+    /// `components.serverVariables`, added in 2.4.0.
+    pub fn supports_server_variable_references(&self) -> bool {
+        self.is_at_least(2, 4)
+    }
+
+    /// Whether servers have `tags`, added in 2.5.0.
+    pub fn supports_server_tags(&self) -> bool {
+        self.is_at_least(2, 5)
+    }
+
+    /// Whether the document uses the 3.x layout with top-level operations.
+    pub fn is_v3(&self) -> bool {
+        self.major_minor().is_some_and(|(major, _)| major == 3)
+    }
+}
+
+impl Default for SpecVersion {
+    /// An empty, and therefore unknown, version.
+    fn default() -> Self {
+        SpecVersion::Unknown(String::new())
+    }
+}
+
+impl FromStr for SpecVersion {
+    type Err = Infallible;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        Ok(match version {
+            "2.0.0" => SpecVersion::V2_0_0,
+            "2.1.0" => SpecVersion::V2_1_0,
+            "2.2.0" => SpecVersion::V2_2_0,
+            "2.3.0" => SpecVersion::V2_3_0,
+            "2.4.0" => SpecVersion::V2_4_0,
+            "2.5.0" => SpecVersion::V2_5_0,
+            "2.6.0" => SpecVersion::V2_6_0,
+            "3.0.0" => SpecVersion::V3_0_0,
+            version => SpecVersion::Unknown(version.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for SpecVersion {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SpecVersion {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Serialize for SpecVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SpecVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let version = String::deserialize(deserializer)?;
+        match version.parse() {
+            Ok(version) => Ok(version),
+            Err(infallible) => match infallible {},
+        }
+    }
+}
+
+#[test]
+fn test_spec_version_round_trip() {
+    for version in ["2.0.0", "2.6.0", "3.0.0", "2.7.0-rc1"] {
+        let parsed: SpecVersion = serde_json::from_value(version.into()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), version);
+    }
+    assert!(SpecVersion::V3_0_0.is_v3());
+    assert!(!SpecVersion::V3_0_0.supports_message_id());
+    assert!(SpecVersion::V2_6_0.supports_message_id());
+    assert_eq!(
+        "2.7.0-rc1".parse::<SpecVersion>().unwrap().major_minor(),
+        Some((2, 7))
+    );
+    assert_eq!("latest".parse::<SpecVersion>().unwrap().major_minor(), None);
+    assert!(!"latest"
+        .parse::<SpecVersion>()
+        .unwrap()
+        .supports_server_tags());
+}
@@ -0,0 +1,59 @@
+//! Data structures for [AsyncAPI 3.0](https://www.asyncapi.com/docs/reference/specification/v3.0.0)
+//! documents.
+//!
+//! Version 3.0 reorganizes the document: channels are keyed by an identifier
+//! instead of their address, and operations are no longer nested in channels
+//! but live in the top-level `operations` object and point to their channel.
+//! Objects that changed have their own types in this module: besides the
+//! document, channels and operations, these are the servers, parameters,
+//! messages and message traits, which lost `messageId` and `schemaFormat`,
+//! and the security schemes, whose OAuth 2.0 flows list `availableScopes`.
+//! Objects whose shape did not change, like [Schema][crate::Schema], tags or
+//! the bindings, are shared with the 2.x types in the crate root.
+use serde::{Deserialize, Serialize};
+
+mod api;
+mod channel;
+mod components;
+mod info;
+mod message;
+mod message_trait;
+mod operation;
+mod operation_trait;
+mod parameter;
+mod reply;
+mod security_scheme;
+mod server;
+
+pub use api::AsyncApiV3;
+pub use channel::Channel;
+pub use components::Components;
+pub use info::Info;
+pub use message::Message;
+pub use message_trait::MessageTrait;
+pub use operation::{Action, Operation};
+pub use operation_trait::OperationTrait;
+pub use parameter::Parameter;
+pub use reply::{Reply, ReplyAddress};
+pub use security_scheme::{
+    OAuthFlowAuthorizationCode, OAuthFlowClientCredentials, OAuthFlowImplicit, OAuthFlowPassword,
+    OAuthFlows, SecurityScheme,
+};
+pub use server::Server;
+
+/// A reference to another object of the document, e.g. the servers of a
+/// channel. Unlike [ReferenceOr][crate::ReferenceOr], the object can't be
+/// inlined.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Reference {
+    #[serde(rename = "$ref")]
+    pub reference: String,
+}
+
+impl Reference {
+    pub fn new(reference: impl Into<String>) -> Self {
+        Reference {
+            reference: reference.into(),
+        }
+    }
+}
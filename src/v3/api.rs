@@ -0,0 +1,153 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Channel, Components, Info, Operation, Server};
+use crate::{Identifier, MediaType, ReferenceOr, SpecVersion};
+
+/// The root document object of an AsyncAPI 3.0 document.
+///
+/// # Examples
+///
+/// ```yaml
+/// asyncapi: 3.0.0
+/// info:
+///   title: Account Service
+///   version: 1.0.0
+/// channels:
+///   userSignedUp:
+///     address: user/signedup
+///     messages:
+///       UserSignedUp:
+///         $ref: '#/components/messages/UserSignedUp'
+/// operations:
+///   sendUserSignedUp:
+///     action: send
+///     channel:
+///       $ref: '#/channels/userSignedUp'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AsyncApiV3 {
+    /// **Required.** Specifies the AsyncAPI Specification version being used,
+    /// e.g. `3.0.0`.
+    pub asyncapi: SpecVersion,
+    /// Identifier of the application the AsyncAPI document is defining. It
+    /// must conform to the URI format, a URN is RECOMMENDED.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Identifier>,
+    /// **Required.** Provides metadata about the API.
+    pub info: Info,
+    /// Provides connection details of servers.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub servers: IndexMap<String, ReferenceOr<Server>>,
+    /// Default content type to use when encoding/decoding a message's payload.
+    /// The value MUST be a specific media type (e.g. `application/json`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_content_type: Option<MediaType>,
+    /// The channels used by this application, keyed by an identifier that is
+    /// unique within the document.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub channels: IndexMap<String, ReferenceOr<Channel>>,
+    /// The operations this application MUST implement, keyed by an
+    /// identifier that is unique within the document.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub operations: IndexMap<String, ReferenceOr<Operation>>,
+    /// An element to hold various reusable objects for the specification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Components>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl AsyncApiV3 {
+    /// Creates the smallest valid 3.0 document: an API called `title` in
+    /// `version` without any servers, channels or operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let api = asyncapi::v3::AsyncApiV3::new("Account Service", "1.0.0");
+    /// assert_eq!(api.asyncapi, "3.0.0");
+    /// ```
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        AsyncApiV3 {
+            asyncapi: SpecVersion::V3_0_0,
+            info: Info {
+                title: title.into(),
+                version: version.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+#[test]
+fn test_deserialize_root_document() {
+    let example = r#"
+    asyncapi: 3.0.0
+    id: 'urn:example:com:smartylighting:streetlights:server'
+    info:
+      title: Streetlights Kafka API
+      version: 1.0.0
+      license:
+        name: Apache 2.0
+      tags:
+        - $ref: '#/components/tags/lights'
+      externalDocs:
+        url: https://streetlights.example.com/docs
+    defaultContentType: application/json
+    servers:
+      scram-connections:
+        host: test.mykafkacluster.org:18092
+        protocol: kafka-secure
+        description: Test broker secured with scramSha256
+        security:
+          - $ref: '#/components/securitySchemes/saslScram'
+    channels:
+      lightingMeasured:
+        description: The topic on which measured values may be produced and consumed.
+        servers:
+          - $ref: '#/servers/scram-connections'
+    operations:
+      receiveLightMeasurement:
+        action: receive
+        channel:
+          $ref: '#/channels/lightingMeasured'
+        summary: Inform about environmental lighting conditions of a particular streetlight.
+    components:
+      tags:
+        lights:
+          name: lights
+          description: Operations on the streetlights.
+      securitySchemes:
+        saslScram:
+          type: scramSha256
+    "#;
+    let api: AsyncApiV3 = serde_yaml::from_str(example).unwrap();
+    assert_eq!(
+        api.info.tags,
+        [ReferenceOr::ref_("#/components/tags/lights")]
+    );
+    assert!(api.info.extensions.is_empty());
+    let ReferenceOr::Item(server) = &api.servers["scram-connections"] else {
+        panic!("expected an inline server");
+    };
+    assert_eq!(server.host, "test.mykafkacluster.org:18092");
+    assert_eq!(
+        server.security,
+        [ReferenceOr::ref_("#/components/securitySchemes/saslScram")]
+    );
+    let ReferenceOr::Item(channel) = &api.channels["lightingMeasured"] else {
+        panic!("expected an inline channel");
+    };
+    assert_eq!(channel.servers[0].reference, "#/servers/scram-connections");
+    assert!(api.operations.contains_key("receiveLightMeasurement"));
+    assert!(api.extensions.is_empty());
+    assert!(api.components.as_ref().unwrap().tags.contains_key("lights"));
+
+    let value = serde_json::to_value(&api).unwrap();
+    assert_eq!(serde_json::from_value::<AsyncApiV3>(value).unwrap(), api);
+}
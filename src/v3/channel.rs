@@ -0,0 +1,236 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Message, Parameter, Reference};
+use crate::{ChannelBinding, ExternalDocumentation, ReferenceOr, Tag};
+
+/// Describes a shared communication channel.
+///
+/// Channels are keyed by an identifier in the document, the actual topic,
+/// routing key or path is the channel's `address`.
+///
+/// # Examples
+///
+/// ```yaml
+/// address: 'users.{userId}'
+/// title: Users channel
+/// description: This channel is used to exchange messages about user events.
+/// messages:
+///   userSignedUp:
+///     $ref: '#/components/messages/userSignedUp'
+///   userCompletedOrder:
+///     $ref: '#/components/messages/userCompletedOrder'
+/// parameters:
+///   userId:
+///     $ref: '#/components/parameters/userId'
+/// servers:
+///   - $ref: '#/servers/rabbitmqInProd'
+/// bindings:
+///   amqp:
+///     is: queue
+///     queue:
+///       exclusive: true
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    /// The address of the channel, e.g. a topic name, routing key or path.
+    /// It MAY contain parameters as in `users.{userId}`. When omitted, the
+    /// address is unknown or dynamic, e.g. when it's only known at runtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// The messages that can be sent on this channel, keyed by an identifier
+    /// that [Operations][super::Operation] reference them by.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub messages: IndexMap<String, ReferenceOr<Message>>,
+    /// A human-friendly title for the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// An optional description of this channel.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// References to the [Server Objects][super::Server] this channel is
+    /// available on. If empty, the channel is available on all servers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<Reference>,
+    /// A map of the parameters included in the channel address.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub parameters: IndexMap<String, ReferenceOr<Parameter>>,
+    /// A list of tags for logical grouping of channels.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<ChannelBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+#[test]
+fn test_deserialize_streetlights_example() {
+    use super::{Action, AsyncApiV3};
+
+    // From the official 3.0.0 examples in the AsyncAPI spec repository.
+    let example = r#"
+    asyncapi: 3.0.0
+    info:
+      title: Streetlights Kafka API
+      version: 1.0.0
+      description: The Smartylighting Streetlights API allows you to remotely manage the city lights.
+      license:
+        name: Apache 2.0
+        url: https://www.apache.org/licenses/LICENSE-2.0
+    defaultContentType: application/json
+    servers:
+      scram-connections:
+        host: test.mykafkacluster.org:18092
+        protocol: kafka-secure
+        description: Test broker secured with scramSha256
+        security:
+          - $ref: '#/components/securitySchemes/saslScram'
+        tags:
+          - name: env:test-scram
+            description: This environment is meant for running internal tests through scramSha256
+    channels:
+      lightingMeasured:
+        address: smartylighting.streetlights.1.0.event.{streetlightId}.lighting.measured
+        messages:
+          lightMeasured:
+            $ref: '#/components/messages/lightMeasured'
+        description: The topic on which measured values may be produced and consumed.
+        parameters:
+          streetlightId:
+            $ref: '#/components/parameters/streetlightId'
+      lightTurnOn:
+        address: smartylighting.streetlights.1.0.action.{streetlightId}.turn.on
+        messages:
+          turnOn:
+            $ref: '#/components/messages/turnOnOff'
+        parameters:
+          streetlightId:
+            $ref: '#/components/parameters/streetlightId'
+    operations:
+      receiveLightMeasurement:
+        action: receive
+        channel:
+          $ref: '#/channels/lightingMeasured'
+        summary: Inform about environmental lighting conditions of a particular streetlight.
+        traits:
+          - $ref: '#/components/operationTraits/kafka'
+        messages:
+          - $ref: '#/channels/lightingMeasured/messages/lightMeasured'
+      turnOn:
+        action: send
+        channel:
+          $ref: '#/channels/lightTurnOn'
+        traits:
+          - $ref: '#/components/operationTraits/kafka'
+        messages:
+          - $ref: '#/channels/lightTurnOn/messages/turnOn'
+    components:
+      messages:
+        lightMeasured:
+          name: lightMeasured
+          title: Light measured
+          summary: Inform about environmental lighting conditions of a particular streetlight.
+          contentType: application/json
+          payload:
+            $ref: '#/components/schemas/lightMeasuredPayload'
+        turnOnOff:
+          name: turnOnOff
+          title: Turn on/off
+          summary: Command a particular streetlight to turn the lights on or off.
+          payload:
+            $ref: '#/components/schemas/turnOnOffPayload'
+      schemas:
+        lightMeasuredPayload:
+          type: object
+          properties:
+            lumens:
+              type: integer
+              minimum: 0
+              description: Light intensity measured in lumens.
+            sentAt:
+              $ref: '#/components/schemas/sentAt'
+        turnOnOffPayload:
+          type: object
+          properties:
+            command:
+              type: string
+              enum:
+                - 'on'
+                - 'off'
+              description: Whether to turn on or off the light.
+            sentAt:
+              $ref: '#/components/schemas/sentAt'
+        sentAt:
+          type: string
+          format: date-time
+          description: Date and time when the message was sent.
+      securitySchemes:
+        saslScram:
+          type: scramSha256
+          description: Provide your username and password for SASL/SCRAM authentication
+      parameters:
+        streetlightId:
+          description: The ID of the streetlight.
+      operationTraits:
+        kafka:
+          bindings:
+            kafka:
+              clientId:
+                type: string
+                enum:
+                  - my-app-id
+    "#;
+    let api: AsyncApiV3 = serde_yaml::from_str(example).unwrap();
+
+    let ReferenceOr::Item(channel) = &api.channels["lightingMeasured"] else {
+        panic!("expected an inline channel");
+    };
+    assert_eq!(
+        channel.address.as_deref(),
+        Some("smartylighting.streetlights.1.0.event.{streetlightId}.lighting.measured")
+    );
+    assert_eq!(
+        channel.messages["lightMeasured"],
+        ReferenceOr::ref_("#/components/messages/lightMeasured")
+    );
+    assert_eq!(
+        channel.parameters["streetlightId"],
+        ReferenceOr::ref_("#/components/parameters/streetlightId")
+    );
+    assert!(channel.extensions.is_empty());
+
+    let ReferenceOr::Item(operation) = &api.operations["turnOn"] else {
+        panic!("expected an inline operation");
+    };
+    assert_eq!(operation.action, Action::Send);
+    assert_eq!(operation.channel.reference, "#/channels/lightTurnOn");
+
+    let components = api.components.as_ref().unwrap();
+    assert!(matches!(
+        &components.parameters["streetlightId"],
+        ReferenceOr::Item(parameter) if parameter.description.as_deref() == Some("The ID of the streetlight.")
+    ));
+
+    let value = serde_json::to_value(&api).unwrap();
+    assert_eq!(serde_json::from_value::<AsyncApiV3>(value).unwrap(), api);
+}
+
+#[test]
+fn test_channel_without_address() {
+    let channel: Channel = serde_yaml::from_str("address: null\ndescription: Replies").unwrap();
+    assert_eq!(channel.address, None);
+    assert!(channel.extensions.is_empty());
+}
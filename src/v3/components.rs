@@ -0,0 +1,96 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Channel, Message, MessageTrait, Operation, OperationTrait, Parameter, Reply, ReplyAddress,
+    SecurityScheme, Server,
+};
+use crate::{
+    ChannelBinding, CorrelationId, ExternalDocumentation, MessageBinding, OperationBinding,
+    Payload, ReferenceOr, ServerBinding, ServerVariable, Tag,
+};
+
+/// Holds a set of reusable objects for different aspects of the AsyncAPI
+/// specification. All objects defined within the components object will have
+/// no effect on the API unless they are explicitly referenced from properties
+/// outside the components object.
+///
+/// All the fixed fields declared above are objects that MUST use keys that
+/// match the regular expression: `^[a-zA-Z0-9\.\-_]+$`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Components {
+    /// An object to hold reusable [Schema Objects][crate::Schema] and
+    /// [Multi Format Schema Objects][crate::MultiFormatSchema].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub schemas: IndexMap<String, Payload>,
+    /// An object to hold reusable [Server Objects][super::Server].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub servers: IndexMap<String, ReferenceOr<Server>>,
+    /// An object to hold reusable [Channel Objects][super::Channel].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub channels: IndexMap<String, ReferenceOr<Channel>>,
+    /// An object to hold reusable [Operation Objects][super::Operation].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub operations: IndexMap<String, ReferenceOr<Operation>>,
+    /// An object to hold reusable [Message Objects][super::Message].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub messages: IndexMap<String, ReferenceOr<Message>>,
+    /// An object to hold reusable
+    /// [Security Scheme Objects][super::SecurityScheme].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub security_schemes: IndexMap<String, ReferenceOr<SecurityScheme>>,
+    /// An object to hold reusable
+    /// [Server Variable Objects][crate::ServerVariable].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub server_variables: IndexMap<String, ReferenceOr<ServerVariable>>,
+    /// An object to hold reusable [Parameter Objects][super::Parameter].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub parameters: IndexMap<String, ReferenceOr<Parameter>>,
+    /// An object to hold reusable
+    /// [Correlation ID Objects][crate::CorrelationId].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub correlation_ids: IndexMap<String, ReferenceOr<CorrelationId>>,
+    /// An object to hold reusable
+    /// [Operation Trait Objects][super::OperationTrait].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub operation_traits: IndexMap<String, ReferenceOr<OperationTrait>>,
+    /// An object to hold reusable
+    /// [Message Trait Objects][super::MessageTrait].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub message_traits: IndexMap<String, ReferenceOr<MessageTrait>>,
+    /// An object to hold reusable [Reply Objects][super::Reply].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub replies: IndexMap<String, ReferenceOr<Reply>>,
+    /// An object to hold reusable
+    /// [Reply Address Objects][super::ReplyAddress].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub reply_addresses: IndexMap<String, ReferenceOr<ReplyAddress>>,
+    /// An object to hold reusable [Tag Objects][crate::Tag].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub tags: IndexMap<String, ReferenceOr<Tag>>,
+    /// An object to hold reusable
+    /// [External Documentation Objects][crate::ExternalDocumentation].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub external_docs: IndexMap<String, ReferenceOr<ExternalDocumentation>>,
+    /// An object to hold reusable
+    /// [Server Bindings Objects][crate::ServerBinding].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub server_bindings: IndexMap<String, ReferenceOr<ServerBinding>>,
+    /// An object to hold reusable
+    /// [Channel Bindings Objects][crate::ChannelBinding].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub channel_bindings: IndexMap<String, ReferenceOr<ChannelBinding>>,
+    /// An object to hold reusable
+    /// [Operation Bindings Objects][crate::OperationBinding].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub operation_bindings: IndexMap<String, ReferenceOr<OperationBinding>>,
+    /// An object to hold reusable
+    /// [Message Bindings Objects][crate::MessageBinding].
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub message_bindings: IndexMap<String, ReferenceOr<MessageBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
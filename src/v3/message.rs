@@ -0,0 +1,135 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::MessageTrait;
+use crate::{
+    CorrelationId, Example, ExternalDocumentation, MediaType, MessageBinding, Payload, ReferenceOr,
+    Schema, Tag,
+};
+
+/// Describes a message received on a given channel and operation.
+///
+/// Unlike [2.x messages][crate::Message], a 3.0 message has no `messageId`,
+/// it is identified by its key in the `messages` of a channel or of the
+/// components, and no `schemaFormat`, a payload in another format is a
+/// [Multi Format Schema Object][crate::MultiFormatSchema].
+///
+/// # Examples
+///
+/// ```yaml
+/// name: UserSignup
+/// title: User signup
+/// summary: Action to sign a user up.
+/// contentType: application/json
+/// tags:
+///   - name: user
+///   - $ref: '#/components/tags/signup'
+/// externalDocs:
+///   $ref: '#/components/externalDocs/signup'
+/// payload:
+///   schemaFormat: application/vnd.apache.avro;version=1.9.0
+///   schema:
+///     $ref: 'path/to/user-create.avsc#/UserCreate'
+/// traits:
+///   - $ref: '#/components/messageTraits/commonHeaders'
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    /// Schema definition of the application headers.
+    /// Schema MUST be of type "object". It **MUST NOT** define the protocol headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<ReferenceOr<Schema>>,
+    /// Definition of the message payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Payload>,
+    /// Definition of the correlation ID used for message tracing or matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<ReferenceOr<CorrelationId>>,
+    /// The content type to use when encoding/decoding a message's payload.
+    /// When omitted, the value MUST be the one specified on the
+    /// defaultContentType field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<MediaType>,
+    /// A machine-friendly name for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A human-friendly title for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the message is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the message.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A list of tags for logical grouping and categorization of messages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<MessageBinding>>,
+    /// A list of examples.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Example>,
+    /// A list of traits to apply to the message object. Traits MUST be merged
+    /// into the message object in the same order they are listed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traits: Vec<ReferenceOr<MessageTrait>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+#[test]
+fn test_message_tag_references() {
+    let message: Message = serde_yaml::from_str(
+        r#"
+        name: UserSignedUp
+        tags:
+          - $ref: '#/components/tags/user'
+          - name: signup
+        externalDocs:
+          $ref: '#/components/externalDocs/signup'
+        traits:
+          - tags:
+              - $ref: '#/components/tags/user'
+        "#,
+    )
+    .expect("Could not deserialize message");
+    assert_eq!(message.tags[0], ReferenceOr::ref_("#/components/tags/user"));
+    assert!(matches!(&message.tags[1], ReferenceOr::Item(tag) if tag.name == "signup"));
+    assert_eq!(
+        message.external_docs,
+        Some(ReferenceOr::ref_("#/components/externalDocs/signup"))
+    );
+    let ReferenceOr::Item(message_trait) = &message.traits[0] else {
+        panic!("expected an inline trait");
+    };
+    assert_eq!(
+        message_trait.tags,
+        [ReferenceOr::ref_("#/components/tags/user")]
+    );
+}
+
+#[test]
+fn test_message_has_no_message_id_or_schema_format() {
+    use crate::ParseOptions;
+
+    let input = "messageId: userSignedUp\nschemaFormat: application/vnd.apache.avro;version=1.9.0";
+    let message: Message = serde_yaml::from_str(input).unwrap();
+    assert_eq!(
+        message.extensions.keys().collect::<Vec<_>>(),
+        ["messageId", "schemaFormat"]
+    );
+    assert!(ParseOptions::new()
+        .deny_unknown_fields(true)
+        .parse::<Message>(input)
+        .is_err());
+}
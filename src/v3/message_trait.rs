@@ -0,0 +1,55 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    message_trait::MessageExample, CorrelationId, ExternalDocumentation, MediaType, MessageBinding,
+    ReferenceOr, Schema, Tag,
+};
+
+/// Describes a trait that MAY be applied to a [Message Object][super::Message].
+/// This object MAY contain any property from the
+/// [Message Object][super::Message], except `payload` and `traits`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageTrait {
+    /// Schema definition of the application headers.
+    /// Schema MUST be of type "object". It **MUST NOT** define the protocol headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<ReferenceOr<Schema>>,
+    /// Definition of the correlation ID used for message tracing or matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<ReferenceOr<CorrelationId>>,
+    /// The content type to use when encoding/decoding a message's payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<MediaType>,
+    /// A machine-friendly name for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A human-friendly title for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the message is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the message.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A list of tags for logical grouping and categorization of messages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<MessageBinding>>,
+    /// A list of examples.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<MessageExample>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
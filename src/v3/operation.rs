@@ -0,0 +1,188 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::{OperationTrait, Reference, Reply, SecurityScheme};
+use crate::{ExternalDocumentation, OperationBinding, ReferenceOr, Tag};
+
+/// Describes a specific operation.
+///
+/// # Examples
+///
+/// ```yaml
+/// title: User sign up
+/// summary: Action to sign a user up.
+/// description: A longer description
+/// channel:
+///   $ref: '#/channels/userSignup'
+/// action: send
+/// tags:
+///   - name: user
+///   - name: signup
+///   - name: register
+/// bindings:
+///   amqp:
+///     ack: false
+/// traits:
+///   - $ref: '#/components/operationTraits/kafka'
+/// messages:
+///   - $ref: '#/components/messages/userSignup'
+/// reply:
+///   channel:
+///     $ref: '#/channels/userSignupReply'
+/// ```
+///
+/// The same operation built in code:
+///
+/// ```
+/// use asyncapi::v3::{Action, Operation, Reference};
+///
+/// let mut operation = Operation::new(Action::Send, Reference::new("#/channels/userSignup"));
+/// operation.summary = Some("Action to sign a user up.".to_string());
+/// operation
+///     .messages
+///     .push(Reference::new("#/components/messages/userSignup"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// **Required.** Whether the application will send messages to or expect
+    /// messages from the given channel.
+    pub action: Action,
+    /// **Required.** A reference to the [Channel][super::Channel] this
+    /// operation is performed on.
+    pub channel: Reference,
+    /// A human-friendly title for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the operation is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the operation.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A declaration of which security schemes are associated with this
+    /// operation. Using any of them is enough to authorize the operation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<ReferenceOr<SecurityScheme>>,
+    /// A list of tags for logical grouping and categorization of operations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<OperationBinding>>,
+    /// A list of traits to apply to the operation object. Traits MUST be
+    /// merged using traits merge mechanism. The resulting object MUST be a
+    /// valid [Operation Object][Operation].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traits: Vec<ReferenceOr<OperationTrait>>,
+    /// References to the messages of the channel this operation is about.
+    /// Every message MUST be available on the referenced channel. If empty,
+    /// the operation is about all messages of the channel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<Reference>,
+    /// The definition of the reply in a request-reply operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply: Option<ReferenceOr<Reply>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Operation {
+    /// Creates an operation that performs `action` on the referenced
+    /// `channel`, leaving all optional fields empty.
+    pub fn new(action: Action, channel: Reference) -> Self {
+        Operation {
+            action,
+            channel,
+            title: None,
+            summary: None,
+            description: None,
+            security: Vec::new(),
+            tags: Vec::new(),
+            external_docs: None,
+            bindings: None,
+            traits: Vec::new(),
+            messages: Vec::new(),
+            reply: None,
+            extensions: IndexMap::new(),
+        }
+    }
+}
+
+/// What the application described by the document does in an
+/// [Operation][Operation].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    /// The application sends messages to the channel.
+    Send,
+    /// The application expects to receive messages from the channel.
+    Receive,
+}
+
+impl Action {
+    /// The value of the `action` field.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::Send => "send",
+            Action::Receive => "receive",
+        }
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[test]
+fn test_deserialize_operation() {
+    let example = r#"
+    title: User sign up
+    channel:
+      $ref: '#/channels/userSignup'
+    action: send
+    traits:
+      - $ref: '#/components/operationTraits/kafka'
+    messages:
+      - $ref: '#/components/messages/userSignup'
+    reply:
+      channel:
+        $ref: '#/channels/userSignupReply'
+    "#;
+    let operation: Operation = serde_yaml::from_str(example).unwrap();
+    assert_eq!(operation.action, Action::Send);
+    assert_eq!(operation.channel, Reference::new("#/channels/userSignup"));
+    assert_eq!(
+        operation.messages,
+        [Reference::new("#/components/messages/userSignup")]
+    );
+    let Some(ReferenceOr::Item(reply)) = &operation.reply else {
+        panic!("expected an inline reply");
+    };
+    assert_eq!(
+        reply.channel,
+        Some(Reference::new("#/channels/userSignupReply"))
+    );
+    assert!(operation.extensions.is_empty());
+
+    let value = serde_json::to_value(&operation).unwrap();
+    assert_eq!(value["action"], "send");
+    assert_eq!(
+        serde_json::from_value::<Operation>(value).unwrap(),
+        operation
+    );
+
+    assert!(serde_yaml::from_str::<Operation>(
+        "action: publish\nchannel:\n  $ref: '#/channels/userSignup'"
+    )
+    .is_err());
+}
@@ -0,0 +1,42 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::SecurityScheme;
+use crate::{ExternalDocumentation, OperationBinding, ReferenceOr, Tag};
+
+/// Describes a trait that MAY be applied to an
+/// [Operation Object][super::Operation]. This object MAY contain any property
+/// from the [Operation Object][super::Operation], except the `action`,
+/// `channel` and `traits` ones.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationTrait {
+    /// A human-friendly title for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of what the operation is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the operation.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A declaration of which security schemes are associated with this
+    /// operation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<ReferenceOr<SecurityScheme>>,
+    /// A list of tags for logical grouping and categorization of operations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<OperationBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
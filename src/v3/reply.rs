@@ -0,0 +1,123 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::Reference;
+use crate::{
+    validation::{Validate, ValidationError},
+    ReferenceOr, RuntimeExpression,
+};
+
+/// Describes the reply part of a request-reply
+/// [Operation][super::Operation].
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Reply {
+    /// Where the reply should be sent to, determined at runtime from the
+    /// request message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<ReferenceOr<ReplyAddress>>,
+    /// A reference to the [Channel][super::Channel] the reply is sent on.
+    /// When omitted, the reply is sent on the channel given at runtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Reference>,
+    /// References to the messages that can be sent as a reply. Every message
+    /// MUST be available on the reply channel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<Reference>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// The location of the address a reply should be sent to, for when the
+/// channel is only known at runtime.
+///
+/// # Examples
+///
+/// ```yaml
+/// description: Consumer inbox
+/// location: $message.header#/replyTo
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyAddress {
+    /// An optional description of the address.
+    /// [CommonMark syntax](https://spec.commonmark.org/) can be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// **REQUIRED.** A runtime expression that specifies the location of the
+    /// reply address, e.g. `$message.header#/replyTo`.
+    pub location: RuntimeExpression,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Validate for ReplyAddress {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.location
+            .validate_at(&format!("{path}/location"), errors);
+    }
+}
+
+#[test]
+fn test_request_reply_round_trip() {
+    use super::{AsyncApiV3, Operation};
+
+    let example = r#"
+    asyncapi: 3.0.0
+    info:
+      title: Ping/pong example with reply address
+      version: 1.0.0
+    channels:
+      ping:
+        description: Requester sends requests here.
+      pong:
+        description: Reply address is determined at runtime.
+    operations:
+      pingRequest:
+        action: send
+        channel:
+          $ref: '#/channels/ping'
+        reply:
+          $ref: '#/components/replies/pong'
+    components:
+      replies:
+        pong:
+          address:
+            $ref: '#/components/replyAddresses/inbox'
+          channel:
+            $ref: '#/channels/pong'
+      replyAddresses:
+        inbox:
+          description: Reply is sent to the topic given in the request
+          location: $message.header#/replyTo
+    "#;
+    let api: AsyncApiV3 = serde_yaml::from_str(example).unwrap();
+    let ReferenceOr::Item(Operation {
+        reply: Some(reply), ..
+    }) = &api.operations["pingRequest"]
+    else {
+        panic!("expected an operation with a reply");
+    };
+    assert_eq!(reply, &ReferenceOr::ref_("#/components/replies/pong"));
+
+    let components = api.components.as_ref().unwrap();
+    let ReferenceOr::Item(pong) = &components.replies["pong"] else {
+        panic!("expected an inline reply");
+    };
+    assert_eq!(
+        pong.address,
+        Some(ReferenceOr::ref_("#/components/replyAddresses/inbox"))
+    );
+    let ReferenceOr::Item(inbox) = &components.reply_addresses["inbox"] else {
+        panic!("expected an inline reply address");
+    };
+    assert_eq!(inbox.location.pointer(), Some("/replyTo"));
+    assert!(inbox.validate().is_ok());
+
+    let value = serde_json::to_value(&api).unwrap();
+    assert_eq!(serde_json::from_value::<AsyncApiV3>(value).unwrap(), api);
+}
@@ -0,0 +1,366 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{ApiKeyLocation, HttpApiKeyLocation};
+
+/// Defines a security scheme that can be used by the operations and servers.
+/// The types of schemes are the same as in
+/// [2.x][crate::SecurityScheme], but OAuth 2.0 flows list their
+/// `availableScopes`, and OAuth 2.0 and OpenID Connect schemes list the
+/// `scopes` they require where they are used.
+///
+/// # Examples
+///
+/// ```yaml
+/// type: oauth2
+/// flows:
+///   implicit:
+///     authorizationUrl: https://example.com/api/oauth/dialog
+///     availableScopes:
+///       write:pets: modify pets in your account
+///       read:pets: read your pets
+/// scopes:
+///   - write:pets
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
+pub enum SecurityScheme {
+    #[serde(rename = "userPassword")]
+    UserPassword {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "apiKey")]
+    ApiKey {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED**. The location of the API key.
+        /// Valid values are `"user"` and `"password"`.
+        #[serde(rename = "in")]
+        location: ApiKeyLocation,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    X509 {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "symmetricEncryption")]
+    SymmetricEncryption {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "asymmetricEncryption")]
+    AsymmetricEncryption {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "httpApiKey")]
+    HttpApiKey {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED**. The name of the header,
+        /// query or cookie parameter to be used.
+        name: String,
+        /// **REQUIRED**. The location of the API key.
+        /// Valid values are `"query"`, `"header"` or `"cookie"`.
+        #[serde(rename = "in")]
+        location: HttpApiKeyLocation,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "http", rename_all = "camelCase")]
+    Http {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED**. The name of the HTTP Authorization scheme
+        /// to be used in the Authorization header as defined in
+        /// [RFC7235](https://tools.ietf.org/html/rfc7235#section-5.1).
+        scheme: String,
+        /// A hint to the client to identify how the bearer token is formatted.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        bearer_format: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED**. An object containing configuration
+        /// information for the flow types supported.
+        flows: OAuthFlows,
+        /// The list of the scopes required by the operation or server the
+        /// scheme is used by.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        scopes: Vec<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "openIdConnect", rename_all = "camelCase")]
+    OpenIdConnect {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// **REQUIRED**. OpenId Connect URL to discover
+        /// OAuth2 configuration values. This MUST be in the form of a URL.
+        open_id_connect_url: String,
+        /// The list of the scopes required by the operation or server the
+        /// scheme is used by.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        scopes: Vec<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "plain")]
+    Plain {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "scramSha256")]
+    ScramSha256 {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "scramSha512")]
+    ScramSha512 {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    #[serde(rename = "gssapi")]
+    Gssapi {
+        /// A short description for security scheme.
+        /// [CommonMark syntax](https://spec.commonmark.org/)
+        /// MAY be used for rich text representation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// This object MAY be extended with
+        /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+        #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+        extensions: IndexMap<String, serde_json::Value>,
+    },
+    /// A type of security scheme this crate doesn't know about, e.g. one added
+    /// by a later version of the specification. All fields are kept as they are.
+    #[serde(untagged)]
+    Other {
+        /// The `type` of the security scheme. For the types known to this crate
+        /// the dedicated variant is used instead.
+        #[serde(
+            rename = "type",
+            deserialize_with = "crate::security_scheme::deserialize_unknown_type"
+        )]
+        typ: String,
+        /// All other fields of the security scheme.
+        #[serde(flatten)]
+        fields: IndexMap<String, serde_json::Value>,
+    },
+}
+
+/// Allows configuration of the supported OAuth Flows.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlows {
+    /// Configuration for the OAuth Implicit flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implicit: Option<OAuthFlowImplicit>,
+    /// Configuration for the OAuth Resource Owner Protected Credentials flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<OAuthFlowPassword>,
+    /// Configuration for the OAuth Client Credentials flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_credentials: Option<OAuthFlowClientCredentials>,
+    /// Configuration for the OAuth Authorization Code flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<OAuthFlowAuthorizationCode>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Configuration details for the OAuth Implicit flow.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlowImplicit {
+    /// **REQUIRED**. The authorization URL to be used for this flow.
+    /// This MUST be in the form of a URL.
+    pub authorization_url: String,
+    /// The URL to be used for obtaining refresh tokens.
+    /// This MUST be in the form of a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+    /// **REQUIRED**. The available scopes for the OAuth2 security scheme.
+    /// A map between the scope name and a short description for it.
+    pub available_scopes: IndexMap<String, String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Configuration details for the OAuth Resource Owner Password Credentials flow.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlowPassword {
+    /// **REQUIRED**. The token URL to be used for this flow.
+    /// This MUST be in the form of a URL.
+    pub token_url: String,
+    /// The URL to be used for obtaining refresh tokens.
+    /// This MUST be in the form of a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+    /// **REQUIRED**. The available scopes for the OAuth2 security scheme.
+    /// A map between the scope name and a short description for it.
+    pub available_scopes: IndexMap<String, String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Configuration details for the OAuth Client Credentials flow.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlowClientCredentials {
+    /// **REQUIRED**. The token URL to be used for this flow.
+    /// This MUST be in the form of a URL.
+    pub token_url: String,
+    /// The URL to be used for obtaining refresh tokens.
+    /// This MUST be in the form of a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+    /// **REQUIRED**. The available scopes for the OAuth2 security scheme.
+    /// A map between the scope name and a short description for it.
+    pub available_scopes: IndexMap<String, String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+/// Configuration details for the OAuth Authorization Code flow.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlowAuthorizationCode {
+    /// **REQUIRED**. The authorization URL to be used for this flow.
+    /// This MUST be in the form of a URL.
+    pub authorization_url: String,
+    /// **REQUIRED**. The token URL to be used for this flow.
+    /// This MUST be in the form of a URL.
+    pub token_url: String,
+    /// The URL to be used for obtaining refresh tokens.
+    /// This MUST be in the form of a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+    /// **REQUIRED**. The available scopes for the OAuth2 security scheme.
+    /// A map between the scope name and a short description for it.
+    pub available_scopes: IndexMap<String, String>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+#[test]
+fn test_deserialize_oauth2_security_scheme() {
+    let scheme: SecurityScheme = serde_yaml::from_str(
+        r#"
+        type: oauth2
+        flows:
+          clientCredentials:
+            tokenUrl: https://example.com/api/oauth/token
+            availableScopes:
+              write:pets: modify pets in your account
+              read:pets: read your pets
+        scopes:
+          - write:pets
+        "#,
+    )
+    .expect("Could not deserialize OAuth2 security scheme");
+
+    let SecurityScheme::OAuth2 { flows, scopes, .. } = &scheme else {
+        panic!("expected an oauth2 scheme, got {scheme:?}")
+    };
+    let flow = flows.client_credentials.as_ref().unwrap();
+    assert_eq!(flow.token_url, "https://example.com/api/oauth/token");
+    assert_eq!(
+        flow.available_scopes.keys().collect::<Vec<_>>(),
+        ["write:pets", "read:pets"]
+    );
+    assert_eq!(scopes, &["write:pets"]);
+    assert!(flows.implicit.is_none());
+}
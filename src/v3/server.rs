@@ -0,0 +1,138 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::SecurityScheme;
+use crate::{ExternalDocumentation, ReferenceOr, ServerBinding, ServerVariable, Tag};
+
+/// An object representing a message broker, a server or any other kind of
+/// computer program capable of sending and/or receiving data.
+///
+/// # Examples
+///
+/// ```yaml
+/// host: 'rabbitmq.in.mycompany.com:5672'
+/// pathname: /production
+/// protocol: amqp
+/// protocolVersion: 0.9.1
+/// description: Production RabbitMQ broker (uses the `production` vhost).
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Server {
+    /// **REQUIRED.** The server host name. It MAY include the port. This
+    /// field supports [Server Variables][crate::ServerVariable].
+    pub host: String,
+    /// **REQUIRED.** The protocol this server supports for connection.
+    pub protocol: String,
+    /// The version of the protocol used for connection.
+    /// For instance: AMQP `0.9.1`, HTTP `2.0`, Kafka `1.0.0`, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<String>,
+    /// The path to a resource in the host. This field supports
+    /// [Server Variables][crate::ServerVariable].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pathname: Option<String>,
+    /// An optional string describing the server.
+    /// [CommonMark syntax](https://spec.commonmark.org/) MAY be used
+    /// for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A human-friendly title for the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short summary of the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A map between a variable name and its value. The value is used
+    /// for substitution in the server's `host` and `pathname` template.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub variables: IndexMap<String, ReferenceOr<ServerVariable>>,
+    /// A declaration of which security schemes can be used with this server.
+    /// Using any of them is enough to authorize a connection.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<ReferenceOr<SecurityScheme>>,
+    /// A list of tags for logical grouping and categorization of servers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ReferenceOr<Tag>>,
+    /// Additional external documentation for this server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ReferenceOr<ExternalDocumentation>>,
+    /// A map where the keys describe the name of the protocol and the values
+    /// describe protocol-specific definitions for the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ReferenceOr<ServerBinding>>,
+    /// This object MAY be extended with
+    /// [Specification Extensions](https://www.asyncapi.com/docs/reference/specification/v3.0.0#specificationExtensions).
+    #[serde(flatten, deserialize_with = "crate::parse::deserialize_extensions")]
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Server {
+    /// Creates a server from the `url` of a 2.x server, splitting it into
+    /// `host` and `pathname`. A scheme in the URL is dropped, the `protocol`
+    /// describes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::v3::Server;
+    ///
+    /// let server = Server::from_url("amqp://rabbitmq.example.com:5672/production", "amqp");
+    /// assert_eq!(server.host, "rabbitmq.example.com:5672");
+    /// assert_eq!(server.pathname.as_deref(), Some("/production"));
+    /// assert_eq!(server.url(), "rabbitmq.example.com:5672/production");
+    /// ```
+    pub fn from_url(url: &str, protocol: impl Into<String>) -> Self {
+        let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let (host, pathname) = match url.find('/') {
+            Some(index) => (&url[..index], Some(url[index..].to_string())),
+            None => (url, None),
+        };
+        Server {
+            host: host.to_string(),
+            protocol: protocol.into(),
+            pathname,
+            ..Default::default()
+        }
+    }
+
+    /// The `url` of the server in the 2.x form, i.e. the `host` followed by
+    /// the `pathname`.
+    pub fn url(&self) -> String {
+        match &self.pathname {
+            Some(pathname) if !pathname.is_empty() && !pathname.starts_with('/') => {
+                format!("{}/{pathname}", self.host)
+            }
+            Some(pathname) => format!("{}{pathname}", self.host),
+            None => self.host.clone(),
+        }
+    }
+}
+
+#[test]
+fn test_url_conversion() {
+    for (url, host, pathname) in [
+        ("api.gigantic-server.com", "api.gigantic-server.com", None),
+        (
+            "{username}.gigantic-server.com:{port}/{basePath}",
+            "{username}.gigantic-server.com:{port}",
+            Some("/{basePath}"),
+        ),
+        (
+            "wss://ws.example.com/stream",
+            "ws.example.com",
+            Some("/stream"),
+        ),
+    ] {
+        let server = Server::from_url(url, "ws");
+        assert_eq!(server.host, host);
+        assert_eq!(server.pathname.as_deref(), pathname);
+    }
+
+    let server = Server {
+        host: "example.com".to_string(),
+        pathname: Some("v1".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(server.url(), "example.com/v1");
+}
@@ -0,0 +1,147 @@
+//! Checks of rules the specification states but the data structures can't express.
+use std::fmt;
+
+use crate::Error;
+
+/// A single violation of a rule of the specification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// [JSON Pointer](https://tools.ietf.org/html/rfc6901) to the offending
+    /// value, e.g. `/channels/user~1signedup/subscribe/operationId`.
+    pub path: String,
+    /// Describes the violated rule.
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        ValidationError {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "/: {}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Appends `segment` to the JSON Pointer `path`, escaping `~` and `/`.
+pub fn join_pointer(path: &str, segment: &str) -> String {
+    format!("{path}/{}", segment.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Types that can check the rules of the specification that apply to them.
+pub trait Validate {
+    /// Appends every violation found to `errors`. `path` is the JSON Pointer
+    /// to `self`, it prefixes the paths of the reported errors.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>);
+
+    /// Checks `self` and returns every violation found as an [`Error::Validate`].
+    fn validate(&self) -> Result<(), Error> {
+        let mut errors = Vec::new();
+        self.validate_at("", &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Validate(errors))
+        }
+    }
+}
+
+/// Reports an error unless `value` is an absolute URL.
+///
+/// Values containing template placeholders such as `{tenant}` or
+/// `<your-url>` are rejected as well. With the `url` feature enabled the value
+/// is parsed according to the WHATWG URL standard, otherwise only its general
+/// shape is checked.
+pub(crate) fn check_url(path: String, value: &str, errors: &mut Vec<ValidationError>) {
+    if value.is_empty() {
+        errors.push(ValidationError::new(path, "must not be empty"));
+    } else if value.contains(['{', '}', '<', '>']) {
+        errors.push(ValidationError::new(
+            path,
+            format!("`{value}` contains a placeholder"),
+        ));
+    } else if !is_url(value) {
+        errors.push(ValidationError::new(
+            path,
+            format!("`{value}` is not an absolute URL"),
+        ));
+    }
+}
+
+/// Reports an error unless `value` looks like an email address: a local part
+/// and a domain separated by a single `@`, without whitespace.
+pub(crate) fn check_email(path: String, value: &str, errors: &mut Vec<ValidationError>) {
+    let valid = value.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && !domain.contains('@')
+            && domain.contains('.')
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+    }) && !value.chars().any(char::is_whitespace);
+    if !valid {
+        errors.push(ValidationError::new(
+            path,
+            format!("`{value}` is not an email address"),
+        ));
+    }
+}
+
+/// Reports an error if `value` is longer than `max` characters.
+pub(crate) fn check_length(
+    path: String,
+    value: &str,
+    max: usize,
+    errors: &mut Vec<ValidationError>,
+) {
+    let length = value.chars().count();
+    if length > max {
+        errors.push(ValidationError::new(
+            path,
+            format!("is {length} characters long, at most {max} are allowed"),
+        ));
+    }
+}
+
+/// The names of the `{name}` placeholders in `template`, in order.
+pub(crate) fn placeholders(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        names.push(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    names
+}
+
+#[cfg(feature = "url")]
+fn is_url(value: &str) -> bool {
+    url::Url::parse(value).is_ok()
+}
+
+/// Checks for a scheme as defined in RFC 3986 followed by a non-empty
+/// remainder without whitespace.
+#[cfg(not(feature = "url"))]
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
+        && !value.chars().any(char::is_whitespace)
+}
@@ -0,0 +1,812 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{
+    error::FormatError, loader::RefLoader, reference::reference_name, validation::join_pointer,
+    AsyncAPI, ComponentKind, Error, ReferenceOr, ResolveError,
+};
+
+/// A set of named documents whose references may point into each other.
+///
+/// Documents are named by their file name or URI, e.g. `services/users.yaml`.
+/// Besides [AsyncAPI documents][AsyncAPI], a workspace can hold files that
+/// only contain shared components. References are resolved relative to the
+/// document they appear in, so `../common.yaml#/components/schemas/User` used
+/// in `services/users.yaml` points into `common.yaml`.
+///
+/// # Examples
+///
+/// ```
+/// use asyncapi::schema::{SchemaKind, Type};
+/// use asyncapi::{ReferenceOr, Schema, Workspace};
+///
+/// let mut workspace = Workspace::new();
+/// workspace
+///     .insert_yaml(
+///         "common.yaml",
+///         r#"
+///         components:
+///           schemas:
+///             User:
+///               type: object
+///         "#,
+///     )
+///     .unwrap();
+///
+/// let user: ReferenceOr<Schema> = ReferenceOr::ref_("../common.yaml#/components/schemas/User");
+/// let schema = workspace.resolve(Some("services/users.yaml"), &user).unwrap();
+/// assert!(matches!(schema.schema_kind, SchemaKind::Type(Type::Object(_))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Workspace {
+    documents: IndexMap<String, Value>,
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a document, replacing any document of the same name.
+    pub fn insert(&mut self, name: impl Into<String>, document: Value) {
+        self.documents.insert(name.into(), document);
+    }
+
+    /// Parses a YAML or JSON document and adds it under `name`.
+    pub fn insert_yaml(&mut self, name: impl Into<String>, input: &str) -> Result<(), Error> {
+        let document =
+            serde_yaml::from_str(input).map_err(|error| Error::Parse(FormatError::Yaml(error)))?;
+        self.insert(name, document);
+        Ok(())
+    }
+
+    /// Adds an AsyncAPI document under `name`.
+    pub fn insert_document(
+        &mut self,
+        name: impl Into<String>,
+        api: &AsyncAPI,
+    ) -> Result<(), Error> {
+        let document = serde_json::to_value(api)
+            .map_err(|error| Error::Serialize(FormatError::Json(error)))?;
+        self.insert(name, document);
+        Ok(())
+    }
+
+    /// Loads the document `name` and every document it references, directly
+    /// or indirectly, that is not part of the workspace yet.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn load() -> Result<(), asyncapi::Error> {
+    /// use asyncapi::{loader::FileLoader, Workspace};
+    ///
+    /// let mut workspace = Workspace::new();
+    /// workspace
+    ///     .load(&FileLoader::new("specs"), "services/users.yaml")
+    ///     .await?;
+    /// let api = workspace.document("services/users.yaml")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load(&mut self, loader: &impl RefLoader, name: &str) -> Result<(), Error> {
+        let mut pending = vec![name.to_string()];
+        while let Some(name) = pending.pop() {
+            if self.documents.contains_key(&name) {
+                continue;
+            }
+            let bytes = loader.load(&name).await?;
+            let document: Value = serde_yaml::from_slice(&bytes)
+                .map_err(|error| Error::Parse(FormatError::Yaml(error)))?;
+            let mut references = Vec::new();
+            collect_references(&document, &mut references);
+            for reference in references {
+                let path = reference
+                    .split_once('#')
+                    .map_or(reference, |(path, _)| path);
+                if !path.is_empty() {
+                    pending.push(join(Some(&name), path));
+                }
+            }
+            self.insert(name, document);
+        }
+        Ok(())
+    }
+
+    /// Returns the names of all documents in insertion order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.documents.keys().map(String::as_str)
+    }
+
+    /// Returns the AsyncAPI document called `name`.
+    pub fn document(&self, name: &str) -> Result<AsyncAPI, Error> {
+        let document = self
+            .documents
+            .get(name)
+            .ok_or_else(|| ResolveError::NotFound(name.to_string()))?;
+        deserialize(document)
+    }
+
+    /// Resolves `item` if it is a reference, `base` being the name of the
+    /// document the reference appears in. References that are found at the
+    /// target are followed as well.
+    ///
+    /// Without a `base`, only references to documents by their full name can
+    /// be resolved.
+    pub fn resolve<T>(&self, base: Option<&str>, item: &ReferenceOr<T>) -> Result<T, Error>
+    where
+        T: DeserializeOwned + Clone,
+    {
+        match item {
+            ReferenceOr::Item(item) => Ok(item.clone()),
+            ReferenceOr::Reference { reference } => self.resolve_reference(base, reference),
+        }
+    }
+
+    /// Resolves a `$ref` value such as `common.yaml#/components/schemas/User`,
+    /// see [`resolve`][Workspace::resolve].
+    pub fn resolve_reference<T: DeserializeOwned>(
+        &self,
+        base: Option<&str>,
+        reference: &str,
+    ) -> Result<T, Error> {
+        let mut seen = Vec::new();
+        let mut document = base.map(str::to_string);
+        let mut reference = reference.to_string();
+        loop {
+            let (path, pointer) = reference.split_once('#').unwrap_or((&reference, ""));
+            let name = match (path, &document) {
+                ("", Some(document)) => document.clone(),
+                ("", None) => return Err(ResolveError::NotFound(reference).into()),
+                (path, document) => join(document.as_deref(), path),
+            };
+            if seen.contains(&(name.clone(), pointer.to_string())) {
+                return Err(ResolveError::Cycle(reference).into());
+            }
+            seen.push((name.clone(), pointer.to_string()));
+
+            let value = self
+                .documents
+                .get(&name)
+                .and_then(|root| root.pointer(pointer))
+                .ok_or_else(|| ResolveError::NotFound(reference.clone()))?;
+            match value.get("$ref").and_then(Value::as_str) {
+                Some(next) => {
+                    reference = next.to_string();
+                    document = Some(name);
+                }
+                None => return deserialize(value),
+            }
+        }
+    }
+
+    /// Returns the AsyncAPI document called `name` with everything it
+    /// references in other documents copied into its components, so it is
+    /// self-contained.
+    ///
+    /// Every reference to another document is replaced by a reference to the
+    /// copy. Targets are copied to the field of the components that matches
+    /// where they are referenced, e.g. the target of the `$ref` of a
+    /// parameter to [parameters][crate::Components::parameters] and that of an
+    /// operation trait to [operationTraits][crate::Components::operation_traits].
+    /// Targets of the form `#/components/{field}/{name}` keep their name,
+    /// others are named after the last segment of their pointer or file. A
+    /// number is appended to names that are already taken. A target
+    /// referenced several times is copied once.
+    ///
+    /// Bundling fails with [`ResolveError::UnknownComponentKind`] for a
+    /// reference used where no component is expected, e.g. inside an
+    /// example, unless its target is of the form
+    /// `#/components/{field}/{name}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asyncapi::{ReferenceOr, Workspace};
+    ///
+    /// let mut workspace = Workspace::new();
+    /// workspace
+    ///     .insert_yaml(
+    ///         "asyncapi.yaml",
+    ///         r#"
+    ///         asyncapi: 2.6.0
+    ///         info:
+    ///           title: Account Service
+    ///           version: 1.0.0
+    ///         channels:
+    ///           user/signedup:
+    ///             subscribe:
+    ///               message:
+    ///                 $ref: 'messages.yaml#/UserSignedUp'
+    ///         "#,
+    ///     )
+    ///     .unwrap();
+    /// workspace
+    ///     .insert_yaml(
+    ///         "messages.yaml",
+    ///         r#"
+    ///         UserSignedUp:
+    ///           payload:
+    ///             $ref: 'common.yaml#/components/schemas/User'
+    ///         "#,
+    ///     )
+    ///     .unwrap();
+    /// workspace
+    ///     .insert_yaml("common.yaml", "components: {schemas: {User: {type: object}}}")
+    ///     .unwrap();
+    ///
+    /// let api = workspace.bundle("asyncapi.yaml").unwrap();
+    /// let components = api.components.unwrap();
+    /// assert!(components.schemas.contains_key("User"));
+    /// let message = &components.messages["UserSignedUp"];
+    /// assert!(matches!(
+    ///     message,
+    ///     ReferenceOr::Item(message)
+    ///         if message.payload == Some(asyncapi::Payload::reference("#/components/schemas/User"))
+    /// ));
+    /// ```
+    pub fn bundle(&self, name: &str) -> Result<AsyncAPI, Error> {
+        let mut document = self
+            .documents
+            .get(name)
+            .ok_or_else(|| ResolveError::NotFound(name.to_string()))?
+            .clone();
+        let mut bundler = Bundler {
+            workspace: self,
+            root: name,
+            existing: document.get("components").cloned().unwrap_or_default(),
+            copies: HashMap::new(),
+            components: IndexMap::new(),
+        };
+        bundler.rewrite(name, &mut document, Position::Document)?;
+
+        if !bundler.components.is_empty() {
+            let root = document
+                .as_object_mut()
+                .ok_or_else(|| ResolveError::NotFound(name.to_string()))?;
+            let components = root
+                .entry("components")
+                .or_insert_with(|| Value::Object(Map::new()));
+            for (field, copies) in bundler.components {
+                let Some(map) = components.as_object_mut().and_then(|components| {
+                    components
+                        .entry(field)
+                        .or_insert_with(|| Value::Object(Map::new()))
+                        .as_object_mut()
+                }) else {
+                    continue;
+                };
+                map.extend(copies);
+            }
+        }
+        deserialize(&document)
+    }
+}
+
+/// The state of [`Workspace::bundle`].
+struct Bundler<'a> {
+    workspace: &'a Workspace,
+    root: &'a str,
+    /// The components of the root document before bundling.
+    existing: Value,
+    /// The local references to the copies, by document and pointer.
+    copies: HashMap<(String, String), String>,
+    /// The copies, by field of the components and name.
+    components: IndexMap<String, Map<String, Value>>,
+}
+
+impl Bundler<'_> {
+    /// Rewrites the references in `value`, which is at `position` in the
+    /// document `base`.
+    fn rewrite(&mut self, base: &str, value: &mut Value, position: Position) -> Result<(), Error> {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get("$ref") {
+                    if let Some(local) = self.copy(base, &reference.clone(), position.kind())? {
+                        *value = serde_json::json!({ "$ref": local });
+                    }
+                    return Ok(());
+                }
+                for (key, value) in object.iter_mut() {
+                    self.rewrite(base, value, position.field(key))?;
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.rewrite(base, value, position.item())?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Copies the target of `reference` into the components, unless it is
+    /// part of the root document, and returns the local reference to use
+    /// instead, if it changes. `kind` is the kind of component expected where
+    /// the reference is used.
+    fn copy(
+        &mut self,
+        base: &str,
+        reference: &str,
+        kind: Option<ComponentKind>,
+    ) -> Result<Option<String>, Error> {
+        let (path, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        let document = match path {
+            "" => base.to_string(),
+            path => join(Some(base), path),
+        };
+        if document == self.root {
+            return Ok((!path.is_empty()).then(|| format!("#{pointer}")));
+        }
+        let key = (document, pointer.to_string());
+        if let Some(local) = self.copies.get(&key) {
+            return Ok(Some(local.clone()));
+        }
+
+        let mut target = self
+            .workspace
+            .documents
+            .get(&key.0)
+            .and_then(|root| root.pointer(pointer))
+            .ok_or_else(|| ResolveError::NotFound(reference.to_string()))?
+            .clone();
+        let component = ComponentKind::parse(&format!("#{pointer}"));
+        let kind = kind
+            .or(component.as_ref().map(|(kind, _)| *kind))
+            .ok_or_else(|| ResolveError::UnknownComponentKind(reference.to_string()))?;
+        let name = match component {
+            Some((_, name)) => name,
+            None if pointer.is_empty() => {
+                let file = key.0.rsplit('/').next().unwrap_or(&key.0);
+                file.split('.').next().unwrap_or(file).to_string()
+            }
+            None => reference_name(pointer),
+        };
+        let field = kind.as_str().to_string();
+        let name = self.unique_name(&field, name);
+        let local = join_pointer(&format!("#/components/{field}"), &name);
+        self.copies.insert(key.clone(), local.clone());
+        // Reserve the name before rewriting, the target may reference itself.
+        self.components
+            .entry(field.clone())
+            .or_default()
+            .insert(name.clone(), Value::Null);
+
+        self.rewrite(&key.0, &mut target, Position::Component(kind))?;
+        self.components[&field][&name] = target;
+        Ok(Some(local))
+    }
+
+    /// `name`, or `name` followed by the first number that makes it unique
+    /// in the `field` of the components.
+    fn unique_name(&self, field: &str, name: String) -> String {
+        let taken = |name: &str| {
+            self.existing
+                .get(field)
+                .and_then(|components| components.get(name))
+                .is_some()
+                || self
+                    .components
+                    .get(field)
+                    .is_some_and(|components| components.contains_key(name))
+        };
+        if !taken(&name) {
+            return name;
+        }
+        (2..)
+            .map(|number| format!("{name}{number}"))
+            .find(|name| !taken(name))
+            .expect("one of infinitely many names is free")
+    }
+}
+
+/// What the value at some place of a 2.x document is, as far as bundling
+/// needs to know which field of the components a reference there points to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Position {
+    Document,
+    Components,
+    /// An object that could be a component of this kind.
+    Component(ComponentKind),
+    /// A map or list of components of this kind.
+    Several(ComponentKind),
+    Operation,
+    /// The binding of one protocol, whose fields are schemas if anything.
+    ProtocolBinding,
+    /// A place where no component can be used, e.g. an example.
+    Other,
+}
+
+impl Position {
+    /// The kind of component a reference at this position points to.
+    fn kind(self) -> Option<ComponentKind> {
+        match self {
+            Position::Component(kind) => Some(kind),
+            _ => None,
+        }
+    }
+
+    /// The position of the field called `key` of an object at this position.
+    fn field(self, key: &str) -> Position {
+        use ComponentKind::*;
+
+        match (self, key) {
+            (Position::Document, "servers") => Position::Several(Server),
+            (Position::Document, "channels") => Position::Several(Channel),
+            (Position::Document, "components") => Position::Components,
+            (Position::Components, field) => {
+                ComponentKind::from_field(field).map_or(Position::Other, Position::Several)
+            }
+            (Position::Several(kind), _) => Position::Component(kind),
+            (Position::Operation, "traits") => Position::Several(OperationTrait),
+            (Position::Operation, "bindings") => Position::Component(OperationBinding),
+            (Position::Operation, "message") => Position::Component(Message),
+            (Position::ProtocolBinding, _) => Position::Component(Schema),
+            (Position::Component(kind), key) => match (kind, key) {
+                (Server, "variables") => Position::Several(ServerVariable),
+                (Server, "bindings") => Position::Component(ServerBinding),
+                (Channel, "parameters") => Position::Several(Parameter),
+                (Channel, "bindings") => Position::Component(ChannelBinding),
+                (Channel, "publish" | "subscribe") => Position::Operation,
+                (Message, "oneOf") => Position::Several(Message),
+                (Message, "traits") => Position::Several(MessageTrait),
+                (Message | MessageTrait, "headers" | "payload") => Position::Component(Schema),
+                (Message | MessageTrait, "correlationId") => Position::Component(CorrelationId),
+                (Message | MessageTrait, "bindings") => Position::Component(MessageBinding),
+                (OperationTrait, "bindings") => Position::Component(OperationBinding),
+                (Parameter, "schema") => Position::Component(Schema),
+                (ServerBinding | ChannelBinding | OperationBinding | MessageBinding, _) => {
+                    Position::ProtocolBinding
+                }
+                (
+                    Schema,
+                    "properties" | "patternProperties" | "definitions" | "$defs"
+                    | "dependentSchemas",
+                ) => Position::Several(Schema),
+                (
+                    Schema,
+                    "enum" | "const" | "default" | "examples" | "example" | "externalDocs"
+                    | "discriminator",
+                ) => Position::Other,
+                (Schema, _) => Position::Component(Schema),
+                _ => Position::Other,
+            },
+            _ => Position::Other,
+        }
+    }
+
+    /// The position of the items of an array at this position.
+    fn item(self) -> Position {
+        match self {
+            Position::Several(kind) => Position::Component(kind),
+            // e.g. `allOf` or `items` of a schema
+            Position::Component(ComponentKind::Schema) => self,
+            _ => Position::Other,
+        }
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(value: &Value) -> Result<T, Error> {
+    T::deserialize(value).map_err(|error| Error::Parse(FormatError::Json(error)))
+}
+
+/// Collects the values of all `$ref` fields in `value`.
+fn collect_references<'a>(value: &'a Value, references: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match value {
+                    Value::String(reference) if key == "$ref" => references.push(reference),
+                    value => collect_references(value, references),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_references(value, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Resolves the relative `path` against the name of the `base` document.
+fn join(base: Option<&str>, path: &str) -> String {
+    if path.contains("://") || path.starts_with('/') {
+        return path.to_string();
+    }
+    let mut segments: Vec<&str> = match base.and_then(|base| base.rsplit_once('/')) {
+        Some((directory, _)) => directory.split('/').collect(),
+        None => Vec::new(),
+    };
+    for segment in path.split('/') {
+        match segment {
+            "." => {}
+            ".." if segments.last().is_some_and(|last| *last != "..") => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+#[test]
+fn test_resolve_across_documents() {
+    use crate::{Message, Schema};
+
+    let mut workspace = Workspace::new();
+    workspace
+        .insert_yaml(
+            "shared/messages.yaml",
+            r#"
+            components:
+              messages:
+                UserSignedUp:
+                  name: userSignedUp
+                  payload:
+                    $ref: 'schemas.yaml#/components/schemas/User'
+                Alias:
+                  $ref: '#/components/messages/UserSignedUp'
+            "#,
+        )
+        .unwrap();
+    workspace
+        .insert_yaml(
+            "shared/schemas.yaml",
+            r#"
+            components:
+              schemas:
+                User:
+                  type: object
+                Loop:
+                  $ref: '#/components/schemas/Loop'
+            "#,
+        )
+        .unwrap();
+
+    let message: Message = workspace
+        .resolve_reference(
+            Some("services/users.yaml"),
+            "../shared/messages.yaml#/components/messages/Alias",
+        )
+        .unwrap();
+    assert_eq!(message.name.as_deref(), Some("userSignedUp"));
+
+    let schema: Schema = workspace
+        .resolve_reference(
+            Some("shared/messages.yaml"),
+            "schemas.yaml#/components/schemas/User",
+        )
+        .unwrap();
+    assert!(matches!(
+        schema.schema_kind,
+        crate::schema::SchemaKind::Type(crate::schema::Type::Object(_))
+    ));
+
+    assert!(matches!(
+        workspace.resolve_reference::<Schema>(None, "shared/schemas.yaml#/components/schemas/Loop"),
+        Err(Error::Resolve(ResolveError::Cycle(_)))
+    ));
+    assert!(matches!(
+        workspace.resolve_reference::<Schema>(None, "missing.yaml#/components/schemas/User"),
+        Err(Error::Resolve(ResolveError::NotFound(_)))
+    ));
+}
+
+#[test]
+fn test_load_referenced_documents() {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    struct Files;
+
+    impl RefLoader for Files {
+        async fn load(&self, uri: &str) -> Result<Vec<u8>, Error> {
+            let contents = match uri {
+                "services/users.yaml" => {
+                    "channels:\n  user/signedup:\n    subscribe:\n      message:\n        $ref: '../shared/messages.yaml#/UserSignedUp'"
+                }
+                "shared/messages.yaml" => {
+                    "UserSignedUp:\n  payload:\n    $ref: 'schemas.yaml#/User'"
+                }
+                "shared/schemas.yaml" => "User:\n  type: object",
+                uri => return Err(ResolveError::NotFound(uri.to_string()).into()),
+            };
+            Ok(contents.as_bytes().to_vec())
+        }
+    }
+
+    let mut workspace = Workspace::new();
+    // The loader never suspends, a single poll drives the future to completion.
+    let poll = {
+        let future = pin!(workspace.load(&Files, "services/users.yaml"));
+        future.poll(&mut Context::from_waker(Waker::noop()))
+    };
+    match poll {
+        Poll::Ready(result) => result.unwrap(),
+        Poll::Pending => panic!("loading did not complete"),
+    }
+    assert_eq!(
+        workspace.names().collect::<Vec<_>>(),
+        [
+            "services/users.yaml",
+            "shared/messages.yaml",
+            "shared/schemas.yaml"
+        ]
+    );
+}
+
+#[test]
+fn test_bundle() {
+    let mut workspace = Workspace::new();
+    workspace
+        .insert_yaml(
+            "services/users.yaml",
+            r#"
+            asyncapi: 2.6.0
+            info:
+              title: Account Service
+              version: 1.0.0
+            channels:
+              user/signedup:
+                subscribe:
+                  message:
+                    oneOf:
+                      - $ref: '../shared/messages.yaml#/components/messages/UserSignedUp'
+                      - $ref: '../shared/messages.yaml#/components/messages/UserSignedUp'
+              user/deleted:
+                subscribe:
+                  message:
+                    payload:
+                      $ref: '../shared/user.yaml'
+            components:
+              schemas:
+                User:
+                  type: string
+            "#,
+        )
+        .unwrap();
+    workspace
+        .insert_yaml(
+            "shared/messages.yaml",
+            r#"
+            components:
+              messages:
+                UserSignedUp:
+                  payload:
+                    $ref: '#/components/schemas/User'
+              schemas:
+                User:
+                  $ref: 'user.yaml'
+            "#,
+        )
+        .unwrap();
+    workspace
+        .insert_yaml(
+            "shared/user.yaml",
+            r#"
+            type: object
+            properties:
+              friend:
+                $ref: '#'
+            "#,
+        )
+        .unwrap();
+
+    let api = workspace.bundle("services/users.yaml").unwrap();
+    let components = serde_json::to_value(api.components.unwrap()).unwrap();
+    assert_eq!(
+        components["messages"]["UserSignedUp"]["payload"]["$ref"],
+        "#/components/schemas/User2"
+    );
+    assert_eq!(
+        components["schemas"]["User2"]["$ref"],
+        "#/components/schemas/user"
+    );
+    assert_eq!(
+        components["schemas"]["user"]["properties"]["friend"]["$ref"],
+        "#/components/schemas/user"
+    );
+    assert_eq!(components["schemas"]["User"]["type"], "string");
+    assert_eq!(components["messages"].as_object().unwrap().len(), 1);
+
+    assert!(matches!(
+        workspace.bundle("shared/missing.yaml"),
+        Err(Error::Resolve(ResolveError::NotFound(_)))
+    ));
+}
+
+#[test]
+fn test_bundle_component_kinds() {
+    let mut workspace = Workspace::new();
+    workspace
+        .insert_yaml(
+            "asyncapi.yaml",
+            r#"
+            asyncapi: 2.6.0
+            info:
+              title: Account Service
+              version: 1.0.0
+            channels:
+              user/{userId}:
+                parameters:
+                  userId:
+                    $ref: 'common.yaml#/userId'
+                publish:
+                  traits:
+                    - $ref: 'common.yaml#/kafka'
+                  message:
+                    name: UserSignedUp
+                    correlationId:
+                      $ref: 'common.yaml#/correlation'
+            "#,
+        )
+        .unwrap();
+    workspace
+        .insert_yaml(
+            "common.yaml",
+            r#"
+            userId:
+              schema:
+                type: string
+            kafka:
+              bindings:
+                kafka:
+                  groupId:
+                    $ref: '#/group'
+            group:
+              type: string
+            correlation:
+              location: $message.header#/correlationId
+            "#,
+        )
+        .unwrap();
+
+    let api = workspace.bundle("asyncapi.yaml").unwrap();
+    let components = serde_json::to_value(api.components.unwrap()).unwrap();
+    assert_eq!(
+        components["parameters"]["userId"]["schema"]["type"],
+        "string"
+    );
+    assert_eq!(
+        components["operationTraits"]["kafka"]["bindings"]["kafka"]["groupId"]["$ref"],
+        "#/components/schemas/group"
+    );
+    assert_eq!(
+        components["correlationIds"]["correlation"]["location"],
+        "$message.header#/correlationId"
+    );
+    assert!(components.get("messages").is_none());
+
+    workspace
+        .insert_yaml(
+            "asyncapi.yaml",
+            r#"
+            asyncapi: 2.6.0
+            info:
+              title: Account Service
+              version: 1.0.0
+            channels:
+              user/signedup:
+                publish:
+                  message:
+                    name: UserSignedUp
+                    examples:
+                      - payload:
+                          $ref: 'common.yaml#/group'
+            "#,
+        )
+        .unwrap();
+    assert!(matches!(
+        workspace.bundle("asyncapi.yaml"),
+        Err(Error::Resolve(ResolveError::UnknownComponentKind(_)))
+    ));
+}